@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The ABI of a program describes its interface: the transitions that can be called, and the records, structs,
+//! and mappings that their inputs, outputs, and finalize blocks refer to. Types are written in Leo syntax.
+
+use crate::{FunctionStub, Input, Mapping, Mode, Output, Program, Struct, Stub, Type, Variant};
+
+use leo_span::Symbol;
+use serde::{Deserialize, Serialize};
+
+/// The interface of a program, as written to `build/<program>.abi.json`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Abi {
+    /// The program ID, e.g. `token.aleo`.
+    pub program: String,
+    /// The program IDs of the programs it imports.
+    pub imports: Vec<String>,
+    /// The structs declared in the program.
+    pub structs: Vec<AbiStruct>,
+    /// The records declared in the program.
+    pub records: Vec<AbiStruct>,
+    /// The mappings declared in the program.
+    pub mappings: Vec<AbiMapping>,
+    /// The transitions of the program.
+    pub transitions: Vec<AbiTransition>,
+}
+
+/// A struct or record.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbiStruct {
+    pub name: String,
+    pub fields: Vec<AbiParameter>,
+}
+
+/// A mapping.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbiMapping {
+    pub name: String,
+    pub key: String,
+    pub value: String,
+}
+
+/// A transition, along with the parameters of its finalize block, if it has one.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbiTransition {
+    pub name: String,
+    pub inputs: Vec<AbiParameter>,
+    pub outputs: Vec<AbiParameter>,
+    pub finalize: Option<Vec<AbiParameter>>,
+}
+
+/// An input or output of a transition, a parameter of a finalize block, or a field of a struct or record.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AbiParameter {
+    /// The name, which outputs do not have.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The type. Records declared by other programs are written as `<program id>/<record>`, e.g. `token.aleo/token`.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// The visibility, which is one of `constant`, `private`, and `public`.
+    /// Records, which are always private, and the parameters of finalize blocks, which are always public, have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
+}
+
+impl Abi {
+    /// Describes a program from its structs, mappings, and functions.
+    fn new<'a>(
+        program: String,
+        imports: Vec<String>,
+        structs: impl Iterator<Item = &'a Struct>,
+        mappings: impl Iterator<Item = &'a Mapping>,
+        functions: impl Iterator<Item = FunctionStub>,
+    ) -> Self {
+        let (records, structs): (Vec<&Struct>, Vec<&Struct>) = structs.partition(|struct_| struct_.is_record);
+        let record_names = records.iter().map(|record| record.name()).collect::<Vec<_>>();
+
+        let describe_struct = |struct_: &Struct| AbiStruct {
+            name: struct_.name().to_string(),
+            fields: struct_
+                .members
+                .iter()
+                .map(|member| AbiParameter {
+                    name: Some(member.name().to_string()),
+                    type_: member.type_.to_string(),
+                    mode: struct_.is_record.then(|| mode(member.mode)),
+                })
+                .collect(),
+        };
+        // Records are passed without a visibility, while other types default to being private.
+        let parameter = |name: Option<String>, type_: &Type, mode_: Mode| AbiParameter {
+            name,
+            type_: type_.to_string(),
+            mode: match type_ {
+                Type::Identifier(identifier) if record_names.contains(&identifier.name) => None,
+                _ => Some(mode(mode_)),
+            },
+        };
+
+        let transitions = functions
+            .filter(|function| function.variant == Variant::Transition)
+            .map(|function| AbiTransition {
+                name: function.name().to_string(),
+                inputs: function
+                    .input
+                    .iter()
+                    .map(|input| match input {
+                        Input::Internal(input) => {
+                            parameter(Some(input.identifier.to_string()), &input.type_, input.mode)
+                        }
+                        Input::External(input) => AbiParameter {
+                            name: Some(input.identifier.to_string()),
+                            type_: external_record(input.program_name.name, input.record.name),
+                            mode: None,
+                        },
+                    })
+                    .collect(),
+                outputs: function
+                    .output
+                    .iter()
+                    .map(|output| match output {
+                        Output::Internal(output) => parameter(None, &output.type_, output.mode),
+                        Output::External(output) => AbiParameter {
+                            name: None,
+                            type_: external_record(output.program_name.name, output.record.name),
+                            mode: None,
+                        },
+                    })
+                    .collect(),
+                finalize: function.finalize_stub.as_ref().map(|finalize| {
+                    finalize
+                        .input
+                        .iter()
+                        .map(|input| AbiParameter {
+                            name: Some(input.identifier().to_string()),
+                            type_: input.type_().to_string(),
+                            mode: None,
+                        })
+                        .collect()
+                }),
+            })
+            .collect();
+
+        Self {
+            program,
+            imports,
+            structs: structs.into_iter().map(describe_struct).collect(),
+            records: records.into_iter().map(describe_struct).collect(),
+            mappings: mappings
+                .map(|mapping| AbiMapping {
+                    name: mapping.identifier.to_string(),
+                    key: mapping.key_type.to_string(),
+                    value: mapping.value_type.to_string(),
+                })
+                .collect(),
+            transitions,
+        }
+    }
+}
+
+impl From<&Program> for Abi {
+    /// Describes the program scope of a program.
+    fn from(program: &Program) -> Self {
+        let scope = program.program_scopes.values().next().expect("A program always has a program scope.");
+        let network = scope.program_id.network;
+        Self::new(
+            scope.program_id.to_string(),
+            program.imports.keys().map(|import| format!("{import}.{network}")).collect(),
+            scope.structs.iter().map(|(_, struct_)| struct_),
+            scope.mappings.iter().map(|(_, mapping)| mapping),
+            scope.functions.iter().map(|(_, function)| FunctionStub::from(function.clone())),
+        )
+    }
+}
+
+impl From<&Stub> for Abi {
+    fn from(stub: &Stub) -> Self {
+        Self::new(
+            stub.stub_id.to_string(),
+            stub.imports.iter().map(|import| import.to_string()).collect(),
+            stub.structs.iter().map(|(_, struct_)| struct_),
+            stub.mappings.iter().map(|(_, mapping)| mapping),
+            stub.functions.iter().map(|(_, function)| function.clone()),
+        )
+    }
+}
+
+/// Returns the visibility of a mode, in which the absence of a mode means private.
+fn mode(mode: Mode) -> String {
+    match mode {
+        Mode::None | Mode::Private => "private",
+        Mode::Constant => "constant",
+        Mode::Public => "public",
+    }
+    .to_string()
+}
+
+/// Returns the type of a record declared by another program.
+fn external_record(program: Symbol, record: Symbol) -> String {
+    format!("{program}.aleo/{record}")
+}
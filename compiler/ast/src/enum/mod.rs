@@ -0,0 +1,63 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod variant;
+pub use variant::*;
+
+use crate::{Identifier, Node, NodeID};
+use leo_span::{Span, Symbol};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// An enum type definition, e.g., `enum Status { Pending, Filled(u64) }`.
+/// Each variant optionally carries a payload of a single type.
+/// Enums are lowered into structs, with a `tag` member and a member for the payload of each variant.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Enum {
+    /// The name of the enum.
+    pub identifier: Identifier,
+    /// The variants of the enum.
+    pub variants: Vec<EnumVariant>,
+    /// The entire span of the enum definition.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl Enum {
+    /// Returns the enum name as a Symbol.
+    pub fn name(&self) -> Symbol {
+        self.identifier.name
+    }
+
+    /// Returns the position and the definition of the variant with the given name, if it exists.
+    pub fn variant(&self, name: Symbol) -> Option<(usize, &EnumVariant)> {
+        self.variants.iter().enumerate().find(|(_, variant)| variant.name() == name)
+    }
+}
+
+impl fmt::Display for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "enum {} {{ ", self.identifier)?;
+        for variant in self.variants.iter() {
+            writeln!(f, "        {variant}")?;
+        }
+        write!(f, "    }}")
+    }
+}
+
+crate::simple_node_impl!(Enum);
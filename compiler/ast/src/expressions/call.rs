@@ -16,12 +16,15 @@
 
 use super::*;
 
-/// A function call expression, e.g.`foo(args)` or `Foo::bar(args)`.
+/// A function call expression, e.g.`foo(args)`, `foo::[N](args)` or `Foo::bar(args)`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CallExpression {
     /// An expression evaluating to a callable function,
     /// either a member of a structure or a free function.
     pub function: Box<Expression>, // todo: make this identifier?
+    /// Expressions for the const arguments passed to a generic function, e.g. `4u32` in `foo::[4u32](args)`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub const_arguments: Vec<Expression>,
     /// Expressions for the arguments passed to the functions parameters.
     pub arguments: Vec<Expression>,
     /// The name of the external program call, e.g.`bar` in `bar.leo`.
@@ -36,13 +39,19 @@ impl fmt::Display for CallExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self.external {
             Some(external) => {
-                write!(f, "{external}.leo/{}(", self.function)?;
+                write!(f, "{external}.leo/{}", self.function)?;
             }
             None => {
-                write!(f, "{}(", self.function)?;
+                write!(f, "{}", self.function)?;
             }
         }
 
+        if !self.const_arguments.is_empty() {
+            let arguments = self.const_arguments.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+            write!(f, "::[{arguments}]")?;
+        }
+        write!(f, "(")?;
+
         for (i, param) in self.arguments.iter().enumerate() {
             write!(f, "{param}")?;
             if i < self.arguments.len() - 1 {
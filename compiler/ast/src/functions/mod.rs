@@ -17,6 +17,9 @@
 pub mod annotation;
 pub use annotation::*;
 
+pub mod const_parameter;
+pub use const_parameter::*;
+
 pub mod core_function;
 pub use core_function::*;
 
@@ -39,7 +42,7 @@ pub mod mode;
 pub use mode::*;
 
 use crate::{Block, FunctionStub, Identifier, Node, NodeID, TupleType, Type};
-use leo_span::{Span, Symbol};
+use leo_span::{sym, Span, Symbol};
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -53,6 +56,9 @@ pub struct Function {
     pub variant: Variant,
     /// The function identifier, e.g., `foo` in `function foo(...) { ... }`.
     pub identifier: Identifier,
+    /// The const parameters of a generic `inline` function, e.g. `N: u32` in `inline sum::[N: u32](...)`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub const_parameters: Vec<ConstParameter>,
     /// The function's input parameters.
     pub input: Vec<Input>,
     /// The function's output declarations.
@@ -84,6 +90,7 @@ impl Function {
         annotations: Vec<Annotation>,
         variant: Variant,
         identifier: Identifier,
+        const_parameters: Vec<ConstParameter>,
         input: Vec<Input>,
         output: Vec<Output>,
         block: Block,
@@ -103,7 +110,19 @@ impl Function {
             _ => Type::Tuple(TupleType::new(output.iter().map(get_output_type).collect())),
         };
 
-        Function { annotations, variant, identifier, input, output, output_type, block, finalize, span, id }
+        Function {
+            annotations,
+            variant,
+            identifier,
+            const_parameters,
+            input,
+            output,
+            output_type,
+            block,
+            finalize,
+            span,
+            id,
+        }
     }
 
     /// Returns function name.
@@ -111,6 +130,21 @@ impl Function {
         self.identifier.name
     }
 
+    /// Returns `true` if the function has an annotation with the given name.
+    pub fn has_annotation(&self, name: Symbol) -> bool {
+        self.annotations.iter().any(|annotation| annotation.identifier.name == name)
+    }
+
+    /// Returns `true` if the function is a test, i.e. it is annotated with `@test`.
+    pub fn is_test(&self) -> bool {
+        self.has_annotation(sym::test)
+    }
+
+    /// Returns `true` if the function is generic, i.e. it has const parameters.
+    pub fn is_generic(&self) -> bool {
+        !self.const_parameters.is_empty()
+    }
+
     ///
     /// Private formatting method used for optimizing [fmt::Debug] and [fmt::Display] implementations.
     ///
@@ -121,6 +155,10 @@ impl Function {
             Variant::Transition => write!(f, "transition ")?,
         }
         write!(f, "{}", self.identifier)?;
+        if self.is_generic() {
+            let parameters = self.const_parameters.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(", ");
+            write!(f, "::[{parameters}]")?;
+        }
 
         let parameters = self.input.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",");
         let returns = match self.output.len() {
@@ -146,6 +184,7 @@ impl From<FunctionStub> for Function {
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
+            const_parameters: Vec::new(),
             input: function.input,
             output: function.output,
             output_type: function.output_type,
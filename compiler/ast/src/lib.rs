@@ -22,6 +22,9 @@
 
 #![allow(ambiguous_glob_reexports)]
 
+pub mod abi;
+pub use self::abi::*;
+
 pub mod access;
 pub use self::access::*;
 
@@ -31,6 +34,9 @@ pub use self::r#struct::*;
 pub mod common;
 pub use self::common::*;
 
+pub mod r#enum;
+pub use self::r#enum::*;
+
 pub mod expressions;
 pub use self::expressions::*;
 
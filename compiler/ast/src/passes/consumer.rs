@@ -85,6 +85,7 @@ pub trait StatementConsumer {
             Statement::Definition(stmt) => self.consume_definition(stmt),
             Statement::Expression(stmt) => self.consume_expression_statement(stmt),
             Statement::Iteration(stmt) => self.consume_iteration(*stmt),
+            Statement::Match(stmt) => self.consume_match(stmt),
             Statement::Return(stmt) => self.consume_return(stmt),
         }
     }
@@ -107,6 +108,8 @@ pub trait StatementConsumer {
 
     fn consume_iteration(&mut self, input: IterationStatement) -> Self::Output;
 
+    fn consume_match(&mut self, input: MatchStatement) -> Self::Output;
+
     fn consume_return(&mut self, input: ReturnStatement) -> Self::Output;
 }
 
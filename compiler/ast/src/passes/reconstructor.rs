@@ -141,6 +141,11 @@ pub trait ExpressionReconstructor {
         (
             Expression::Call(CallExpression {
                 function: Box::new(self.reconstruct_expression(*input.function).0),
+                const_arguments: input
+                    .const_arguments
+                    .into_iter()
+                    .map(|argument| self.reconstruct_expression(argument).0)
+                    .collect(),
                 arguments: input.arguments.into_iter().map(|arg| self.reconstruct_expression(arg).0).collect(),
                 external: input.external,
                 span: input.span,
@@ -255,6 +260,7 @@ pub trait StatementReconstructor: ExpressionReconstructor {
             Statement::Definition(stmt) => self.reconstruct_definition(stmt),
             Statement::Expression(stmt) => self.reconstruct_expression_statement(stmt),
             Statement::Iteration(stmt) => self.reconstruct_iteration(*stmt),
+            Statement::Match(stmt) => self.reconstruct_match(stmt),
             Statement::Return(stmt) => self.reconstruct_return(stmt),
         }
     }
@@ -380,12 +386,23 @@ pub trait StatementReconstructor: ExpressionReconstructor {
             Statement::Iteration(Box::new(IterationStatement {
                 variable: input.variable,
                 type_: input.type_,
-                start: self.reconstruct_expression(input.start).0,
-                start_value: input.start_value,
-                stop: self.reconstruct_expression(input.stop).0,
-                stop_value: input.stop_value,
+                range: match input.range {
+                    IterationRange::Range { start, start_value, stop, stop_value, inclusive, step, step_value } => {
+                        IterationRange::Range {
+                            start: self.reconstruct_expression(start).0,
+                            start_value,
+                            stop: self.reconstruct_expression(stop).0,
+                            stop_value,
+                            inclusive,
+                            step: step.map(|step| self.reconstruct_expression(step).0),
+                            step_value,
+                        }
+                    }
+                    IterationRange::Array { array } => {
+                        IterationRange::Array { array: self.reconstruct_expression(array).0 }
+                    }
+                },
                 block: self.reconstruct_block(input.block).0,
-                inclusive: input.inclusive,
                 span: input.span,
                 id: input.id,
             })),
@@ -393,6 +410,27 @@ pub trait StatementReconstructor: ExpressionReconstructor {
         )
     }
 
+    fn reconstruct_match(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Match(MatchStatement {
+                expression: self.reconstruct_expression(input.expression).0,
+                arms: input
+                    .arms
+                    .into_iter()
+                    .map(|arm| MatchArm {
+                        pattern: arm.pattern,
+                        block: self.reconstruct_block(arm.block).0,
+                        span: arm.span,
+                        id: arm.id,
+                    })
+                    .collect(),
+                span: input.span,
+                id: input.id,
+            }),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
         (
             Statement::Return(ReturnStatement {
@@ -417,6 +455,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
                 .into_iter()
                 .map(|(id, import)| (id, (self.reconstruct_import(import.0), import.1)))
                 .collect(),
+            modules: input.modules,
             stubs: input.stubs.into_iter().map(|(id, stub)| (id, self.reconstruct_stub(stub))).collect(),
             program_scopes: input
                 .program_scopes
@@ -442,6 +481,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
         ProgramScope {
             program_id: input.program_id,
             structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            enums: input.enums,
             mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: input
@@ -461,6 +501,7 @@ pub trait ProgramReconstructor: StatementReconstructor {
             annotations: input.annotations,
             variant: input.variant,
             identifier: input.identifier,
+            const_parameters: input.const_parameters,
             input: input.input,
             output: input.output,
             output_type: input.output_type,
@@ -144,6 +144,7 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(stmt) => self.visit_match(stmt),
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
@@ -203,11 +204,26 @@ pub trait StatementVisitor<'a>: ExpressionVisitor<'a> {
     }
 
     fn visit_iteration(&mut self, input: &'a IterationStatement) {
-        self.visit_expression(&input.start, &Default::default());
-        self.visit_expression(&input.stop, &Default::default());
+        match &input.range {
+            IterationRange::Range { start, stop, step, .. } => {
+                self.visit_expression(start, &Default::default());
+                self.visit_expression(stop, &Default::default());
+                if let Some(step) = step {
+                    self.visit_expression(step, &Default::default());
+                }
+            }
+            IterationRange::Array { array } => {
+                self.visit_expression(array, &Default::default());
+            }
+        }
         self.visit_block(&input.block);
     }
 
+    fn visit_match(&mut self, input: &'a MatchStatement) {
+        self.visit_expression(&input.expression, &Default::default());
+        input.arms.iter().for_each(|arm| self.visit_block(&arm.block));
+    }
+
     fn visit_return(&mut self, input: &'a ReturnStatement) {
         self.visit_expression(&input.expression, &Default::default());
         if let Some(arguments) = &input.finalize_arguments {
@@ -16,6 +16,9 @@
 
 //! A Leo program consists of import statements and program scopes.
 
+pub mod module;
+pub use module::*;
+
 pub mod program_id;
 pub use program_id::*;
 
@@ -34,6 +37,10 @@ use std::fmt;
 pub struct Program {
     /// A map from import names to import definitions.
     pub imports: IndexMap<Symbol, (Program, Span)>,
+    /// A map from the names of the imported modules to the spans of their imports.
+    /// Note that the modules are merged into the program scope once they are parsed.
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub modules: IndexMap<Symbol, Span>,
     /// A map from program stub names to program stub scopes.
     pub stubs: IndexMap<Symbol, Stub>,
     /// A map from program names to program scopes.
@@ -45,6 +52,9 @@ impl fmt::Display for Program {
         for (id, _import) in self.imports.iter() {
             writeln!(f, "import {id}.leo;")?;
         }
+        for (id, _) in self.modules.iter() {
+            writeln!(f, "import {id};")?;
+        }
         for (_, stub) in self.stubs.iter() {
             stub.fmt(f)?;
             writeln!(f,)?;
@@ -60,6 +70,11 @@ impl fmt::Display for Program {
 impl Default for Program {
     /// Constructs an empty program node.
     fn default() -> Self {
-        Self { imports: IndexMap::new(), stubs: IndexMap::new(), program_scopes: IndexMap::new() }
+        Self {
+            imports: IndexMap::new(),
+            modules: IndexMap::new(),
+            stubs: IndexMap::new(),
+            program_scopes: IndexMap::new(),
+        }
     }
 }
@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A Leo module consists of const, struct, and inline function definitions,
+//! which are merged into the program scope of the program that imports it.
+
+use crate::{ConstDeclaration, Function, Struct};
+
+use indexmap::IndexMap;
+use leo_span::{Span, Symbol};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Stores the Leo module abstract syntax tree.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Module {
+    /// A map from the names of the imported modules to the spans of their imports.
+    pub modules: IndexMap<Symbol, Span>,
+    /// A vector of const definitions.
+    pub consts: Vec<(Symbol, ConstDeclaration)>,
+    /// A vector of struct definitions.
+    pub structs: Vec<(Symbol, Struct)>,
+    /// A vector of function definitions.
+    pub functions: Vec<(Symbol, Function)>,
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (id, _) in self.modules.iter() {
+            writeln!(f, "import {id};")?;
+        }
+        for (_, const_) in self.consts.iter() {
+            writeln!(f, "const {const_}")?;
+        }
+        for (_, struct_) in self.structs.iter() {
+            writeln!(f, "{struct_}")?;
+        }
+        for (_, function) in self.functions.iter() {
+            writeln!(f, "{function}")?;
+        }
+        Ok(())
+    }
+}
@@ -21,26 +21,16 @@ use leo_span::Span;
 use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, fmt};
 
-/// A bounded `for` loop statement `for variable in start .. =? stop block`.
+/// A bounded `for` loop statement `for variable: type in range block`.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub struct IterationStatement {
     /// The binding / variable to introduce in the body `block`.
     pub variable: Identifier,
     /// The type of the iteration.
     pub type_: Type,
-    /// The start of the iteration.
-    pub start: Expression,
-    /// The concrete value of `start`.
-    #[serde(skip)]
-    pub start_value: RefCell<Option<Value>>,
-    /// The end of the iteration, possibly `inclusive`.
-    pub stop: Expression,
-    /// The concrete value of `stop`.
-    #[serde(skip)]
-    pub stop_value: RefCell<Option<Value>>,
-    /// Whether `stop` is inclusive or not.
-    /// Signified with `=` when parsing.
-    pub inclusive: bool,
+    /// The values the variable is bound to.
+    #[serde(flatten)]
+    pub range: IterationRange,
     /// The block to run on each iteration.
     pub block: Block,
     /// The span from `for` to `block`.
@@ -49,10 +39,57 @@ pub struct IterationStatement {
     pub id: NodeID,
 }
 
+/// The values iterated over by a `for` loop.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[serde(untagged)]
+pub enum IterationRange {
+    /// A range of integers `start .. =? stop`, optionally written `(start .. =? stop).step(step)`.
+    /// The range is descending if `start` is greater than `stop`.
+    Range {
+        /// The start of the iteration.
+        start: Expression,
+        /// The concrete value of `start`.
+        #[serde(skip)]
+        start_value: RefCell<Option<Value>>,
+        /// The end of the iteration, possibly `inclusive`.
+        stop: Expression,
+        /// The concrete value of `stop`.
+        #[serde(skip)]
+        stop_value: RefCell<Option<Value>>,
+        /// Whether `stop` is inclusive or not.
+        /// Signified with `=` when parsing.
+        inclusive: bool,
+        /// The distance between consecutive values, one if not given.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        step: Option<Expression>,
+        /// The concrete value of `step`.
+        #[serde(skip)]
+        step_value: RefCell<Option<Value>>,
+    },
+    /// The elements of an array, in order.
+    Array {
+        /// The array to iterate over.
+        array: Expression,
+    },
+}
+
 impl fmt::Display for IterationStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let eq = if self.inclusive { "=" } else { "" };
-        write!(f, "for {} in {}..{eq}{} {}", self.variable, self.start, self.stop, self.block)
+        write!(f, "for {} in {} {}", self.variable, self.range, self.block)
+    }
+}
+
+impl fmt::Display for IterationRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Range { start, stop, inclusive, step: None, .. } => {
+                write!(f, "{start}..{}{stop}", if *inclusive { "=" } else { "" })
+            }
+            Self::Range { start, stop, inclusive, step: Some(step), .. } => {
+                write!(f, "({start}..{}{stop}).step({step})", if *inclusive { "=" } else { "" })
+            }
+            Self::Array { array } => write!(f, "{array}"),
+        }
     }
 }
 
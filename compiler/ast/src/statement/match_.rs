@@ -0,0 +1,92 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Block, Expression, Identifier, Node, NodeID};
+use leo_span::Span;
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A `match expression { arms }` statement, which selects a block based on the variant of an enum.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct MatchStatement {
+    /// The enum-typed expression being matched.
+    pub expression: Expression,
+    /// The arms of the match, in order.
+    pub arms: Vec<MatchArm>,
+    /// The span from `match` to the closing brace.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "match {} {{", self.expression)?;
+        for arm in self.arms.iter() {
+            writeln!(f, "{arm}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+crate::simple_node_impl!(MatchStatement);
+
+/// An arm of a match statement, e.g. `Status::Filled(amount) => { ... }`.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct MatchArm {
+    /// The pattern of the arm.
+    pub pattern: MatchPattern,
+    /// The block to evaluate if the pattern matches.
+    pub block: Block,
+    /// The span of the arm.
+    pub span: Span,
+    /// The ID of the node.
+    pub id: NodeID,
+}
+
+impl fmt::Display for MatchArm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} => {}", self.pattern, self.block)
+    }
+}
+
+crate::simple_node_impl!(MatchArm);
+
+/// The pattern of a match arm.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum MatchPattern {
+    /// A variant of an enum, e.g. `Status::Pending` or `Status::Filled(amount)`.
+    /// The binding, if any, names the payload of the variant, and is `None` for `_`.
+    Variant { enum_name: Identifier, variant: Identifier, binding: Option<Identifier>, has_payload: bool },
+    /// The wildcard pattern `_`, which matches any variant.
+    Wildcard,
+}
+
+impl fmt::Display for MatchPattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatchPattern::Variant { enum_name, variant, binding: Some(binding), .. } => {
+                write!(f, "{enum_name}::{variant}({binding})")
+            }
+            MatchPattern::Variant { enum_name, variant, has_payload: true, .. } => {
+                write!(f, "{enum_name}::{variant}(_)")
+            }
+            MatchPattern::Variant { enum_name, variant, .. } => write!(f, "{enum_name}::{variant}"),
+            MatchPattern::Wildcard => write!(f, "_"),
+        }
+    }
+}
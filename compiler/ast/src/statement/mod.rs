@@ -41,6 +41,9 @@ pub use expression::*;
 pub mod iteration;
 pub use iteration::*;
 
+pub mod match_;
+pub use match_::*;
+
 pub mod return_;
 pub use return_::*;
 
@@ -72,6 +75,8 @@ pub enum Statement {
     Expression(ExpressionStatement),
     /// A `for` statement.
     Iteration(Box<IterationStatement>),
+    /// A `match` statement.
+    Match(MatchStatement),
     /// A return statement `return expr;`.
     Return(ReturnStatement),
 }
@@ -95,6 +100,7 @@ impl fmt::Display for Statement {
             Statement::Definition(x) => x.fmt(f),
             Statement::Expression(x) => x.fmt(f),
             Statement::Iteration(x) => x.fmt(f),
+            Statement::Match(x) => x.fmt(f),
             Statement::Return(x) => x.fmt(f),
         }
     }
@@ -113,6 +119,7 @@ impl Node for Statement {
             Definition(n) => n.span(),
             Expression(n) => n.span(),
             Iteration(n) => n.span(),
+            Match(n) => n.span(),
             Return(n) => n.span(),
         }
     }
@@ -129,6 +136,7 @@ impl Node for Statement {
             Definition(n) => n.set_span(span),
             Expression(n) => n.set_span(span),
             Iteration(n) => n.set_span(span),
+            Match(n) => n.set_span(span),
             Return(n) => n.set_span(span),
         }
     }
@@ -145,6 +153,7 @@ impl Node for Statement {
             Definition(n) => n.id(),
             Expression(n) => n.id(),
             Iteration(n) => n.id(),
+            Match(n) => n.id(),
             Return(n) => n.id(),
         }
     }
@@ -161,6 +170,7 @@ impl Node for Statement {
             Definition(n) => n.set_id(id),
             Expression(n) => n.set_id(id),
             Iteration(n) => n.set_id(id),
+            Match(n) => n.set_id(id),
             Return(n) => n.set_id(id),
         }
     }
@@ -115,7 +115,7 @@ impl<N: Network> From<&RecordType<N>> for Struct {
             identifier: Identifier::from(input.name()),
             members: [
                 vec![Member {
-                    mode: if input.owner().is_private() { Mode::Public } else { Mode::Private },
+                    mode: if input.owner().is_public() { Mode::Public } else { Mode::Private },
                     identifier: Identifier::new(Symbol::intern("owner"), Default::default()),
                     type_: Type::Address,
                     span: Default::default(),
@@ -124,16 +124,19 @@ impl<N: Network> From<&RecordType<N>> for Struct {
                 input
                     .entries()
                     .iter()
-                    .map(|(id, entry)| Member {
-                        mode: if input.owner().is_public() { Mode::Public } else { Mode::Private },
-                        identifier: Identifier::from(id),
-                        type_: match entry {
-                            Public(t) => Type::from(t),
-                            Private(t) => Type::from(t),
-                            Constant(t) => Type::from(t),
-                        },
-                        span: Default::default(),
-                        id: Default::default(),
+                    .map(|(id, entry)| {
+                        let (mode, type_) = match entry {
+                            Public(t) => (Mode::Public, Type::from(t)),
+                            Private(t) => (Mode::Private, Type::from(t)),
+                            Constant(t) => (Mode::Constant, Type::from(t)),
+                        };
+                        Member {
+                            mode,
+                            identifier: Identifier::from(id),
+                            type_,
+                            span: Default::default(),
+                            id: Default::default(),
+                        }
                     })
                     .collect_vec(),
             ]
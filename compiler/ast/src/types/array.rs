@@ -14,24 +14,48 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{NonNegativeNumber, Type};
+use crate::{Identifier, NonNegativeNumber, Type};
 use snarkvm::console::program::ArrayType as ConsoleArrayType;
 
 use serde::{Deserialize, Serialize};
 use snarkvm::prelude::Network;
 use std::fmt;
 
+/// The length of an array type.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ArrayLength {
+    /// A fixed length, e.g. `4` in `[u8; 4]`.
+    Fixed(NonNegativeNumber),
+    /// A const parameter of a generic `inline` function, e.g. `N` in `[u8; N]`.
+    Const(Identifier),
+}
+
+impl fmt::Display for ArrayLength {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Fixed(length) => write!(f, "{length}"),
+            Self::Const(parameter) => write!(f, "{parameter}"),
+        }
+    }
+}
+
 /// An array type.
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ArrayType {
     element_type: Box<Type>,
-    length: NonNegativeNumber,
+    length: ArrayLength,
 }
 
 impl ArrayType {
     /// Creates a new array type.
     pub fn new(element: Type, length: NonNegativeNumber) -> Self {
-        Self { element_type: Box::new(element), length }
+        Self { element_type: Box::new(element), length: ArrayLength::Fixed(length) }
+    }
+
+    /// Creates a new array type whose length is a const parameter, e.g. `[u8; N]`.
+    pub fn with_const_length(element: Type, parameter: Identifier) -> Self {
+        Self { element_type: Box::new(element), length: ArrayLength::Const(parameter) }
     }
 
     /// Returns the element type of the array.
@@ -40,8 +64,19 @@ impl ArrayType {
     }
 
     /// Returns the length of the array.
+    /// Note that monomorphization replaces const parameters with fixed lengths before the length is needed.
     pub fn length(&self) -> usize {
-        self.length.value()
+        match &self.length {
+            ArrayLength::Fixed(length) => length.value(),
+            ArrayLength::Const(parameter) => {
+                unreachable!("The length of the array is the const parameter `{parameter}`")
+            }
+        }
+    }
+
+    /// Returns the length of the array as it is written in the source.
+    pub fn declared_length(&self) -> &ArrayLength {
+        &self.length
     }
 
     /// Returns the base element type of the array.
@@ -57,7 +92,7 @@ impl<N: Network> From<&ConsoleArrayType<N>> for ArrayType {
     fn from(array_type: &ConsoleArrayType<N>) -> Self {
         Self {
             element_type: Box::new(Type::from(array_type.next_element_type())),
-            length: NonNegativeNumber::from(array_type.length().to_string().replace("u32", "")),
+            length: ArrayLength::Fixed(NonNegativeNumber::from(array_type.length().to_string().replace("u32", ""))),
         }
     }
 }
@@ -28,9 +28,6 @@ use std::{
 
 // TODO: Consider refactoring this module to use the console implementations from snarkVM.
 
-// This is temporary since the currently unused code is used in constant folding.
-#[allow(dead_code)]
-
 // Macro for making implementing unary operations over appropriate types easier.
 macro_rules! implement_const_unary {
     (
@@ -71,10 +68,8 @@ macro_rules! implement_const_unary {
             l: $logic:expr
         ]),+]
     ) => {
-        // TODO: This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
         #[allow(clippy::redundant_closure_call)]
-        pub(crate) fn $name(self, span: Span) -> Result<Self> {
+        pub fn $name(self, span: Span) -> Result<Self> {
             use Value::*;
 
             match self {
@@ -158,10 +153,8 @@ macro_rules! implement_const_binary {
             logic: $logic:expr
         ]),+]
     ) => {
-        // This is temporary since the currently unused code is used in constant folding.
-        #[allow(dead_code)]
         #[allow(clippy::redundant_closure_call)]
-        pub(crate) fn $name(self, other: Self, span: Span) -> Result<Self> {
+        pub fn $name(self, other: Self, span: Span) -> Result<Self> {
             use Value::*;
 
             match (self, other) {
@@ -568,6 +561,43 @@ impl Value {
         ]
     );
 
+    implement_const_binary!(
+        @overflowing
+        name: rem,
+        method: checked_rem,
+        string: "%",
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
+    implement_const_binary!(
+        @non-overflowing
+        name: rem_wrapped,
+        method: wrapping_rem,
+        patterns: [
+            [I8, [I8], I8, i8, i8],
+            [I16, [I16], I16, i16, i16],
+            [I32, [I32], I32, i32, i32],
+            [I64, [I64], I64, i64, i64],
+            [I128, [I128], I128, i128, i128],
+            [U8, [U8], U8, u8, u8],
+            [U16, [U16], U16, u16, u16],
+            [U32, [U32], U32, u32, u32],
+            [U64, [U64], U64, u64, u64],
+            [U128, [U128], U128, u128, u128]
+        ]
+    );
+
     implement_const_binary!(
         @overflowing
         name: shl,
@@ -704,9 +734,49 @@ impl Value {
         ]
     );
 
-    // TODO: This is temporary since the currently unused code is used in constant folding.
-    #[allow(dead_code)]
-    pub(crate) fn is_supported_const_fold_type(&self) -> bool {
+    /// Casts an integer value to the given integer type.
+    /// Fails if the value cannot be represented by the type.
+    pub fn cast(self, integer_type: &IntegerType, span: Span) -> Result<Self> {
+        use Value::*;
+
+        // Integers are represented as `i128`s, unless they are too large, in which case they are represented as `u128`s.
+        let integer: Result<i128, u128> = match &self {
+            I8(v, _) => Ok(*v as i128),
+            I16(v, _) => Ok(*v as i128),
+            I32(v, _) => Ok(*v as i128),
+            I64(v, _) => Ok(*v as i128),
+            I128(v, _) => Ok(*v),
+            U8(v, _) => Ok(*v as i128),
+            U16(v, _) => Ok(*v as i128),
+            U32(v, _) => Ok(*v as i128),
+            U64(v, _) => Ok(*v as i128),
+            U128(v, _) => i128::try_from(*v).map_err(|_| *v),
+            // Unreachable because type checking should have already caught this and errored out.
+            s => unreachable!("Const operation not supported {}.cast()", type_name(s)),
+        };
+        let overflow =
+            || -> LeoError { FlattenError::cast_overflow(&self, Type::from(&self), integer_type, span).into() };
+        let signed = |integer: Result<i128, u128>| integer.ok();
+        let unsigned = |integer: Result<i128, u128>| match integer {
+            Ok(integer) => u128::try_from(integer).ok(),
+            Err(integer) => Some(integer),
+        };
+
+        Ok(match integer_type {
+            IntegerType::I8 => I8(signed(integer).and_then(|v| v.try_into().ok()).ok_or_else(overflow)?, span),
+            IntegerType::I16 => I16(signed(integer).and_then(|v| v.try_into().ok()).ok_or_else(overflow)?, span),
+            IntegerType::I32 => I32(signed(integer).and_then(|v| v.try_into().ok()).ok_or_else(overflow)?, span),
+            IntegerType::I64 => I64(signed(integer).and_then(|v| v.try_into().ok()).ok_or_else(overflow)?, span),
+            IntegerType::I128 => I128(signed(integer).ok_or_else(overflow)?, span),
+            IntegerType::U8 => U8(unsigned(integer).and_then(|v| v.try_into().ok()).ok_or_else(overflow)?, span),
+            IntegerType::U16 => U16(unsigned(integer).and_then(|v| v.try_into().ok()).ok_or_else(overflow)?, span),
+            IntegerType::U32 => U32(unsigned(integer).and_then(|v| v.try_into().ok()).ok_or_else(overflow)?, span),
+            IntegerType::U64 => U64(unsigned(integer).and_then(|v| v.try_into().ok()).ok_or_else(overflow)?, span),
+            IntegerType::U128 => U128(unsigned(integer).ok_or_else(overflow)?, span),
+        })
+    }
+
+    pub fn is_supported_const_fold_type(&self) -> bool {
         use Value::*;
         matches!(
             self,
@@ -899,8 +969,7 @@ impl TryFrom<&Literal> for Value {
 }
 
 impl Literal {
-    #[allow(unused)]
-    fn from_value(v: Value, id: NodeID) -> Self {
+    pub fn from_value(v: Value, id: NodeID) -> Self {
         use Value::*;
         match v {
             Input(_, _) => todo!("We need to test if this is hittable"),
@@ -20,14 +20,17 @@
 pub use leo_ast::Ast;
 use leo_ast::{NodeBuilder, Program, Stub};
 use leo_errors::{emitter::Handler, CompilerError, Result};
-pub use leo_passes::SymbolTable;
 use leo_passes::*;
+pub use leo_passes::{InstructionSourceMap, InstructionSpans, Lint, LintConfig, SymbolTable};
 use leo_span::{source_map::FileName, symbol::with_session_globals, Symbol};
 
 use sha2::{Digest, Sha256};
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
-use crate::CompilerOptions;
+use crate::{merge_modules, CompilerOptions};
 use indexmap::{IndexMap, IndexSet};
 
 /// The primary entry point of the Leo compiler.
@@ -55,6 +58,8 @@ pub struct Compiler<'a> {
     type_table: TypeTable,
     /// The stubs for imported programs. Produced by `Retriever` module.
     import_stubs: IndexMap<Symbol, Stub>,
+    /// The spans of the Leo statements that instructions in the generated bytecode were produced from.
+    pub instruction_spans: InstructionSpans,
 }
 
 impl<'a> Compiler<'a> {
@@ -83,6 +88,7 @@ impl<'a> Compiler<'a> {
             assigner,
             import_stubs,
             type_table,
+            instruction_spans: InstructionSpans::default(),
         }
     }
 
@@ -122,6 +128,10 @@ impl<'a> Compiler<'a> {
             .into());
         }
 
+        // Merge the modules imported by the program, which are in the same directory as the main file.
+        let directory = self.main_file_path.parent().map(Path::to_path_buf).unwrap_or_default();
+        merge_modules(self.handler, &self.node_builder, &mut self.ast.ast, &directory)?;
+
         if self.compiler_options.output.initial_ast {
             self.write_ast_to_json("initial_ast.json")?;
         }
@@ -138,6 +148,18 @@ impl<'a> Compiler<'a> {
         self.parse_program_from_string(&program_string, FileName::Real(self.main_file_path.clone()))
     }
 
+    /// Runs the enum lowering pass.
+    pub fn enum_lowering_pass(&mut self) -> Result<()> {
+        self.ast = EnumLowerer::do_pass((std::mem::take(&mut self.ast), self.handler, &self.node_builder))?;
+        Ok(())
+    }
+
+    /// Runs the monomorphization pass.
+    pub fn monomorphization_pass(&mut self) -> Result<()> {
+        self.ast = Monomorphizer::do_pass((std::mem::take(&mut self.ast), self.handler, &self.node_builder))?;
+        Ok(())
+    }
+
     /// Runs the symbol table pass.
     pub fn symbol_table_pass(&self) -> Result<SymbolTable> {
         let symbol_table = SymbolTableCreator::do_pass((&self.ast, self.handler))?;
@@ -157,6 +179,22 @@ impl<'a> Compiler<'a> {
         Ok((symbol_table, struct_graph, call_graph))
     }
 
+    /// Runs the linting pass.
+    pub fn linting_pass(&self, symbol_table: &SymbolTable, call_graph: &CallGraph) -> Result<()> {
+        if let Some(config) = &self.compiler_options.build.lints {
+            Linter::do_pass((&self.ast, self.handler, symbol_table, call_graph, config))?;
+        }
+        Ok(())
+    }
+
+    /// Runs the privacy analysis pass.
+    pub fn privacy_analysis_pass(&self, symbol_table: &SymbolTable) -> Result<()> {
+        if self.compiler_options.build.check_privacy {
+            PrivacyAnalyzer::do_pass((&self.ast, self.handler, symbol_table, &self.type_table))?;
+        }
+        Ok(())
+    }
+
     /// Runs the loop unrolling pass.
     pub fn loop_unrolling_pass(&mut self, symbol_table: SymbolTable) -> Result<SymbolTable> {
         let (ast, symbol_table) = Unroller::do_pass((
@@ -196,6 +234,17 @@ impl<'a> Compiler<'a> {
         Ok(())
     }
 
+    /// Runs the constant folding pass.
+    pub fn constant_folding_pass(&mut self) -> Result<()> {
+        self.ast = ConstantFolder::do_pass((std::mem::take(&mut self.ast), self.handler))?;
+
+        if self.compiler_options.output.const_folded_ast {
+            self.write_ast_to_json("const_folded_ast.json")?;
+        }
+
+        Ok(())
+    }
+
     /// Runs the flattening pass.
     pub fn flattening_pass(&mut self, symbol_table: &SymbolTable) -> Result<()> {
         self.ast = Flattener::do_pass((
@@ -267,19 +316,42 @@ impl<'a> Compiler<'a> {
         struct_graph: &StructGraph,
         call_graph: &CallGraph,
     ) -> Result<String> {
-        CodeGenerator::do_pass((&self.ast, symbol_table, &self.type_table, struct_graph, call_graph, &self.ast.ast))
+        let (bytecode, instruction_spans) = CodeGenerator::do_pass((
+            &self.ast,
+            symbol_table,
+            &self.type_table,
+            struct_graph,
+            call_graph,
+            &self.ast.ast,
+        ))?;
+        self.instruction_spans = instruction_spans;
+        Ok(bytecode)
     }
 
     /// Runs the compiler stages.
     pub fn compiler_stages(&mut self) -> Result<(SymbolTable, StructGraph, CallGraph)> {
+        self.enum_lowering_pass()?;
+        self.monomorphization_pass()?;
+
         let st = self.symbol_table_pass()?;
         let (st, struct_graph, call_graph) = self.type_checker_pass(st)?;
 
+        self.linting_pass(&st, &call_graph)?;
+
+        // Test transitions are type checked, but only compiled when tests are enabled.
+        if !self.compiler_options.build.tests_enabled {
+            self.remove_test_functions();
+        }
+
+        self.privacy_analysis_pass(&st)?;
+
         // TODO: Make this pass optional.
         let st = self.loop_unrolling_pass(st)?;
 
         self.static_single_assignment_pass(&st)?;
 
+        self.constant_folding_pass()?;
+
         self.flattening_pass(&st)?;
 
         self.destructuring_pass()?;
@@ -304,6 +376,25 @@ impl<'a> Compiler<'a> {
         Ok(bytecode)
     }
 
+    /// Parses and type checks the Leo program, without lowering it into Aleo instructions.
+    /// The type-checked AST, in which enums are lowered and generic functions are monomorphized, is left in `self.ast`.
+    pub fn check(&mut self) -> Result<()> {
+        self.parse_program()?;
+        self.add_import_stubs()?;
+        self.enum_lowering_pass()?;
+        self.monomorphization_pass()?;
+        let st = self.symbol_table_pass()?;
+        self.type_checker_pass(st)?;
+        Ok(())
+    }
+
+    /// Removes the `@test` transitions from the program.
+    fn remove_test_functions(&mut self) {
+        self.ast.ast.program_scopes.values_mut().for_each(|program_scope| {
+            program_scope.functions.retain(|(_, function)| !function.is_test());
+        });
+    }
+
     /// Writes the AST to a JSON file.
     fn write_ast_to_json(&self, file_suffix: &str) -> Result<()> {
         // Remove `Span`s if they are not enabled.
@@ -22,5 +22,8 @@
 mod compiler;
 pub use compiler::*;
 
+mod modules;
+pub use modules::*;
+
 mod options;
 pub use options::*;
@@ -0,0 +1,101 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The modules of a Leo program, which are merged into its program scope before it is checked.
+
+use leo_ast::{Module, NodeBuilder, Program};
+use leo_errors::{emitter::Handler, CompilerError, Result};
+use leo_span::{source_map::FileName, symbol::with_session_globals, Span, Symbol};
+
+use indexmap::IndexMap;
+use std::{fs, path::Path};
+
+/// Parses the modules imported by the program, and the modules they import, from the given directory.
+/// Their items are merged into the program scope, so that the rest of the compiler sees a single program.
+/// Note that a module is merged before the modules and the program that import it.
+pub fn merge_modules(
+    handler: &Handler,
+    node_builder: &NodeBuilder,
+    program: &mut Program,
+    directory: &Path,
+) -> Result<()> {
+    let mut loader = ModuleLoader { handler, node_builder, directory, loaded: IndexMap::new(), stack: Vec::new() };
+    for (name, span) in std::mem::take(&mut program.modules) {
+        loader.load(name, span)?;
+    }
+
+    // Note that parsing enforces that there is exactly one program scope in a file.
+    let Some(program_scope) = program.program_scopes.values_mut().next() else {
+        return Ok(());
+    };
+    let (mut consts, mut structs, mut functions) = (Vec::new(), Vec::new(), Vec::new());
+    for module in loader.loaded.into_values() {
+        consts.extend(module.consts);
+        structs.extend(module.structs);
+        functions.extend(module.functions);
+    }
+    consts.append(&mut program_scope.consts);
+    structs.append(&mut program_scope.structs);
+    functions.append(&mut program_scope.functions);
+    program_scope.consts = consts;
+    program_scope.structs = structs;
+    program_scope.functions = functions;
+
+    Ok(())
+}
+
+/// Loads the modules imported by a program, in the order in which they are merged.
+struct ModuleLoader<'a> {
+    /// The handler used to report errors while parsing the modules.
+    handler: &'a Handler,
+    /// The node builder of the program, so that the nodes of the modules have unique ids.
+    node_builder: &'a NodeBuilder,
+    /// The directory containing the modules.
+    directory: &'a Path,
+    /// The modules loaded so far, each after the modules it imports.
+    loaded: IndexMap<Symbol, Module>,
+    /// The modules being loaded, each imported by the one before it.
+    stack: Vec<Symbol>,
+}
+
+impl ModuleLoader<'_> {
+    /// Loads the module with the given name, after the modules it imports.
+    fn load(&mut self, name: Symbol, span: Span) -> Result<()> {
+        if self.loaded.contains_key(&name) {
+            return Ok(());
+        }
+        if let Some(index) = self.stack.iter().position(|module| *module == name) {
+            let cycle = self.stack[index..].iter().chain([&name]).map(|module| module.to_string()).collect::<Vec<_>>();
+            return Err(CompilerError::cyclic_module_imports(cycle.join(" -> "), span).into());
+        }
+
+        let path = self.directory.join(format!("{name}.leo"));
+        let source =
+            fs::read_to_string(&path).map_err(|_| CompilerError::module_not_found(name, path.display(), span))?;
+        let source_file = with_session_globals(|s| s.source_map.new_source(&source, FileName::Real(path)));
+        let module =
+            leo_parser::parse_module(self.handler, self.node_builder, &source_file.src, source_file.start_pos)?;
+
+        self.stack.push(name);
+        for (import, span) in module.modules.iter() {
+            self.load(*import, *span)?;
+        }
+        self.stack.pop();
+
+        self.loaded.insert(name, module);
+        Ok(())
+    }
+}
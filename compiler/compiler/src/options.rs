@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
+use crate::LintConfig;
+
 // NOTE: If compiler passes are made optional, pass preconditions and invariants may not necessarily hold true.
 
 #[derive(Clone, Default)]
@@ -28,6 +30,12 @@ pub struct CompilerOptions {
 pub struct BuildOptions {
     /// Whether to enable dead code elimination.
     pub dce_enabled: bool,
+    /// Whether to keep `@test` transitions in the compiled program.
+    pub tests_enabled: bool,
+    /// Whether to warn when private data flows into public outputs, finalize arguments, or mappings.
+    pub check_privacy: bool,
+    /// The lints to check, if linting is enabled.
+    pub lints: Option<LintConfig>,
 }
 
 #[derive(Clone, Default)]
@@ -48,6 +56,8 @@ pub struct OutputOptions {
     pub unrolled_ast: bool,
     /// If enabled writes the AST after static single assignment.
     pub ssa_ast: bool,
+    /// If enabled writes the AST after constant folding.
+    pub const_folded_ast: bool,
     /// If enabled writes the AST after flattening.
     pub flattened_ast: bool,
     /// If enabled writes the AST after destructuring.
@@ -65,6 +65,7 @@ struct CompileOutput {
     pub initial_ast: String,
     pub unrolled_ast: String,
     pub ssa_ast: String,
+    pub const_folded_ast: String,
     pub flattened_ast: String,
     pub destructured_ast: String,
     pub inlined_ast: String,
@@ -94,6 +95,7 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
                 initial_ast: true,
                 unrolled_ast: true,
                 ssa_ast: true,
+                const_folded_ast: true,
                 flattened_ast: true,
                 destructured_ast: true,
                 inlined_ast: true,
@@ -117,7 +119,16 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
         handler.extend_if_error(package.get_process().map_err(LeoError::Anyhow))?;
 
         // Hash the ast files.
-        let (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast) = hash_asts();
+        let (
+            initial_ast,
+            unrolled_ast,
+            ssa_ast,
+            const_folded_ast,
+            flattened_ast,
+            destructured_ast,
+            inlined_ast,
+            dce_ast,
+        ) = hash_asts();
 
         // Hash the symbol tables.
         let (initial_symbol_table, type_checked_symbol_table, unrolled_symbol_table) = hash_symbol_tables();
@@ -134,6 +145,7 @@ fn run_test(test: Test, handler: &Handler, buf: &BufferEmitter) -> Result<Value,
             initial_ast,
             unrolled_ast,
             ssa_ast,
+            const_folded_ast,
             flattened_ast,
             destructured_ast,
             inlined_ast,
@@ -234,8 +234,16 @@ impl<'a> StatementVisitor<'a> for CheckUniqueNodeIds<'a> {
     fn visit_iteration(&mut self, input: &'a IterationStatement) {
         self.visit_identifier(&input.variable, &Default::default());
         self.check_ty(&input.type_);
-        self.visit_expression(&input.start, &Default::default());
-        self.visit_expression(&input.stop, &Default::default());
+        match &input.range {
+            IterationRange::Range { start, stop, step, .. } => {
+                self.visit_expression(start, &Default::default());
+                self.visit_expression(stop, &Default::default());
+                if let Some(step) = step {
+                    self.visit_expression(step, &Default::default());
+                }
+            }
+            IterationRange::Array { array } => self.visit_expression(array, &Default::default()),
+        }
         self.visit_block(&input.block);
         self.check(input.id)
     }
@@ -274,8 +282,11 @@ impl<'a> ProgramVisitor<'a> for CheckUniqueNodeIds<'a> {
     fn visit_function(&mut self, input: &'a Function) {
         let Function { annotations, identifier, input, output, block, finalize, id, .. } = input;
         // Check the annotations.
-        for Annotation { identifier, id, .. } in annotations {
+        for Annotation { identifier, arguments, id, .. } in annotations {
             self.visit_identifier(identifier, &Default::default());
+            arguments.iter().for_each(|argument| {
+                self.visit_identifier(argument, &Default::default());
+            });
             self.check(*id);
         }
         // Check the function name.
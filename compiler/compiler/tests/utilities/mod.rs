@@ -17,7 +17,7 @@
 mod check_unique_node_ids;
 use check_unique_node_ids::*;
 
-use leo_compiler::{BuildOptions, Compiler, CompilerOptions};
+use leo_compiler::{BuildOptions, Compiler, CompilerOptions, LintConfig};
 use leo_errors::{
     emitter::{Buffer, Emitter, Handler},
     LeoError,
@@ -45,16 +45,17 @@ pub type Network = Testnet3;
 #[allow(unused)]
 pub type Aleo = snarkvm::circuit::AleoV0;
 
-pub fn hash_asts() -> (String, String, String, String, String, String, String) {
+pub fn hash_asts() -> (String, String, String, String, String, String, String, String) {
     let initial_ast = hash_file("/tmp/output/test.initial_ast.json");
     let unrolled_ast = hash_file("/tmp/output/test.unrolled_ast.json");
     let ssa_ast = hash_file("/tmp/output/test.ssa_ast.json");
+    let const_folded_ast = hash_file("/tmp/output/test.const_folded_ast.json");
     let flattened_ast = hash_file("/tmp/output/test.flattened_ast.json");
     let destructured_ast = hash_file("/tmp/output/test.destructured_ast.json");
     let inlined_ast = hash_file("/tmp/output/test.inlined_ast.json");
     let dce_ast = hash_file("/tmp/output/test.dce_ast.json");
 
-    (initial_ast, unrolled_ast, ssa_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast)
+    (initial_ast, unrolled_ast, ssa_ast, const_folded_ast, flattened_ast, destructured_ast, inlined_ast, dce_ast)
 }
 
 pub fn hash_symbol_tables() -> (String, String, String) {
@@ -77,6 +78,18 @@ pub fn get_cwd_option(test: &Test) -> Option<PathBuf> {
 }
 
 pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
+    // Whether the privacy analysis is enabled, e.g. `check_privacy: true`.
+    let check_privacy = test_config
+        .extra
+        .get("check_privacy")
+        .map_or(false, |value| value.as_bool().expect("Expected `check_privacy` to be a boolean."));
+    // Whether all lints are checked, e.g. `lint: true`.
+    let lints = test_config
+        .extra
+        .get("lint")
+        .map_or(false, |value| value.as_bool().expect("Expected `lint` to be a boolean."))
+        .then(LintConfig::default);
+
     match test_config.extra.get("configs") {
         Some(configs) => {
             // Parse the sequence of compiler configurations.
@@ -97,11 +110,14 @@ pub fn get_build_options(test_config: &TestConfig) -> Vec<BuildOptions> {
                             .expect("Expected key `dce_enabled`")
                             .as_bool()
                             .expect("Expected value to be a boolean."),
+                        tests_enabled: false,
+                        check_privacy,
+                        lints: lints.clone(),
                     }
                 })
                 .collect()
         }
-        None => vec![BuildOptions { dce_enabled: true }],
+        None => vec![BuildOptions { dce_enabled: true, tests_enabled: false, check_privacy, lints }],
     }
 }
 
@@ -231,6 +247,9 @@ pub fn temp_dir() -> PathBuf {
 }
 
 pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, LeoError> {
+    parsed.enum_lowering_pass()?;
+    parsed.monomorphization_pass()?;
+
     let st = parsed.symbol_table_pass()?;
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
@@ -239,10 +258,16 @@ pub fn compile_and_process<'a>(parsed: &'a mut Compiler<'a>) -> Result<String, L
 
     CheckUniqueNodeIds::new().visit_program(&parsed.ast.ast);
 
+    parsed.linting_pass(&st, &call_graph)?;
+
+    parsed.privacy_analysis_pass(&st)?;
+
     let st = parsed.loop_unrolling_pass(st)?;
 
     parsed.static_single_assignment_pass(&st)?;
 
+    parsed.constant_folding_pass()?;
+
     parsed.flattening_pass(&st)?;
 
     parsed.destructuring_pass()?;
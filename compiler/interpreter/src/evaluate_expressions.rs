@@ -0,0 +1,267 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{operations, Interpreter, Value};
+
+use leo_ast::{
+    AccessExpression,
+    AssociatedFunction,
+    BinaryOperation,
+    CallExpression,
+    Expression,
+    Identifier,
+    Node,
+    StructExpression,
+    Type,
+    UnaryOperation,
+};
+use leo_errors::{InterpreterError, Result};
+use leo_span::sym;
+
+use snarkvm::console::{
+    program::{Literal, LiteralType},
+    types::{Boolean, U32},
+};
+
+use std::str::FromStr;
+
+impl<'a> Interpreter<'a> {
+    /// Evaluates an expression.
+    pub(crate) fn evaluate_expression(&mut self, expression: &'a Expression) -> Result<Value> {
+        let failed = |error| InterpreterError::failed_to_evaluate(expression, error, expression.span());
+        match expression {
+            Expression::Access(access) => self.evaluate_access(access),
+            Expression::Array(array) => Ok(Value::Array(
+                array.elements.iter().map(|element| self.evaluate_expression(element)).collect::<Result<_>>()?,
+            )),
+            Expression::Binary(binary) => {
+                let left = self.evaluate_expression(&binary.left)?;
+                let right = self.evaluate_expression(&binary.right)?;
+                match (binary.op, left, right) {
+                    // Structs, arrays and tuples may be compared too, so equality is decided on the values.
+                    (BinaryOperation::Eq, left, right) => Ok(boolean(left == right)),
+                    (BinaryOperation::Neq, left, right) => Ok(boolean(left != right)),
+                    (op, Value::Literal(left), Value::Literal(right)) => {
+                        Ok(Value::Literal(operations::binary(op, left, right).map_err(failed)?))
+                    }
+                    _ => unreachable!("Type checking guarantees that the operands of `{}` are literals.", binary.op),
+                }
+            }
+            Expression::Call(call) => self.evaluate_call(call),
+            Expression::Cast(cast) => {
+                let value = self.evaluate_expression(&cast.expression)?;
+                let literal = value.as_literal().expect("Type checking guarantees that only literals are cast.");
+                let literal_type = operations::literal_type(&cast.type_).expect("Casts are to literal types.");
+                Ok(Value::Literal(operations::cast(literal, literal_type).map_err(failed)?))
+            }
+            Expression::Err(_) => unreachable!("Error expressions do not exist in type-checked programs."),
+            Expression::Identifier(identifier) => self.variable(identifier),
+            Expression::Literal(literal) => Ok(Value::Literal(operations::literal(literal).map_err(failed)?)),
+            Expression::Struct(struct_) => self.evaluate_struct(struct_),
+            Expression::Ternary(ternary) => {
+                let condition = self.evaluate_expression(&ternary.condition)?;
+                // Both branches are evaluated, as they are in the compiled program, so that the same operations halt.
+                let if_true = self.evaluate_expression(&ternary.if_true)?;
+                let if_false = self.evaluate_expression(&ternary.if_false)?;
+                match condition.as_bool().expect("Type checking guarantees that the condition is a boolean.") {
+                    true => Ok(if_true),
+                    false => Ok(if_false),
+                }
+            }
+            Expression::Tuple(tuple) => Ok(Value::Tuple(
+                tuple.elements.iter().map(|element| self.evaluate_expression(element)).collect::<Result<_>>()?,
+            )),
+            Expression::Unary(unary) => match self.evaluate_expression(&unary.receiver)? {
+                Value::Literal(receiver) => Ok(Value::Literal(operations::unary(unary.op, receiver).map_err(failed)?)),
+                _ => unreachable!("Type checking guarantees that the operands of unary operations are literals."),
+            },
+            Expression::Unit(_) => Ok(Value::Unit),
+        }
+    }
+
+    /// Evaluates an access expression.
+    fn evaluate_access(&mut self, access: &'a AccessExpression) -> Result<Value> {
+        match access {
+            AccessExpression::Array(access) => {
+                let array = self.evaluate_expression(&access.array)?;
+                let index = self.evaluate_expression(&access.index)?;
+                let Value::Array(elements) = array else {
+                    unreachable!("Type checking guarantees that only arrays are indexed.");
+                };
+                let index = index.as_index().ok_or_else(|| {
+                    InterpreterError::failed_to_evaluate(&access.index, "expected an unsigned integer", access.span)
+                })?;
+                let length = elements.len();
+                elements
+                    .into_iter()
+                    .nth(index)
+                    .ok_or_else(|| InterpreterError::array_index_out_of_bounds(index, length, access.span).into())
+            }
+            AccessExpression::AssociatedConstant(constant) => match (&constant.ty, constant.name.name) {
+                (Type::Group, sym::GEN) => Ok(Value::Literal(operations::group_generator())),
+                _ => unreachable!("Type checking guarantees that `group::GEN` is the only associated constant."),
+            },
+            AccessExpression::AssociatedFunction(function) => self.evaluate_associated_function(function),
+            AccessExpression::Member(access) => match &*access.inner {
+                Expression::Identifier(Identifier { name: sym::SelfLower, .. }) => match access.name.name {
+                    sym::caller | sym::signer => Ok(Value::Literal(Literal::Address(self.caller))),
+                    _ => unreachable!("Type checking guarantees that `self` only has a caller and a signer."),
+                },
+                Expression::Identifier(Identifier { name: sym::block, .. }) => {
+                    Ok(Value::Literal(Literal::U32(U32::new(self.block_height))))
+                }
+                inner => {
+                    let value = self.evaluate_expression(inner)?;
+                    Ok(value.member(access.name.name).expect("Type checking guarantees that members exist.").clone())
+                }
+            },
+            AccessExpression::Tuple(access) => {
+                let value = self.evaluate_expression(&access.tuple)?;
+                Ok(value.element(access.index.value()).expect("Type checking guarantees that elements exist.").clone())
+            }
+        }
+    }
+
+    /// Evaluates a call to a function of the program.
+    fn evaluate_call(&mut self, call: &'a CallExpression) -> Result<Value> {
+        if call.external.is_some() {
+            return Err(InterpreterError::unsupported("calls to other programs", call.span).into());
+        }
+        let Expression::Identifier(name) = &*call.function else {
+            unreachable!("Parsing guarantees that a function name is always an identifier.");
+        };
+        let function = self.function(name.name).expect("Type checking guarantees that called functions exist.");
+        let arguments =
+            call.arguments.iter().map(|argument| self.evaluate_expression(argument)).collect::<Result<_>>()?;
+        let (output, _) = self.call(function, arguments)?;
+        Ok(output)
+    }
+
+    /// Evaluates a struct or record expression, whose members are stored in the order in which they are declared.
+    fn evaluate_struct(&mut self, expression: &'a StructExpression) -> Result<Value> {
+        let struct_ = self.struct_(expression.name.name).expect("Type checking guarantees that structs exist.");
+        let mut members = Vec::with_capacity(struct_.members.len());
+        for member in struct_.members.iter() {
+            let initializer = expression
+                .members
+                .iter()
+                .find(|initializer| initializer.identifier.name == member.name())
+                .expect("Type checking guarantees that every member is initialized.");
+            let value = match &initializer.expression {
+                Some(expression) => self.evaluate_expression(expression)?,
+                None => self.variable(&initializer.identifier)?,
+            };
+            members.push((member.name(), value));
+        }
+        Ok(Value::Struct(expression.name.name, members))
+    }
+
+    /// Evaluates a call to a core function, e.g. `BHP256::hash_to_field(x)` or `Mapping::get(m, k)`.
+    fn evaluate_associated_function(&mut self, function: &'a AssociatedFunction) -> Result<Value> {
+        let Type::Identifier(ty) = &function.ty else {
+            unreachable!("Type checking guarantees that core functions are associated with identifiers.");
+        };
+        if ty.name == sym::Mapping {
+            return self.evaluate_mapping_operation(function);
+        }
+
+        let failed = |error| InterpreterError::failed_to_evaluate(function, error, function.span);
+        let arguments =
+            function.arguments.iter().map(|argument| self.evaluate_expression(argument)).collect::<Result<Vec<_>>>()?;
+        let name = function.name.name.to_string();
+        let literal = match ty.name {
+            sym::group => match (function.name.name, &arguments[0]) {
+                (sym::to_x_coordinate, Value::Literal(group)) => {
+                    operations::unary(UnaryOperation::ToXCoordinate, group.clone())
+                }
+                (sym::to_y_coordinate, Value::Literal(group)) => {
+                    operations::unary(UnaryOperation::ToYCoordinate, group.clone())
+                }
+                _ => unreachable!(
+                    "The only associated functions of `group` are `to_x_coordinate` and `to_y_coordinate`."
+                ),
+            },
+            sym::ChaCha => {
+                let literal_type = match name.trim_start_matches("rand_") {
+                    "bool" => LiteralType::Boolean,
+                    type_ => LiteralType::from_str(type_).expect("The `ChaCha` functions return literals."),
+                };
+                Ok(operations::random(literal_type, &mut self.rng))
+            }
+            sym::signature => operations::verify(&arguments[0], &arguments[1], &arguments[2]),
+            // The remaining core functions are named `<hash|commit>_to_<type>`, e.g. `hash_to_field`.
+            algorithm => {
+                let (operation, type_) = name.split_once("_to_").expect("Core functions are named `<op>_to_<type>`.");
+                let literal_type = LiteralType::from_str(type_).expect("Core functions return literals.");
+                match operation {
+                    "hash" => operations::hash(algorithm, &arguments[0], literal_type),
+                    "commit" => operations::commit(algorithm, &arguments[0], &arguments[1], literal_type),
+                    _ => unreachable!("Type checking guarantees that core functions exist."),
+                }
+            }
+        };
+        Ok(Value::Literal(literal.map_err(failed)?))
+    }
+
+    /// Evaluates an operation on a mapping, using the in-memory mapping store.
+    fn evaluate_mapping_operation(&mut self, function: &'a AssociatedFunction) -> Result<Value> {
+        let mapping = match &function.arguments[0] {
+            Expression::Identifier(mapping) if self.mappings.contains_key(&mapping.name) => mapping,
+            _ => return Err(InterpreterError::unsupported("mappings of other programs", function.span).into()),
+        };
+        let key = self.evaluate_expression(&function.arguments[1])?;
+        let value = match function.arguments.get(2) {
+            Some(value) => Some(self.evaluate_expression(value)?),
+            None => None,
+        };
+
+        let store = self.mappings.get_mut(&mapping.name).expect("The mapping exists, as checked above.");
+        match (function.name.name, value) {
+            (sym::get, None) => store
+                .get(&key)
+                .cloned()
+                .ok_or_else(|| InterpreterError::mapping_key_not_found(mapping, &key, function.span).into()),
+            (sym::get_or_use, Some(default)) => Ok(store.get(&key).cloned().unwrap_or(default)),
+            (sym::set, Some(value)) => {
+                store.insert(key, value);
+                Ok(Value::Unit)
+            }
+            (sym::remove, None) => {
+                store.remove(&key);
+                Ok(Value::Unit)
+            }
+            (sym::contains, None) => Ok(boolean(store.contains_key(&key))),
+            _ => unreachable!("Type checking guarantees that mapping operations have the right number of arguments."),
+        }
+    }
+
+    /// Returns the value of a local variable, or else of a constant of the program.
+    fn variable(&self, identifier: &Identifier) -> Result<Value> {
+        self.frames
+            .last()
+            .and_then(|frame| frame.variables.get(&identifier.name))
+            .or_else(|| self.globals.get(&identifier.name))
+            .cloned()
+            .ok_or_else(|| {
+                InterpreterError::failed_to_evaluate(identifier, "the variable is not defined", identifier.span).into()
+            })
+    }
+}
+
+/// Returns a boolean value.
+fn boolean(value: bool) -> Value {
+    Value::Literal(Literal::Boolean(Boolean::new(value)))
+}
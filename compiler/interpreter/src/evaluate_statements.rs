@@ -0,0 +1,287 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{operations, Interpreter, Step, Value};
+
+use leo_ast::{
+    AccessExpression,
+    AssertVariant,
+    Block,
+    ConsoleFunction,
+    Expression,
+    IterationRange,
+    IterationStatement,
+    Literal,
+    Statement,
+    Type,
+};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{Span, Symbol};
+
+/// How the evaluation continues after a statement.
+pub(crate) enum Flow {
+    /// The next statement is evaluated.
+    Next,
+    /// The function returns the value.
+    Return(Value),
+}
+
+/// A step from a variable to the part of it that is assigned to.
+enum Projection {
+    /// A member of a struct or record.
+    Member(Symbol),
+    /// An element of an array or tuple.
+    Index(usize, Span),
+}
+
+impl<'a> Interpreter<'a> {
+    /// Evaluates the statements of a block, until one of them returns.
+    pub(crate) fn evaluate_block(&mut self, block: &'a Block) -> Result<Flow> {
+        for statement in block.statements.iter() {
+            if let Flow::Return(value) = self.evaluate_statement(statement)? {
+                return Ok(Flow::Return(value));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Evaluates a statement, after showing it to the hook.
+    fn evaluate_statement(&mut self, statement: &'a Statement) -> Result<Flow> {
+        if let Statement::Block(block) = statement {
+            return self.evaluate_block(block);
+        }
+
+        if let Some(hook) = self.hook.as_mut() {
+            let frame = self.frames.last().expect("Statements are only evaluated in functions.");
+            hook.before_statement(&Step {
+                statement,
+                function: frame.function,
+                depth: self.frames.len(),
+                variables: &frame.variables,
+            })?;
+        }
+
+        match statement {
+            Statement::Assert(assert) => {
+                let holds = match &assert.variant {
+                    AssertVariant::Assert(expression) => self.evaluate_bool(expression)?,
+                    AssertVariant::AssertEq(left, right) => {
+                        self.evaluate_expression(left)? == self.evaluate_expression(right)?
+                    }
+                    AssertVariant::AssertNeq(left, right) => {
+                        self.evaluate_expression(left)? != self.evaluate_expression(right)?
+                    }
+                };
+                if !holds {
+                    return Err(InterpreterError::assertion_failed(assert, assert.span).into());
+                }
+            }
+            Statement::Assign(assign) => {
+                let value = self.evaluate_expression(&assign.value)?;
+                self.assign(&assign.place, value)?;
+            }
+            Statement::Block(_) => unreachable!("Blocks are evaluated above."),
+            Statement::Conditional(conditional) => {
+                if self.evaluate_bool(&conditional.condition)? {
+                    return self.evaluate_block(&conditional.then);
+                } else if let Some(otherwise) = &conditional.otherwise {
+                    return self.evaluate_statement(otherwise);
+                }
+            }
+            Statement::Console(console) => {
+                let holds = match &console.function {
+                    ConsoleFunction::Assert(expression) => self.evaluate_bool(expression)?,
+                    ConsoleFunction::AssertEq(left, right) => {
+                        self.evaluate_expression(left)? == self.evaluate_expression(right)?
+                    }
+                    ConsoleFunction::AssertNeq(left, right) => {
+                        self.evaluate_expression(left)? != self.evaluate_expression(right)?
+                    }
+                };
+                if !holds {
+                    return Err(InterpreterError::assertion_failed(&console.function, console.span).into());
+                }
+            }
+            Statement::Const(declaration) => {
+                let value = self.evaluate_expression(&declaration.value)?;
+                self.define(declaration.place.name, value);
+            }
+            Statement::Definition(definition) => {
+                let value = self.evaluate_expression(&definition.value)?;
+                match (&definition.place, value) {
+                    (Expression::Identifier(identifier), value) => self.define(identifier.name, value),
+                    (Expression::Tuple(places), Value::Tuple(values)) => {
+                        for (place, value) in places.elements.iter().zip(values) {
+                            match place {
+                                Expression::Identifier(identifier) => self.define(identifier.name, value),
+                                _ => unreachable!("Type checking guarantees that definitions only bind identifiers."),
+                            }
+                        }
+                    }
+                    _ => unreachable!("Type checking guarantees that tuples are only bound to tuples of identifiers."),
+                }
+            }
+            Statement::Expression(expression) => {
+                self.evaluate_expression(&expression.expression)?;
+            }
+            Statement::Iteration(iteration) => return self.evaluate_iteration(iteration),
+            Statement::Match(_) => unreachable!("Match statements are lowered before type checking completes."),
+            Statement::Return(return_) => {
+                let value = self.evaluate_expression(&return_.expression)?;
+                if let Some(arguments) = &return_.finalize_arguments {
+                    let arguments =
+                        arguments.iter().map(|argument| self.evaluate_expression(argument)).collect::<Result<_>>()?;
+                    self.frames.last_mut().expect("Statements are only evaluated in functions.").finalize_arguments =
+                        Some(arguments);
+                }
+                return Ok(Flow::Return(value));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Evaluates a loop, binding the loop variable to each value of its range or each element of its array in turn.
+    fn evaluate_iteration(&mut self, iteration: &'a IterationStatement) -> Result<Flow> {
+        let values = match &iteration.range {
+            IterationRange::Range { start, stop, inclusive, step, .. } => {
+                self.evaluate_range(iteration, start, stop, *inclusive, step.as_ref())?
+            }
+            IterationRange::Array { array } => match self.evaluate_expression(array)? {
+                Value::Array(elements) => elements,
+                _ => unreachable!("Type checking guarantees that loops iterate over ranges or arrays."),
+            },
+        };
+
+        for value in values {
+            self.define(iteration.variable.name, value);
+            if let Flow::Return(value) = self.evaluate_block(&iteration.block)? {
+                return Ok(Flow::Return(value));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Evaluates the values of the range of a loop, which counts down if `start` is greater than `stop`.
+    fn evaluate_range(
+        &mut self,
+        iteration: &IterationStatement,
+        start: &'a Expression,
+        stop: &'a Expression,
+        inclusive: bool,
+        step: Option<&'a Expression>,
+    ) -> Result<Vec<Value>> {
+        let failed = |error| InterpreterError::failed_to_evaluate(iteration.variable, error, iteration.span);
+        let Type::Integer(integer_type) = iteration.type_ else {
+            unreachable!("Type checking guarantees that loop variables over ranges are integers.");
+        };
+        // Note that the bounds are compared and advanced as `i128`s, so that the loop variable never overflows.
+        let mut bound = |expression: &'a Expression| -> Result<i128> {
+            let value = self.evaluate_expression(expression)?;
+            Ok(value.as_i128().ok_or_else(|| failed(format!("`{value}` is out of range for a loop bound")))?)
+        };
+        let start = bound(start)?;
+        let stop = bound(stop)?;
+        let step = match step {
+            Some(step) => bound(step)?,
+            None => 1,
+        };
+        if step <= 0 {
+            return Err(failed("the loop step must be positive".to_string()).into());
+        }
+
+        let mut values = Vec::new();
+        let mut current = Some(start);
+        while let Some(value) = current {
+            let in_range = match (start > stop, inclusive) {
+                (false, false) => value < stop,
+                (false, true) => value <= stop,
+                (true, false) => value > stop,
+                (true, true) => value >= stop,
+            };
+            if !in_range {
+                break;
+            }
+            let literal = Literal::Integer(integer_type, value.to_string(), Default::default(), Default::default());
+            values.push(Value::Literal(operations::literal(&literal).map_err(|error| failed(error.to_string()))?));
+            current = if start > stop { value.checked_sub(step) } else { value.checked_add(step) };
+        }
+        Ok(values)
+    }
+
+    /// Binds a variable in the current frame.
+    fn define(&mut self, name: Symbol, value: Value) {
+        self.frames.last_mut().expect("Statements are only evaluated in functions.").variables.insert(name, value);
+    }
+
+    /// Assigns a value to a variable, or to a member or element of one.
+    fn assign(&mut self, place: &'a Expression, value: Value) -> Result<()> {
+        // Evaluate the indices first, since they may read the variable being assigned to.
+        let mut projections = Vec::new();
+        let mut root = place;
+        loop {
+            match root {
+                Expression::Identifier(_) => break,
+                Expression::Access(AccessExpression::Member(access)) => {
+                    projections.push(Projection::Member(access.name.name));
+                    root = &access.inner;
+                }
+                Expression::Access(AccessExpression::Tuple(access)) => {
+                    projections.push(Projection::Index(access.index.value(), access.span));
+                    root = &access.tuple;
+                }
+                Expression::Access(AccessExpression::Array(access)) => {
+                    let index = self.evaluate_expression(&access.index)?;
+                    let index = index.as_index().ok_or_else(|| {
+                        InterpreterError::failed_to_evaluate(&access.index, "expected an unsigned integer", access.span)
+                    })?;
+                    projections.push(Projection::Index(index, access.span));
+                    root = &access.array;
+                }
+                _ => unreachable!("Type checking guarantees that only variables and their parts are assigned to."),
+            }
+        }
+        let Expression::Identifier(variable) = root else { unreachable!("The loop above stops at an identifier.") };
+
+        let frame = self.frames.last_mut().expect("Statements are only evaluated in functions.");
+        let mut target = frame.variables.get_mut(&variable.name).ok_or_else(|| {
+            InterpreterError::failed_to_evaluate(variable, "the variable is not defined", variable.span)
+        })?;
+        for projection in projections.into_iter().rev() {
+            target = match projection {
+                Projection::Member(name) => {
+                    target.member_mut(name).expect("Type checking guarantees that members exist.")
+                }
+                Projection::Index(index, span) => {
+                    let length = match target {
+                        Value::Array(elements) | Value::Tuple(elements) => elements.len(),
+                        _ => unreachable!("Type checking guarantees that only arrays and tuples are indexed."),
+                    };
+                    target
+                        .element_mut(index)
+                        .ok_or_else(|| InterpreterError::array_index_out_of_bounds(index, length, span))?
+                }
+            };
+        }
+        *target = value;
+        Ok(())
+    }
+
+    /// Evaluates an expression that type checking guarantees to be a boolean.
+    fn evaluate_bool(&mut self, expression: &'a Expression) -> Result<bool> {
+        let value = self.evaluate_expression(expression)?;
+        Ok(value.as_bool().expect("Type checking guarantees that the expression is a boolean."))
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{evaluate_statements::Flow, operations, CurrentNetwork, Value};
+
+use leo_ast::{Block, Finalize, Function, Input, Program, ProgramScope, Statement, Struct, Type};
+use leo_errors::{InterpreterError, Result};
+use leo_span::{sym, Symbol};
+
+use snarkvm::console::{
+    program::{Entry, Identifier, Literal, Owner, Plaintext, Record, Value as ConsoleValue},
+    types::Address,
+};
+
+use indexmap::IndexMap;
+use rand::SeedableRng;
+use rand_chacha::ChaChaRng;
+use std::str::FromStr;
+
+/// A statement that is about to be evaluated, as observed by a `Hook`.
+pub struct Step<'b> {
+    /// The statement.
+    pub statement: &'b Statement,
+    /// The name of the function, or finalize block, that the statement is in.
+    pub function: Symbol,
+    /// The number of function calls being evaluated, which is `1` for the statements of the transition being run.
+    pub depth: usize,
+    /// The local variables in scope.
+    pub variables: &'b IndexMap<Symbol, Value>,
+}
+
+/// Observes the evaluation of a program, e.g. to pause it at breakpoints.
+pub trait Hook {
+    /// Called before each statement, other than a block, is evaluated.
+    /// Returning an error aborts the evaluation with that error.
+    fn before_statement(&mut self, step: &Step) -> Result<()>;
+}
+
+/// The local variables of a function being evaluated.
+pub(crate) struct Frame {
+    /// The name of the function.
+    pub(crate) function: Symbol,
+    /// The values of the inputs and the variables defined so far.
+    pub(crate) variables: IndexMap<Symbol, Value>,
+    /// The arguments of the finalize block, once the function has returned with `then finalize`.
+    pub(crate) finalize_arguments: Option<Vec<Value>>,
+}
+
+/// Evaluates the transitions of a type-checked Leo program directly on its AST.
+pub struct Interpreter<'a> {
+    /// The type-checked program, including the stubs of its imports.
+    pub(crate) program: &'a Program,
+    /// The scope of the program being interpreted.
+    pub(crate) scope: &'a ProgramScope,
+    /// The values of the constants declared in the program scope.
+    pub(crate) globals: IndexMap<Symbol, Value>,
+    /// The in-memory mapping store that finalize blocks read from and write to.
+    pub(crate) mappings: IndexMap<Symbol, IndexMap<Value, Value>>,
+    /// The call stack.
+    pub(crate) frames: Vec<Frame>,
+    /// The address of `self.caller` and `self.signer`.
+    pub(crate) caller: Address<CurrentNetwork>,
+    /// The value of `block.height`.
+    pub(crate) block_height: u32,
+    /// The random number generator of `ChaCha::rand_*`, which is seeded so that runs are reproducible.
+    pub(crate) rng: ChaChaRng,
+    /// The hook called before each statement.
+    pub(crate) hook: Option<&'a mut dyn Hook>,
+}
+
+impl<'a> Interpreter<'a> {
+    /// Initializes an interpreter for the main program of a type-checked AST, and evaluates its constants.
+    /// The mappings of the program are initially empty.
+    pub fn new(program: &'a Program) -> Result<Self> {
+        let scope = program.program_scopes.values().next().expect("The main program scope always exists.");
+        let mut interpreter = Self {
+            program,
+            scope,
+            globals: IndexMap::new(),
+            mappings: scope.mappings.iter().map(|(name, _)| (*name, IndexMap::new())).collect(),
+            frames: Vec::new(),
+            caller: Address::zero(),
+            block_height: 0,
+            rng: ChaChaRng::seed_from_u64(0),
+            hook: None,
+        };
+        for (name, declaration) in scope.consts.iter() {
+            let value = interpreter.evaluate_expression(&declaration.value)?;
+            interpreter.globals.insert(*name, value);
+        }
+        Ok(interpreter)
+    }
+
+    /// Sets the address of `self.caller` and `self.signer`, which is the zero address by default.
+    pub fn with_caller(mut self, caller: Address<CurrentNetwork>) -> Self {
+        self.caller = caller;
+        self
+    }
+
+    /// Sets the value of `block.height`, which is `0` by default.
+    pub fn with_block_height(mut self, block_height: u32) -> Self {
+        self.block_height = block_height;
+        self
+    }
+
+    /// Sets the hook called before each statement is evaluated.
+    pub fn with_hook(mut self, hook: &'a mut dyn Hook) -> Self {
+        self.hook = Some(hook);
+        self
+    }
+
+    /// Returns the contents of the mappings of the program.
+    pub fn mappings(&self) -> &IndexMap<Symbol, IndexMap<Value, Value>> {
+        &self.mappings
+    }
+
+    /// Runs a function of the program on the given inputs, which are written as they are for `leo run`.
+    /// If the function is a transition with a finalize block, the finalize block is run afterwards.
+    /// The updates of a finalize block to the mappings are only kept if it succeeds.
+    pub fn run(&mut self, name: Symbol, inputs: &[String]) -> Result<Value> {
+        let function =
+            self.function(name).ok_or_else(|| InterpreterError::function_not_found(&self.scope.program_id, name))?;
+        if function.input.len() != inputs.len() {
+            return Err(InterpreterError::incorrect_number_of_inputs(name, function.input.len(), inputs.len()).into());
+        }
+        let arguments = function
+            .input
+            .iter()
+            .zip(inputs)
+            .map(|(input, string)| self.input_value(&input.type_(), string))
+            .collect::<Result<Vec<_>>>()?;
+
+        let (output, finalize_arguments) = self.call(function, arguments)?;
+
+        if let (Some(finalize), Some(arguments)) = (&function.finalize, finalize_arguments) {
+            let mappings = self.mappings.clone();
+            if let Err(error) = self.finalize(finalize, arguments) {
+                self.mappings = mappings;
+                return Err(error);
+            }
+        }
+        Ok(output)
+    }
+
+    /// Returns the function of the program with the given name.
+    pub(crate) fn function(&self, name: Symbol) -> Option<&'a Function> {
+        self.scope.functions.iter().find(|(function, _)| *function == name).map(|(_, function)| function)
+    }
+
+    /// Returns the struct or record with the given name, which is declared in the program or one of its imports.
+    pub(crate) fn struct_(&self, name: Symbol) -> Option<&'a Struct> {
+        let program: &'a Program = self.program;
+        program
+            .program_scopes
+            .values()
+            .flat_map(|scope| scope.structs.iter())
+            .chain(program.stubs.values().flat_map(|stub| stub.structs.iter()))
+            .find(|(struct_, _)| *struct_ == name)
+            .map(|(_, struct_)| struct_)
+    }
+
+    /// Calls a function on the given arguments.
+    /// Returns its output, along with the arguments of its finalize block, if it has one.
+    pub(crate) fn call(
+        &mut self,
+        function: &'a Function,
+        arguments: Vec<Value>,
+    ) -> Result<(Value, Option<Vec<Value>>)> {
+        self.evaluate_body(function.name(), &function.input, &function.block, arguments)
+    }
+
+    /// Runs a finalize block on the given arguments.
+    fn finalize(&mut self, finalize: &'a Finalize, arguments: Vec<Value>) -> Result<()> {
+        self.evaluate_body(finalize.identifier.name, &finalize.input, &finalize.block, arguments)?;
+        Ok(())
+    }
+
+    /// Evaluates the body of a function, or finalize block, in a new frame in which the inputs are bound to the arguments.
+    fn evaluate_body(
+        &mut self,
+        function: Symbol,
+        inputs: &'a [Input],
+        block: &'a Block,
+        arguments: Vec<Value>,
+    ) -> Result<(Value, Option<Vec<Value>>)> {
+        let variables = inputs.iter().map(|input| input.identifier().name).zip(arguments).collect();
+        self.frames.push(Frame { function, variables, finalize_arguments: None });
+        let flow = self.evaluate_block(block);
+        let frame = self.frames.pop().expect("The frame of the function was pushed above.");
+        let output = match flow? {
+            Flow::Return(value) => value,
+            Flow::Next => Value::Unit,
+        };
+        Ok((output, frame.finalize_arguments))
+    }
+
+    /// Parses an input of the given type, written as it is for `leo run`.
+    fn input_value(&self, type_: &Type, input: &str) -> Result<Value> {
+        let value = ConsoleValue::<CurrentNetwork>::from_str(input)
+            .map_err(|error| InterpreterError::invalid_input(input, type_, error))?;
+        let value = match &value {
+            ConsoleValue::Plaintext(plaintext) => self.plaintext_value(type_, plaintext),
+            ConsoleValue::Record(record) => self.record_value(type_, record),
+            _ => None,
+        };
+        value.ok_or_else(|| InterpreterError::invalid_input(input, type_, "the value has a different type").into())
+    }
+
+    /// Converts a snarkVM plaintext into a value of the given type.
+    fn plaintext_value(&self, type_: &Type, plaintext: &Plaintext<CurrentNetwork>) -> Option<Value> {
+        match (type_, plaintext) {
+            (_, Plaintext::Literal(literal, _)) => match operations::literal_type(type_) {
+                Some(literal_type) if literal_type == literal.to_type() => Some(Value::Literal(literal.clone())),
+                _ => None,
+            },
+            (Type::Identifier(name), Plaintext::Struct(members, _)) => {
+                let struct_ = self.struct_(name.name).filter(|struct_| !struct_.is_record)?;
+                if members.len() != struct_.members.len() {
+                    return None;
+                }
+                let members = struct_
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let plaintext = members.get(&Identifier::from_str(&member.name().to_string()).ok()?)?;
+                        Some((member.name(), self.plaintext_value(&member.type_, plaintext)?))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Value::Struct(name.name, members))
+            }
+            (Type::Array(array_type), Plaintext::Array(elements, _)) if elements.len() == array_type.length() => {
+                let elements = elements
+                    .iter()
+                    .map(|element| self.plaintext_value(array_type.element_type(), element))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(Value::Array(elements))
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts a snarkVM record into a value of the given record type.
+    fn record_value(&self, type_: &Type, record: &Record<CurrentNetwork, Plaintext<CurrentNetwork>>) -> Option<Value> {
+        let name = match type_ {
+            Type::Identifier(name) => name.name,
+            _ => return None,
+        };
+        let struct_ = self.struct_(name).filter(|struct_| struct_.is_record)?;
+        let members = struct_
+            .members
+            .iter()
+            .map(|member| {
+                let value = match member.name() {
+                    sym::owner => match record.owner() {
+                        Owner::Public(address) => Value::Literal(Literal::Address(*address)),
+                        Owner::Private(plaintext) => self.plaintext_value(&Type::Address, plaintext)?,
+                    },
+                    name => match record.data().get(&Identifier::from_str(&name.to_string()).ok()?)? {
+                        Entry::Constant(plaintext) | Entry::Public(plaintext) | Entry::Private(plaintext) => {
+                            self.plaintext_value(&member.type_, plaintext)?
+                        }
+                    },
+                };
+                Some((member.name(), value))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(Value::Struct(name, members))
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The operations of the interpreter that are evaluated with the snarkVM console types.
+//! Each operation returns the snarkVM error as is, and the caller reports it at the expression being evaluated.
+
+use crate::{CurrentNetwork, Value};
+
+use leo_ast::{BinaryOperation, GroupLiteral, Type, UnaryOperation};
+use leo_span::{sym, Symbol};
+
+use snarkvm::{
+    console::{
+        network::prelude::{bail, Network, Result, ToBits, ToFields},
+        program::{Literal, LiteralType},
+        types::{Boolean, Field, Group},
+    },
+    synthesizer::program::*,
+};
+
+use rand_chacha::ChaChaRng;
+use std::{
+    panic::{catch_unwind, set_hook, take_hook, AssertUnwindSafe},
+    str::FromStr,
+};
+
+type N = CurrentNetwork;
+
+/// Converts a Leo literal into a snarkVM literal.
+pub(crate) fn literal(literal: &leo_ast::Literal) -> Result<Literal<N>> {
+    let string = match literal {
+        leo_ast::Literal::Group(group) => match group.as_ref() {
+            GroupLiteral::Single(..) => group.to_string(),
+            // An affine group literal is recovered from its x-coordinate, as the snarkVM parser does.
+            GroupLiteral::Tuple(tuple) => format!("{}group", tuple.x),
+        },
+        leo_ast::Literal::String(..) => bail!("String literals cannot be evaluated"),
+        _ => literal.to_string(),
+    };
+    Literal::from_str(&string.replace('_', ""))
+}
+
+/// Returns the snarkVM literal type of a Leo type, if the type is a literal type.
+pub(crate) fn literal_type(type_: &Type) -> Option<LiteralType> {
+    use leo_ast::IntegerType::*;
+
+    Some(match type_ {
+        Type::Address => LiteralType::Address,
+        Type::Boolean => LiteralType::Boolean,
+        Type::Field => LiteralType::Field,
+        Type::Group => LiteralType::Group,
+        Type::Integer(U8) => LiteralType::U8,
+        Type::Integer(U16) => LiteralType::U16,
+        Type::Integer(U32) => LiteralType::U32,
+        Type::Integer(U64) => LiteralType::U64,
+        Type::Integer(U128) => LiteralType::U128,
+        Type::Integer(I8) => LiteralType::I8,
+        Type::Integer(I16) => LiteralType::I16,
+        Type::Integer(I32) => LiteralType::I32,
+        Type::Integer(I64) => LiteralType::I64,
+        Type::Integer(I128) => LiteralType::I128,
+        Type::Scalar => LiteralType::Scalar,
+        Type::Signature => LiteralType::Signature,
+        Type::String => LiteralType::String,
+        _ => return None,
+    })
+}
+
+/// Returns the generator of the group, i.e. `group::GEN`.
+pub(crate) fn group_generator() -> Literal<N> {
+    Literal::Group(Group::generator())
+}
+
+/// Evaluates a binary operation on two literals.
+pub(crate) fn binary(op: BinaryOperation, left: Literal<N>, right: Literal<N>) -> Result<Literal<N>> {
+    fn evaluate<O: Operation<N, Literal<N>, LiteralType, 2>>(inputs: [Literal<N>; 2]) -> Result<Literal<N>> {
+        halting(|| O::evaluate(&inputs))
+    }
+
+    let inputs = [left, right];
+    match op {
+        BinaryOperation::Add => evaluate::<AddOperation<N>>(inputs),
+        BinaryOperation::AddWrapped => evaluate::<AddWrappedOperation<N>>(inputs),
+        BinaryOperation::And | BinaryOperation::BitwiseAnd => evaluate::<AndOperation<N>>(inputs),
+        BinaryOperation::Div => evaluate::<DivOperation<N>>(inputs),
+        BinaryOperation::DivWrapped => evaluate::<DivWrappedOperation<N>>(inputs),
+        BinaryOperation::Eq => Ok(Literal::Boolean(Boolean::new(inputs[0] == inputs[1]))),
+        BinaryOperation::Gte => evaluate::<GreaterThanOrEqualOperation<N>>(inputs),
+        BinaryOperation::Gt => evaluate::<GreaterThanOperation<N>>(inputs),
+        BinaryOperation::Lte => evaluate::<LessThanOrEqualOperation<N>>(inputs),
+        BinaryOperation::Lt => evaluate::<LessThanOperation<N>>(inputs),
+        BinaryOperation::Mod => evaluate::<ModuloOperation<N>>(inputs),
+        BinaryOperation::Mul => evaluate::<MulOperation<N>>(inputs),
+        BinaryOperation::MulWrapped => evaluate::<MulWrappedOperation<N>>(inputs),
+        BinaryOperation::Nand => evaluate::<NandOperation<N>>(inputs),
+        BinaryOperation::Neq => Ok(Literal::Boolean(Boolean::new(inputs[0] != inputs[1]))),
+        BinaryOperation::Nor => evaluate::<NorOperation<N>>(inputs),
+        BinaryOperation::Or | BinaryOperation::BitwiseOr => evaluate::<OrOperation<N>>(inputs),
+        BinaryOperation::Pow => evaluate::<PowOperation<N>>(inputs),
+        BinaryOperation::PowWrapped => evaluate::<PowWrappedOperation<N>>(inputs),
+        BinaryOperation::Rem => evaluate::<RemOperation<N>>(inputs),
+        BinaryOperation::RemWrapped => evaluate::<RemWrappedOperation<N>>(inputs),
+        BinaryOperation::Shl => evaluate::<ShlOperation<N>>(inputs),
+        BinaryOperation::ShlWrapped => evaluate::<ShlWrappedOperation<N>>(inputs),
+        BinaryOperation::Shr => evaluate::<ShrOperation<N>>(inputs),
+        BinaryOperation::ShrWrapped => evaluate::<ShrWrappedOperation<N>>(inputs),
+        BinaryOperation::Sub => evaluate::<SubOperation<N>>(inputs),
+        BinaryOperation::SubWrapped => evaluate::<SubWrappedOperation<N>>(inputs),
+        BinaryOperation::Xor => evaluate::<XorOperation<N>>(inputs),
+    }
+}
+
+/// Evaluates a unary operation on a literal.
+pub(crate) fn unary(op: UnaryOperation, receiver: Literal<N>) -> Result<Literal<N>> {
+    fn evaluate<O: Operation<N, Literal<N>, LiteralType, 1>>(inputs: [Literal<N>; 1]) -> Result<Literal<N>> {
+        halting(|| O::evaluate(&inputs))
+    }
+
+    let inputs = [receiver];
+    match op {
+        UnaryOperation::Abs => evaluate::<AbsOperation<N>>(inputs),
+        UnaryOperation::AbsWrapped => evaluate::<AbsWrappedOperation<N>>(inputs),
+        UnaryOperation::Double => evaluate::<DoubleOperation<N>>(inputs),
+        UnaryOperation::Inverse => evaluate::<InvOperation<N>>(inputs),
+        UnaryOperation::Negate => evaluate::<NegOperation<N>>(inputs),
+        UnaryOperation::Not => evaluate::<NotOperation<N>>(inputs),
+        UnaryOperation::Square => evaluate::<SquareOperation<N>>(inputs),
+        UnaryOperation::SquareRoot => evaluate::<SquareRootOperation<N>>(inputs),
+        UnaryOperation::ToXCoordinate => coordinate(&inputs[0], |group| group.to_x_coordinate()),
+        UnaryOperation::ToYCoordinate => coordinate(&inputs[0], |group| group.to_y_coordinate()),
+    }
+}
+
+/// Returns a coordinate of a group element.
+fn coordinate(literal: &Literal<N>, coordinate: impl Fn(&Group<N>) -> Field<N>) -> Result<Literal<N>> {
+    match literal {
+        Literal::Group(group) => Ok(Literal::Field(coordinate(group))),
+        _ => bail!("Expected a group element, found '{literal}'"),
+    }
+}
+
+/// Casts a literal to the given type, halting if the value does not fit.
+pub(crate) fn cast(literal: &Literal<N>, literal_type: LiteralType) -> Result<Literal<N>> {
+    halting(|| literal.cast(literal_type))
+}
+
+/// Hashes a value with the core hash function of the given type, e.g. `BHP256`, as the `hash.*` instructions do.
+pub(crate) fn hash(hasher: Symbol, input: &Value, destination: LiteralType) -> Result<Literal<N>> {
+    let input = match input.to_plaintext() {
+        Some(input) => input,
+        None => bail!("Cannot hash '{input}'"),
+    };
+    let bits = input.to_bits_le();
+    let to_group = matches!(destination, LiteralType::Address | LiteralType::Group);
+    let output = match hasher {
+        sym::BHP256 => Literal::Group(N::hash_to_group_bhp256(&bits)?),
+        sym::BHP512 => Literal::Group(N::hash_to_group_bhp512(&bits)?),
+        sym::BHP768 => Literal::Group(N::hash_to_group_bhp768(&bits)?),
+        sym::BHP1024 => Literal::Group(N::hash_to_group_bhp1024(&bits)?),
+        sym::Keccak256 => Literal::Group(N::hash_to_group_bhp256(&N::hash_keccak256(&bits)?)?),
+        sym::Keccak384 => Literal::Group(N::hash_to_group_bhp512(&N::hash_keccak384(&bits)?)?),
+        sym::Keccak512 => Literal::Group(N::hash_to_group_bhp512(&N::hash_keccak512(&bits)?)?),
+        sym::Pedersen64 => Literal::Group(N::hash_to_group_ped64(&bits)?),
+        sym::Pedersen128 => Literal::Group(N::hash_to_group_ped128(&bits)?),
+        sym::Poseidon2 if to_group => Literal::Group(N::hash_to_group_psd2(&input.to_fields()?)?),
+        sym::Poseidon2 => Literal::Field(N::hash_psd2(&input.to_fields()?)?),
+        sym::Poseidon4 if to_group => Literal::Group(N::hash_to_group_psd4(&input.to_fields()?)?),
+        sym::Poseidon4 => Literal::Field(N::hash_psd4(&input.to_fields()?)?),
+        sym::Poseidon8 if to_group => Literal::Group(N::hash_to_group_psd8(&input.to_fields()?)?),
+        sym::Poseidon8 => Literal::Field(N::hash_psd8(&input.to_fields()?)?),
+        sym::SHA3_256 => Literal::Group(N::hash_to_group_bhp256(&N::hash_sha3_256(&bits)?)?),
+        sym::SHA3_384 => Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_384(&bits)?)?),
+        sym::SHA3_512 => Literal::Group(N::hash_to_group_bhp512(&N::hash_sha3_512(&bits)?)?),
+        _ => bail!("Unknown hash function '{hasher}'"),
+    };
+    output.cast_lossy(destination)
+}
+
+/// Commits to a value with the core commitment function of the given type, as the `commit.*` instructions do.
+pub(crate) fn commit(
+    committer: Symbol,
+    input: &Value,
+    randomizer: &Value,
+    destination: LiteralType,
+) -> Result<Literal<N>> {
+    let bits = match input.to_plaintext() {
+        Some(input) => input.to_bits_le(),
+        None => bail!("Cannot commit to '{input}'"),
+    };
+    let randomizer = match randomizer {
+        Value::Literal(Literal::Scalar(randomizer)) => randomizer,
+        _ => bail!("Expected a scalar randomizer, found '{randomizer}'"),
+    };
+    let output = match committer {
+        sym::BHP256 => N::commit_to_group_bhp256(&bits, randomizer)?,
+        sym::BHP512 => N::commit_to_group_bhp512(&bits, randomizer)?,
+        sym::BHP768 => N::commit_to_group_bhp768(&bits, randomizer)?,
+        sym::BHP1024 => N::commit_to_group_bhp1024(&bits, randomizer)?,
+        sym::Pedersen64 => N::commit_to_group_ped64(&bits, randomizer)?,
+        sym::Pedersen128 => N::commit_to_group_ped128(&bits, randomizer)?,
+        _ => bail!("Unknown commitment function '{committer}'"),
+    };
+    Literal::Group(output).cast_lossy(destination)
+}
+
+/// Verifies a signature on a message, as the `sign.verify` instruction does.
+pub(crate) fn verify(signature: &Value, address: &Value, message: &Value) -> Result<Literal<N>> {
+    let (signature, address) = match (signature, address) {
+        (Value::Literal(Literal::Signature(signature)), Value::Literal(Literal::Address(address))) => {
+            (signature, address)
+        }
+        _ => bail!("Expected a signature and an address, found '{signature}' and '{address}'"),
+    };
+    let message = match message.to_plaintext() {
+        Some(message) => message.to_fields()?,
+        None => bail!("Cannot verify a signature on '{message}'"),
+    };
+    Ok(Literal::Boolean(Boolean::new(signature.verify(address, &message))))
+}
+
+/// Samples a random literal of the given type, as the `rand.chacha` instruction does.
+pub(crate) fn random(literal_type: LiteralType, rng: &mut ChaChaRng) -> Literal<N> {
+    Literal::sample(literal_type, rng)
+}
+
+/// Runs the given closure, converting a halt into an error.
+/// Note that snarkVM halts by panicking, so the panic hook is silenced while the closure runs.
+fn halting<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let hook = take_hook();
+    set_hook(Box::new(|_| {}));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    set_hook(hook);
+    match result {
+        Ok(result) => result,
+        Err(payload) => match payload.downcast_ref::<String>() {
+            Some(message) => bail!("{message}"),
+            None => match payload.downcast_ref::<&str>() {
+                Some(message) => bail!("{message}"),
+                None => bail!("The program halted"),
+            },
+        },
+    }
+}
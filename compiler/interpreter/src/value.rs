@@ -0,0 +1,191 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::Symbol;
+
+use snarkvm::console::{
+    network::Testnet3,
+    program::{Literal, Plaintext},
+};
+
+use std::{fmt, str::FromStr};
+
+/// The network whose console types the interpreter evaluates with.
+pub type CurrentNetwork = Testnet3;
+
+/// A value computed by the interpreter.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Value {
+    /// A literal, e.g. `1u8`, `true` or `aleo1...`.
+    Literal(Literal<CurrentNetwork>),
+    /// A struct or record, with its members in declaration order.
+    Struct(Symbol, Vec<(Symbol, Value)>),
+    /// An array.
+    Array(Vec<Value>),
+    /// A tuple.
+    Tuple(Vec<Value>),
+    /// The unit value, returned by functions without outputs.
+    Unit,
+}
+
+impl Value {
+    /// Returns the literal, if the value is one.
+    pub fn as_literal(&self) -> Option<&Literal<CurrentNetwork>> {
+        match self {
+            Value::Literal(literal) => Some(literal),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean, if the value is one.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Literal(Literal::Boolean(boolean)) => Some(**boolean),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an unsigned integer, if the value is one that fits in a `usize`.
+    pub fn as_index(&self) -> Option<usize> {
+        match self {
+            Value::Literal(Literal::U8(integer)) => usize::try_from(**integer).ok(),
+            Value::Literal(Literal::U16(integer)) => usize::try_from(**integer).ok(),
+            Value::Literal(Literal::U32(integer)) => usize::try_from(**integer).ok(),
+            Value::Literal(Literal::U64(integer)) => usize::try_from(**integer).ok(),
+            Value::Literal(Literal::U128(integer)) => usize::try_from(**integer).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of an integer, if the value is one that fits in an `i128`.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Value::Literal(Literal::I8(integer)) => Some(i128::from(**integer)),
+            Value::Literal(Literal::I16(integer)) => Some(i128::from(**integer)),
+            Value::Literal(Literal::I32(integer)) => Some(i128::from(**integer)),
+            Value::Literal(Literal::I64(integer)) => Some(i128::from(**integer)),
+            Value::Literal(Literal::I128(integer)) => Some(**integer),
+            Value::Literal(Literal::U8(integer)) => Some(i128::from(**integer)),
+            Value::Literal(Literal::U16(integer)) => Some(i128::from(**integer)),
+            Value::Literal(Literal::U32(integer)) => Some(i128::from(**integer)),
+            Value::Literal(Literal::U64(integer)) => Some(i128::from(**integer)),
+            Value::Literal(Literal::U128(integer)) => i128::try_from(**integer).ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns the member of a struct or record with the given name.
+    pub fn member(&self, name: Symbol) -> Option<&Value> {
+        match self {
+            Value::Struct(_, members) => members.iter().find(|(member, _)| *member == name).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the member of a struct or record with the given name.
+    pub fn member_mut(&mut self, name: Symbol) -> Option<&mut Value> {
+        match self {
+            Value::Struct(_, members) => members.iter_mut().find(|(member, _)| *member == name).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Returns the element of an array or tuple at the given index.
+    pub fn element(&self, index: usize) -> Option<&Value> {
+        match self {
+            Value::Array(elements) | Value::Tuple(elements) => elements.get(index),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element of an array or tuple at the given index.
+    pub fn element_mut(&mut self, index: usize) -> Option<&mut Value> {
+        match self {
+            Value::Array(elements) | Value::Tuple(elements) => elements.get_mut(index),
+            _ => None,
+        }
+    }
+
+    /// Converts the value into a snarkVM plaintext, which is what the core functions hash and commit to.
+    /// Records, tuples and the unit value have no plaintext representation.
+    pub fn to_plaintext(&self) -> Option<Plaintext<CurrentNetwork>> {
+        Plaintext::from_str(&self.plaintext_string()?).ok()
+    }
+
+    /// Returns the value in the syntax of a snarkVM plaintext, e.g. `{ x: 1u8, y: [true, false] }`.
+    fn plaintext_string(&self) -> Option<String> {
+        match self {
+            Value::Literal(literal) => Some(literal.to_string()),
+            Value::Struct(_, members) => {
+                let members = members
+                    .iter()
+                    .map(|(name, value)| Some(format!("{name}: {}", value.plaintext_string()?)))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!("{{ {} }}", members.join(", ")))
+            }
+            Value::Array(elements) => {
+                let elements = elements.iter().map(|element| element.plaintext_string()).collect::<Option<Vec<_>>>()?;
+                Some(format!("[{}]", elements.join(", ")))
+            }
+            Value::Tuple(_) | Value::Unit => None,
+        }
+    }
+}
+
+impl From<Literal<CurrentNetwork>> for Value {
+    fn from(literal: Literal<CurrentNetwork>) -> Self {
+        Value::Literal(literal)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Literal(literal) => write!(f, "{literal}"),
+            Value::Struct(name, members) => {
+                write!(f, "{name} {{ ")?;
+                for (i, (member, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{member}: {value}")?;
+                }
+                write!(f, " }}")
+            }
+            Value::Array(elements) => {
+                write!(f, "[")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{element}")?;
+                }
+                write!(f, "]")
+            }
+            Value::Tuple(elements) => {
+                write!(f, "(")?;
+                for (i, element) in elements.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{element}")?;
+                }
+                write!(f, ")")
+            }
+            Value::Unit => write!(f, "()"),
+        }
+    }
+}
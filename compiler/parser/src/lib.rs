@@ -33,6 +33,7 @@ pub use parser::*;
 
 use leo_ast::{Ast, NodeBuilder};
 use leo_errors::{emitter::Handler, Result};
+use leo_span::Span;
 
 #[cfg(test)]
 mod test;
@@ -41,3 +42,17 @@ mod test;
 pub fn parse_ast(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Ast> {
     Ok(Ast::new(parser::parse(handler, node_builder, source, start_pos)?))
 }
+
+/// Returns the comments in the given source code text, with their spans, in the order they appear.
+/// Note that the parser discards comments, so they are not part of the AST.
+pub fn parse_comments(source: &str, start_pos: BytePos) -> Result<Vec<(String, Span)>> {
+    tokenize_iter(source, start_pos)
+        .filter_map(|token| match token {
+            Ok(SpannedToken { token: Token::CommentLine(comment) | Token::CommentBlock(comment), span }) => {
+                Some(Ok((comment, span)))
+            }
+            Ok(_) => None,
+            Err(error) => Some(Err(error)),
+        })
+        .collect()
+}
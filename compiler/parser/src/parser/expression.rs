@@ -442,12 +442,33 @@ impl ParserContext<'_> {
         Ok(Expression::Call(CallExpression {
             span: expr.span() + span,
             function: Box::new(Expression::Identifier(name)),
+            const_arguments: Vec::new(),
             external: Some(Box::new(expr)),
             arguments,
             id: self.node_builder.next_id(),
         }))
     }
 
+    /// Parses a call to a generic function, e.g. `foo::[4u32](args)`, after the `::`.
+    fn parse_generic_call(&mut self, function: Expression) -> Result<Expression> {
+        // Check that the expression is an identifier.
+        if !matches!(function, Expression::Identifier(_)) {
+            self.emit_err(ParserError::unexpected(function.to_string(), "an identifier", function.span()))
+        }
+        // Parse the const arguments.
+        let (const_arguments, ..) = self.parse_bracket_comma_list(|p| p.parse_expression().map(Some))?;
+        // Parse the arguments.
+        let (arguments, _, span) = self.parse_expr_tuple()?;
+        Ok(Expression::Call(CallExpression {
+            span: function.span() + span,
+            function: Box::new(function),
+            const_arguments,
+            external: None,
+            arguments,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
     /// Returns an [`Expression`] AST node if the next tokens represent an
     /// array access, struct member access, function call, or static function call expression.
     ///
@@ -490,8 +511,13 @@ impl ParserContext<'_> {
                     }
                 }
             } else if self.eat(&Token::DoubleColon) {
-                // Eat a core struct constant or core struct function call.
-                expr = self.parse_associated_access_expression(expr)?;
+                if self.check(&Token::LeftSquare) {
+                    // Eat a call to a generic function, e.g. `foo::[4u32](args)`.
+                    expr = self.parse_generic_call(expr)?;
+                } else {
+                    // Eat a core struct constant or core struct function call.
+                    expr = self.parse_associated_access_expression(expr)?;
+                }
             } else if self.eat(&Token::LeftSquare) {
                 // Eat an array access.
                 let index = self.parse_expression()?;
@@ -513,6 +539,7 @@ impl ParserContext<'_> {
                 expr = Expression::Call(CallExpression {
                     span: expr.span() + span,
                     function: Box::new(expr),
+                    const_arguments: Vec::new(),
                     external: None,
                     arguments,
                     id: self.node_builder.next_id(),
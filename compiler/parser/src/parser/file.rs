@@ -22,6 +22,7 @@ impl ParserContext<'_> {
     /// Returns a [`Program`] AST if all tokens can be consumed and represent a valid Leo program.
     pub fn parse_program(&mut self) -> Result<Program> {
         let mut imports = IndexMap::new();
+        let mut modules = IndexMap::new();
         let mut program_scopes = IndexMap::new();
 
         // TODO: Remove restrictions on multiple program scopes
@@ -29,6 +30,10 @@ impl ParserContext<'_> {
 
         while self.has_next() {
             match &self.token.token {
+                Token::Import if self.peek_is_module_import() => {
+                    let (id, span) = self.parse_module_import()?;
+                    modules.insert(id, span);
+                }
                 Token::Import => {
                     let (id, import) = self.parse_import()?;
                     imports.insert(id, import);
@@ -53,7 +58,51 @@ impl ParserContext<'_> {
             return Err(ParserError::missing_program_scope(self.token.span).into());
         }
 
-        Ok(Program { imports, stubs: IndexMap::new(), program_scopes })
+        Ok(Program { imports, modules, stubs: IndexMap::new(), program_scopes })
+    }
+
+    /// Returns a [`Module`] AST if all tokens can be consumed and represent a valid Leo module.
+    pub fn parse_module(&mut self) -> Result<Module> {
+        let mut module = Module::default();
+
+        while self.has_next() {
+            match &self.token.token {
+                Token::Import if self.peek_is_module_import() => {
+                    let (id, span) = self.parse_module_import()?;
+                    module.modules.insert(id, span);
+                }
+                // Programs are imported by the main program, whose program scope the module is merged into.
+                Token::Import => return Err(ParserError::cannot_import_program_in_module(self.token.span).into()),
+                Token::Const => {
+                    let declaration = self.parse_const_declaration_statement()?;
+                    module.consts.push((Symbol::intern(&declaration.place.to_string()), declaration));
+                }
+                Token::Struct | Token::Record => {
+                    let (id, struct_) = self.parse_struct()?;
+                    module.structs.push((id, struct_));
+                }
+                Token::At | Token::Function | Token::Transition | Token::Inline => {
+                    let (id, function) = self.parse_function()?;
+                    if function.variant != Variant::Inline {
+                        return Err(ParserError::only_inline_functions_in_modules(function.identifier.span).into());
+                    }
+                    module.functions.push((id, function));
+                }
+                _ => {
+                    return Err(Self::unexpected_item(&self.token, &[
+                        Token::Import,
+                        Token::Const,
+                        Token::Struct,
+                        Token::Record,
+                        Token::At,
+                        Token::Inline,
+                    ])
+                    .into());
+                }
+            }
+        }
+
+        Ok(module)
     }
 
     fn unexpected_item(token: &SpannedToken, expected: &[Token]) -> ParserError {
@@ -87,6 +136,25 @@ impl ParserContext<'_> {
         Ok((import_name.name, (Program::default(), start + end)))
     }
 
+    /// Returns whether the next tokens are a module import `import foo;`, rather than a program import.
+    fn peek_is_module_import(&self) -> bool {
+        self.look_ahead(2, |t| &t.token) == &Token::Semicolon
+    }
+
+    /// Parses a module import `import foo;`.
+    fn parse_module_import(&mut self) -> Result<(Symbol, Span)> {
+        // Parse `import`.
+        let start = self.expect(&Token::Import)?;
+
+        // Parse `foo`.
+        let module_name = self.expect_identifier()?;
+
+        let end = self.expect(&Token::Semicolon)?;
+
+        // Return the module name and the span.
+        Ok((module_name.name, start + end))
+    }
+
     /// Parses a program scope `program foo.aleo { ... }`.
     fn parse_program_scope(&mut self) -> Result<ProgramScope> {
         // Parse `program` keyword.
@@ -112,6 +180,7 @@ impl ParserContext<'_> {
         let mut consts: Vec<(Symbol, ConstDeclaration)> = Vec::new();
         let mut functions: Vec<(Symbol, Function)> = Vec::new();
         let mut structs: Vec<(Symbol, Struct)> = Vec::new();
+        let mut enums: Vec<(Symbol, Enum)> = Vec::new();
         let mut mappings: Vec<(Symbol, Mapping)> = Vec::new();
 
         while self.has_next() {
@@ -124,6 +193,10 @@ impl ParserContext<'_> {
                     let (id, struct_) = self.parse_struct()?;
                     structs.push((id, struct_));
                 }
+                Token::Enum => {
+                    let (id, enum_) = self.parse_enum()?;
+                    enums.push((id, enum_));
+                }
                 Token::Mapping => {
                     let (id, mapping) = self.parse_mapping()?;
                     mappings.push((id, mapping));
@@ -137,6 +210,7 @@ impl ParserContext<'_> {
                     return Err(Self::unexpected_item(&self.token, &[
                         Token::Struct,
                         Token::Record,
+                        Token::Enum,
                         Token::Mapping,
                         Token::At,
                         Token::Function,
@@ -151,7 +225,7 @@ impl ParserContext<'_> {
         // Parse `}`.
         let end = self.expect(&Token::RightCurly)?;
 
-        Ok(ProgramScope { program_id, consts, functions, structs, mappings, span: start + end })
+        Ok(ProgramScope { program_id, consts, functions, structs, enums, mappings, span: start + end })
     }
 
     /// Returns a [`Vec<Member>`] AST node if the next tokens represent a struct member.
@@ -227,6 +301,43 @@ impl ParserContext<'_> {
         }))
     }
 
+    /// Parses an enum definition, e.g., `enum Status { Pending, Filled(u64) }`.
+    pub(super) fn parse_enum(&mut self) -> Result<(Symbol, Enum)> {
+        let start = self.expect(&Token::Enum)?;
+        let enum_name = self.expect_identifier()?;
+
+        self.expect(&Token::LeftCurly)?;
+        let mut variants = Vec::new();
+        while !self.check(&Token::RightCurly) {
+            let identifier = self.expect_identifier()?;
+            let (payload, end) = match self.eat(&Token::LeftParen) {
+                true => {
+                    let (type_, _) = self.parse_type()?;
+                    (Some(type_), self.expect(&Token::RightParen)?)
+                }
+                false => (None, identifier.span),
+            };
+            variants.push(EnumVariant {
+                identifier,
+                payload,
+                span: identifier.span + end,
+                id: self.node_builder.next_id(),
+            });
+
+            if !self.eat(&Token::Comma) {
+                break;
+            }
+        }
+        let end = self.expect(&Token::RightCurly)?;
+
+        Ok((enum_name.name, Enum {
+            identifier: enum_name,
+            variants,
+            span: start + end,
+            id: self.node_builder.next_id(),
+        }))
+    }
+
     /// Parses a mapping declaration, e.g. `mapping balances: address => u128`.
     pub(super) fn parse_mapping(&mut self) -> Result<(Symbol, Mapping)> {
         let start = self.expect(&Token::Mapping)?;
@@ -370,10 +481,32 @@ impl ParserContext<'_> {
 
         // TODO: Verify that this check is sound.
         // Check that there is no whitespace in between the `@` symbol and identifier.
-        match identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
-            true => Err(ParserError::space_in_annotation(span).into()),
-            false => Ok(Annotation { identifier, span, id: self.node_builder.next_id() }),
+        if identifier.span.hi.0 - start.lo.0 > 1 + identifier.name.to_string().len() as u32 {
+            return Err(ParserError::space_in_annotation(span).into());
         }
+
+        // Parse the optional arguments, e.g. `(unused_variable, shadowed_binding)`.
+        let (arguments, span) = match self.peek_is_left_par() {
+            true => {
+                let (arguments, _, arguments_span) =
+                    self.parse_paren_comma_list(|p| p.expect_identifier().map(Some))?;
+                (arguments, span + arguments_span)
+            }
+            false => (Vec::new(), span),
+        };
+
+        Ok(Annotation { identifier, arguments, span, id: self.node_builder.next_id() })
+    }
+
+    /// Returns a [`ConstParameter`] AST node if the next tokens represent a const parameter of a generic function, e.g. `N: u32`.
+    fn parse_const_parameter(&mut self) -> Result<ConstParameter> {
+        let identifier = self.expect_identifier()?;
+        self.expect(&Token::Colon)?;
+        let (type_, type_span) = self.parse_type()?;
+        if !matches!(type_, Type::Integer(_)) {
+            self.emit_err(ParserError::const_parameter_must_be_integer(&type_, type_span));
+        }
+        Ok(ConstParameter { identifier, type_, span: identifier.span + type_span, id: self.node_builder.next_id() })
     }
 
     /// Returns an [`(Identifier, Function)`] AST node if the next tokens represent a function name
@@ -394,6 +527,19 @@ impl ParserContext<'_> {
         };
         let name = self.expect_identifier()?;
 
+        // Parse the const parameters of a generic function, e.g. `::[N: u32]`, if they exist.
+        let const_parameters = match self.eat(&Token::DoubleColon) {
+            false => Vec::new(),
+            true => {
+                let (const_parameters, _, span) =
+                    self.parse_bracket_comma_list(|p| p.parse_const_parameter().map(Some))?;
+                if variant != Variant::Inline {
+                    self.emit_err(ParserError::const_parameters_only_on_inline_functions(span));
+                }
+                const_parameters
+            }
+        };
+
         // Parse parameters.
         let (inputs, ..) = self.parse_paren_comma_list(|p| p.parse_input().map(Some))?;
 
@@ -463,6 +609,7 @@ impl ParserContext<'_> {
                 annotations,
                 variant,
                 name,
+                const_parameters,
                 inputs,
                 output,
                 block,
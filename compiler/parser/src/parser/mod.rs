@@ -43,3 +43,10 @@ pub fn parse(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_
 
     tokens.parse_program()
 }
+
+/// Creates a new module from a given file path and source code text.
+pub fn parse_module(handler: &Handler, node_builder: &NodeBuilder, source: &str, start_pos: BytePos) -> Result<Module> {
+    let mut tokens = ParserContext::new(handler, node_builder, crate::tokenize(source, start_pos)?);
+
+    tokens.parse_module()
+}
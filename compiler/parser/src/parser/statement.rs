@@ -37,12 +37,52 @@ const ASSIGN_TOKENS: &[Token] = &[
 ];
 
 impl ParserContext<'_> {
+    /// Returns a copy of the place of an assignment, with unique ids for all of its nodes.
+    /// The copy is used as the left operand of a compound assignment like `s.balance += 1u64`.
+    fn copy_place(&self, place: &Expression) -> Expression {
+        let copy = |expression: &Expression| Box::new(self.copy_place(expression));
+        let mut copy = match place {
+            Expression::Access(AccessExpression::Array(access)) => {
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: copy(&access.array),
+                    index: copy(&access.index),
+                    ..access.clone()
+                }))
+            }
+            Expression::Access(AccessExpression::Member(access)) => {
+                Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: copy(&access.inner),
+                    name: Identifier { id: self.node_builder.next_id(), ..access.name },
+                    ..access.clone()
+                }))
+            }
+            Expression::Access(AccessExpression::Tuple(access)) => {
+                Expression::Access(AccessExpression::Tuple(TupleAccess {
+                    tuple: copy(&access.tuple),
+                    ..access.clone()
+                }))
+            }
+            Expression::Binary(binary) => Expression::Binary(BinaryExpression {
+                left: copy(&binary.left),
+                right: copy(&binary.right),
+                ..binary.clone()
+            }),
+            Expression::Unary(unary) => {
+                Expression::Unary(UnaryExpression { receiver: copy(&unary.receiver), ..unary.clone() })
+            }
+            _ => place.clone(),
+        };
+        copy.set_id(self.node_builder.next_id());
+        copy
+    }
+
     /// Returns a [`Statement`] AST node if the next tokens represent a statement.
     pub(crate) fn parse_statement(&mut self) -> Result<Statement> {
         match &self.token.token {
             Token::Return => Ok(Statement::Return(self.parse_return_statement()?)),
             Token::If => Ok(Statement::Conditional(self.parse_conditional_statement()?)),
             Token::For => Ok(Statement::Iteration(Box::new(self.parse_loop_statement()?))),
+            Token::Match => Ok(Statement::Match(self.parse_match_statement()?)),
             Token::Assert | Token::AssertEq | Token::AssertNeq => Ok(self.parse_assert_statement()?),
             Token::Let => Ok(Statement::Definition(self.parse_definition_statement()?)),
             Token::Const => Ok(Statement::Const(self.parse_const_declaration_statement()?)),
@@ -116,9 +156,8 @@ impl ParserContext<'_> {
             // Construct the span for the statement.
             let span = place.span() + value.span();
 
-            // Construct a copy of the lhs with a unique id.
-            let mut left = place.clone();
-            left.set_id(self.node_builder.next_id());
+            // Construct a copy of the lhs with unique ids.
+            let left = self.copy_place(&place);
 
             // Simplify complex assignments into simple assignments.
             // For example, `x += 1` becomes `x = x + 1`, while simple assignments like `x = y` remain unchanged.
@@ -234,6 +273,50 @@ impl ParserContext<'_> {
         })
     }
 
+    /// Returns a [`MatchStatement`] AST node if the next tokens represent a match statement.
+    fn parse_match_statement(&mut self) -> Result<MatchStatement> {
+        let start = self.expect(&Token::Match)?;
+        self.disallow_struct_construction = true;
+        let expression = self.parse_conditional_expression()?;
+        self.disallow_struct_construction = false;
+
+        self.expect(&Token::LeftCurly)?;
+        let mut arms = Vec::new();
+        while !self.check(&Token::RightCurly) {
+            let (pattern, pattern_span) = self.parse_match_pattern()?;
+            self.expect(&Token::BigArrow)?;
+            let block = self.parse_block()?;
+            arms.push(MatchArm { pattern, span: pattern_span + block.span, block, id: self.node_builder.next_id() });
+            self.eat(&Token::Comma);
+        }
+        let end = self.expect(&Token::RightCurly)?;
+
+        Ok(MatchStatement { expression, arms, span: start + end, id: self.node_builder.next_id() })
+    }
+
+    /// Parses the pattern of a match arm, e.g. `_`, `Status::Pending`, or `Status::Filled(amount)`.
+    fn parse_match_pattern(&mut self) -> Result<(MatchPattern, Span)> {
+        if self.eat(&Token::Underscore) {
+            return Ok((MatchPattern::Wildcard, self.prev_token.span));
+        }
+
+        let enum_name = self.expect_identifier()?;
+        self.expect(&Token::DoubleColon)?;
+        let variant = self.expect_identifier()?;
+        let (binding, has_payload, end) = match self.eat(&Token::LeftParen) {
+            true => {
+                let binding = match self.eat(&Token::Underscore) {
+                    true => None,
+                    false => Some(self.expect_identifier()?),
+                };
+                (binding, true, self.expect(&Token::RightParen)?)
+            }
+            false => (None, false, variant.span),
+        };
+
+        Ok((MatchPattern::Variant { enum_name, variant, binding, has_payload }, enum_name.span + end))
+    }
+
     /// Returns an [`IterationStatement`] AST node if the next tokens represent an iteration statement.
     fn parse_loop_statement(&mut self) -> Result<IterationStatement> {
         let start_span = self.expect(&Token::For)?;
@@ -242,12 +325,54 @@ impl ParserContext<'_> {
         let type_ = self.parse_type()?;
         self.expect(&Token::In)?;
 
-        // Parse iteration range.
-        let start = self.parse_expression()?;
-        self.expect(&Token::DotDot)?;
-        self.disallow_struct_construction = true;
-        let stop = self.parse_conditional_expression()?;
-        self.disallow_struct_construction = false;
+        // Parse iteration range, or the array to iterate over.
+        let range = if self.peek_is_parenthesized_range() {
+            // Parse a stepped range `(start..stop).step(step)`.
+            self.expect(&Token::LeftParen)?;
+            let start = self.parse_expression()?;
+            self.expect(&Token::DotDot)?;
+            let inclusive = self.eat(&Token::Assign);
+            let stop = self.parse_expression()?;
+            self.expect(&Token::RightParen)?;
+            self.expect(&Token::Dot)?;
+            if !matches!(self.token.token, Token::Identifier(sym::step)) {
+                return self.unexpected("step");
+            }
+            self.bump();
+            self.expect(&Token::LeftParen)?;
+            let step = self.parse_expression()?;
+            self.expect(&Token::RightParen)?;
+            IterationRange::Range {
+                start,
+                start_value: Default::default(),
+                stop,
+                stop_value: Default::default(),
+                inclusive,
+                step: Some(step),
+                step_value: Default::default(),
+            }
+        } else {
+            self.disallow_struct_construction = true;
+            let start = self.parse_conditional_expression()?;
+            let range = match self.eat(&Token::DotDot) {
+                true => {
+                    let inclusive = self.eat(&Token::Assign);
+                    let stop = self.parse_conditional_expression()?;
+                    IterationRange::Range {
+                        start,
+                        start_value: Default::default(),
+                        stop,
+                        stop_value: Default::default(),
+                        inclusive,
+                        step: None,
+                        step_value: Default::default(),
+                    }
+                }
+                false => IterationRange::Array { array: start },
+            };
+            self.disallow_struct_construction = false;
+            range
+        };
 
         let block = self.parse_block()?;
 
@@ -255,16 +380,35 @@ impl ParserContext<'_> {
             span: start_span + block.span,
             variable: ident,
             type_: type_.0,
-            start,
-            start_value: Default::default(),
-            stop,
-            stop_value: Default::default(),
-            inclusive: false,
+            range,
             block,
             id: self.node_builder.next_id(),
         })
     }
 
+    /// Returns `true` if the next tokens are a range in parentheses, `(start..stop)`.
+    fn peek_is_parenthesized_range(&self) -> bool {
+        if !self.check(&Token::LeftParen) {
+            return false;
+        }
+        let mut depth = 0usize;
+        for dist in 0.. {
+            match self.look_ahead(dist, |t| &t.token) {
+                Token::LeftParen | Token::LeftSquare | Token::LeftCurly => depth += 1,
+                Token::RightParen | Token::RightSquare | Token::RightCurly => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return false;
+                    }
+                }
+                Token::DotDot if depth == 1 => return true,
+                Token::Eof => return false,
+                _ => {}
+            }
+        }
+        false
+    }
+
     /// Returns a [`ConsoleStatement`] AST node if the next tokens represent a console statement.
     #[allow(dead_code)]
     fn parse_console_statement(&mut self) -> Result<ConsoleStatement> {
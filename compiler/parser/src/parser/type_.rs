@@ -109,12 +109,15 @@ impl ParserContext<'_> {
             let (element_type, _) = self.parse_type()?;
             // Parse the semi-colon.
             self.expect(&Token::Semicolon)?;
-            // Parse the length.
-            let (length, _) = self.eat_whole_number()?;
+            // Parse the length, which is either a whole number or a const parameter, e.g. `N`.
+            let array_type = match self.eat_identifier() {
+                Some(parameter) => ArrayType::with_const_length(element_type, parameter),
+                None => ArrayType::new(element_type, self.eat_whole_number()?.0),
+            };
             // Parse the right bracket.
             self.expect(&Token::RightSquare)?;
             // Return the array type.
-            Ok((Type::Array(ArrayType::new(element_type, length)), self.prev_token.span))
+            Ok((Type::Array(array_type), self.prev_token.span))
         } else if self.token.token == Token::LeftParen {
             let (types, _, span) = self.parse_paren_comma_list(|p| p.parse_type().map(Some))?;
             match types.len() {
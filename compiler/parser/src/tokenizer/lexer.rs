@@ -390,6 +390,7 @@ impl Token {
                     "const" => Token::Const,
                     "constant" => Token::Constant,
                     "else" => Token::Else,
+                    "enum" => Token::Enum,
                     "false" => Token::False,
                     "field" => Token::Field,
                     "finalize" => Token::Finalize,
@@ -408,6 +409,7 @@ impl Token {
                     "let" => Token::Let,
                     "leo" => Token::Leo,
                     "mapping" => Token::Mapping,
+                    "match" => Token::Match,
                     "private" => Token::Private,
                     "program" => Token::Program,
                     "public" => Token::Public,
@@ -117,6 +117,7 @@ pub enum Token {
     Const,
     Constant,
     Else,
+    Enum,
     Finalize,
     For,
     Function,
@@ -126,6 +127,7 @@ pub enum Token {
     Inline,
     Let,
     Mapping,
+    Match,
     Private,
     Program,
 
@@ -160,6 +162,7 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Const,
     Token::Constant,
     Token::Else,
+    Token::Enum,
     Token::False,
     Token::Field,
     Token::Finalize,
@@ -177,6 +180,7 @@ pub const KEYWORD_TOKENS: &[Token] = &[
     Token::Inline,
     Token::Let,
     Token::Mapping,
+    Token::Match,
     Token::Private,
     Token::Program,
     Token::Public,
@@ -218,6 +222,7 @@ impl Token {
             Token::Const => sym::Const,
             Token::Constant => sym::constant,
             Token::Else => sym::Else,
+            Token::Enum => sym::Enum,
             Token::False => sym::False,
             Token::Field => sym::field,
             Token::Finalize => sym::finalize,
@@ -236,6 +241,7 @@ impl Token {
             Token::Let => sym::Let,
             Token::Leo => sym::leo,
             Token::Mapping => sym::mapping,
+            Token::Match => sym::Match,
             Token::Private => sym::private,
             Token::Program => sym::program,
             Token::Public => sym::public,
@@ -353,6 +359,7 @@ impl fmt::Display for Token {
             Const => write!(f, "const"),
             Constant => write!(f, "constant"),
             Else => write!(f, "else"),
+            Enum => write!(f, "enum"),
             Finalize => write!(f, "finalize"),
             For => write!(f, "for"),
             Function => write!(f, "function"),
@@ -362,6 +369,7 @@ impl fmt::Display for Token {
             Inline => write!(f, "inline"),
             Let => write!(f, "let"),
             Mapping => write!(f, "mapping"),
+            Match => write!(f, "match"),
             Private => write!(f, "private"),
             Program => write!(f, "program"),
             Public => write!(f, "public"),
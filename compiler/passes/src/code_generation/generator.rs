@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{CallGraph, StructGraph, SymbolTable, TypeTable};
+use crate::{CallGraph, InstructionSpans, StructGraph, SymbolTable, TypeTable};
 
 use leo_ast::{Function, Program, ProgramId};
 use leo_span::Symbol;
@@ -53,6 +53,8 @@ pub struct CodeGenerator<'a> {
     pub(crate) program: &'a Program,
     // The program ID of the current program.
     pub(crate) program_id: Option<ProgramId>,
+    /// The spans of the Leo statements that instructions were generated from.
+    pub(crate) instruction_spans: InstructionSpans,
 }
 
 impl<'a> CodeGenerator<'a> {
@@ -80,6 +82,7 @@ impl<'a> CodeGenerator<'a> {
             futures: Vec::new(),
             program,
             program_id: None,
+            instruction_spans: InstructionSpans::default(),
         }
     }
 }
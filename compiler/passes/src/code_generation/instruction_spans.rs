@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Relates instructions in the generated bytecode to the spans of the Leo statements they were generated from.
+#[derive(Clone, Debug, Default)]
+pub struct InstructionSpans {
+    /// For each function, the recorded instructions in the order they were generated.
+    pub functions: IndexMap<Symbol, Vec<(String, Span)>>,
+}
+
+impl InstructionSpans {
+    /// Records the span of an instruction generated in the given function.
+    pub fn insert(&mut self, function: Symbol, instruction: &str, span: Span) {
+        self.functions.entry(function).or_default().push((normalize(instruction), span));
+    }
+
+    /// Returns the span of the first instruction matching `instruction`, preferring those generated in `function`.
+    pub fn find(&self, function: Symbol, instruction: &str) -> Option<Span> {
+        let instruction = normalize(instruction);
+        let find_in = |instructions: &Vec<(String, Span)>| {
+            instructions.iter().find(|(candidate, _)| *candidate == instruction).map(|(_, span)| *span)
+        };
+        self.functions.get(&function).and_then(find_in).or_else(|| self.functions.values().find_map(find_in))
+    }
+
+    /// Resolves the recorded spans into source locations, which remain valid after the compiler session ends.
+    pub fn to_source_map(&self) -> InstructionSourceMap {
+        let functions = with_session_globals(|session| {
+            self.functions
+                .iter()
+                .map(|(function, instructions)| {
+                    let locations = instructions
+                        .iter()
+                        .filter_map(|(instruction, span)| {
+                            let location = session.source_map.span_to_location(*span)?;
+                            let source = session.source_map.line_contents_of_span(*span)?;
+                            Some(InstructionLocation {
+                                instruction: instruction.clone(),
+                                file: location.source_file.name.to_string(),
+                                line: location.line_start,
+                                column: location.col_start,
+                                source: source.lines().next().unwrap_or_default().trim().to_string(),
+                            })
+                        })
+                        .collect();
+                    (function.to_string(), locations)
+                })
+                .collect()
+        });
+        InstructionSourceMap { functions }
+    }
+}
+
+/// The source map written next to the generated bytecode, relating its instructions to the Leo source.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstructionSourceMap {
+    /// For each function, the locations of its instructions in the order they were generated.
+    pub functions: IndexMap<String, Vec<InstructionLocation>>,
+}
+
+impl InstructionSourceMap {
+    /// Returns the location of the first instruction matching `instruction`, preferring those generated in `function`.
+    pub fn find(&self, function: &str, instruction: &str) -> Option<&InstructionLocation> {
+        let instruction = normalize(instruction);
+        let mut locations = self.functions.get(function).into_iter().chain(self.functions.values()).flatten();
+        locations.find(|location| location.instruction == instruction)
+    }
+}
+
+/// The location in the Leo source of the statement that an instruction was generated from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstructionLocation {
+    /// The instruction, without its indentation and trailing semicolon.
+    pub instruction: String,
+    /// The file containing the statement.
+    pub file: String,
+    /// The line of the statement, starting from 1.
+    pub line: usize,
+    /// The column of the statement, starting from 1.
+    pub column: usize,
+    /// The source line of the statement.
+    pub source: String,
+}
+
+/// Strips the indentation and trailing semicolon from an instruction.
+fn normalize(instruction: &str) -> String {
+    instruction.trim().trim_end_matches(';').trim_end().to_string()
+}
@@ -28,17 +28,20 @@ use leo_ast::{
     ExpressionStatement,
     IterationStatement,
     Mode,
+    Node,
     Output,
     ReturnStatement,
     Statement,
 };
 
+use leo_span::Span;
+
 use itertools::Itertools;
 use std::fmt::Write as _;
 
 impl<'a> CodeGenerator<'a> {
     fn visit_statement(&mut self, input: &'a Statement) -> String {
-        match input {
+        let instructions = match input {
             Statement::Assert(stmt) => self.visit_assert(stmt),
             Statement::Assign(stmt) => self.visit_assign(stmt),
             Statement::Block(stmt) => self.visit_block(stmt),
@@ -50,8 +53,25 @@ impl<'a> CodeGenerator<'a> {
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(_) => {
+                unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Return(stmt) => self.visit_return(stmt),
+        };
+
+        // Record the span of each instruction, so that failures can be traced back to the source.
+        // The instructions of nested statements are recorded when those statements are visited.
+        // Note that this unwrap is safe, since `current_function` is set in `visit_function`.
+        if !matches!(input, Statement::Block(_) | Statement::Conditional(_) | Statement::Iteration(_))
+            && input.span() != Span::default()
+        {
+            let function = self.current_function.unwrap().name();
+            for instruction in instructions.lines().filter(|line| !line.trim().is_empty()) {
+                self.instruction_spans.insert(function, instruction, input.span());
+            }
         }
+
+        instructions
     }
 
     fn visit_assert(&mut self, input: &'a AssertStatement) -> String {
@@ -63,21 +83,20 @@ impl<'a> CodeGenerator<'a> {
             // Concatenate the instructions.
             let mut instructions = left_instructions;
             instructions.push_str(&right_instructions);
-            instructions.push_str(&assert_instruction);
 
-            instructions
+            (instructions, assert_instruction)
         };
-        match &input.variant {
+        let (mut instructions, assert_instruction) = match &input.variant {
             AssertVariant::Assert(expr) => {
-                let (operand, mut instructions) = self.visit_expression(expr);
-                let assert_instruction = format!("    assert.eq {operand} true;\n");
-
-                instructions.push_str(&assert_instruction);
-                instructions
+                let (operand, instructions) = self.visit_expression(expr);
+                (instructions, format!("    assert.eq {operand} true;\n"))
             }
             AssertVariant::AssertEq(left, right) => generate_assert_instruction("assert.eq", left, right),
             AssertVariant::AssertNeq(left, right) => generate_assert_instruction("assert.neq", left, right),
-        }
+        };
+
+        instructions.push_str(&assert_instruction);
+        instructions
     }
 
     fn visit_return(&mut self, input: &'a ReturnStatement) -> String {
@@ -95,10 +114,27 @@ impl<'a> CodeGenerator<'a> {
                     self.current_function.unwrap().output.iter()
                 };
                 // If the operand string is empty, initialize an empty vector.
-                let operand_strings = match operand.is_empty() {
+                let mut operand_strings = match operand.is_empty() {
                     true => vec![],
-                    false => operand.split(' ').collect_vec(),
+                    false => operand.split(' ').map(|operand| operand.to_string()).collect_vec(),
                 };
+                // A closure must contain at least one instruction, which is not the case if it only returns constants.
+                // So the literals output by closures are first cast into registers.
+                if !self.is_transition_function {
+                    for (operand, output) in operand_strings.iter_mut().zip(output.clone()) {
+                        if let (false, Output::Internal(output)) = (is_register(operand), output) {
+                            let destination_register = format!("r{}", self.next_register);
+                            self.next_register += 1;
+                            writeln!(
+                                expression_instructions,
+                                "    cast {operand} into {destination_register} as {};",
+                                Self::visit_type(&output.type_)
+                            )
+                            .expect("failed to write to string");
+                            *operand = destination_register;
+                        }
+                    }
+                }
                 let instructions = operand_strings
                     .iter()
                     .zip_eq(output)
@@ -249,3 +285,8 @@ impl<'a> CodeGenerator<'a> {
         input.statements.iter().map(|stmt| self.visit_statement(stmt)).join("")
     }
 }
+
+/// Returns whether the operand is a register, rather than a literal.
+fn is_register(operand: &str) -> bool {
+    matches!(operand.strip_prefix('r').and_then(|index| index.chars().next()), Some(c) if c.is_ascii_digit())
+}
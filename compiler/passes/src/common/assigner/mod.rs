@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use leo_ast::{AssignStatement, Expression, Identifier, NodeID, Statement};
+use leo_ast::{AssignStatement, Expression, Identifier, Node, NodeID, Statement};
 use leo_span::Symbol;
 
 use std::{cell::RefCell, fmt::Display};
@@ -58,11 +58,8 @@ impl AssignerInner {
     /// Constructs the assignment statement `place = expr;`.
     /// This function should be the only place where `AssignStatement`s are constructed.
     fn simple_assign_statement(&mut self, identifier: Identifier, value: Expression, id: NodeID) -> Statement {
-        Statement::Assign(Box::new(AssignStatement {
-            place: Expression::Identifier(identifier),
-            value,
-            span: Default::default(),
-            id,
-        }))
+        // The statement takes the span of its value, so that the instructions generated for it can be traced back to the source.
+        let span = value.span();
+        Statement::Assign(Box::new(AssignStatement { place: Expression::Identifier(identifier), value, span, id }))
     }
 }
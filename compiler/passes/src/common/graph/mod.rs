@@ -72,6 +72,11 @@ impl<N: Node> DiGraph<N> {
         self.nodes.contains(&node)
     }
 
+    /// Returns `true` if some node in the graph has an edge to the given node.
+    pub fn has_predecessors(&self, node: N) -> bool {
+        self.edges.values().any(|targets| targets.contains(&node))
+    }
+
     /// Returns the post-order ordering of the graph.
     /// Detects if there is a cycle in the graph.
     pub fn post_order(&self) -> Result<IndexSet<N>, DiGraphError<N>> {
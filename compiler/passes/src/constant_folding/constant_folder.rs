@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Expression, Value};
+use leo_errors::emitter::Handler;
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+
+pub struct ConstantFolder<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// The constant values of the variables assigned in the current function body.
+    pub(crate) constants: IndexMap<Symbol, Value>,
+}
+
+impl<'a> ConstantFolder<'a> {
+    /// Initializes a new `ConstantFolder`.
+    pub fn new(handler: &'a Handler) -> Self {
+        Self { handler, constants: Default::default() }
+    }
+
+    /// Returns the value of the expression, if it is a literal that can be folded or a variable assigned one.
+    pub(crate) fn constant_value(&self, expression: &Expression) -> Option<Value> {
+        match expression {
+            Expression::Literal(literal) => {
+                Value::try_from(literal).ok().filter(|value| value.is_supported_const_fold_type())
+            }
+            Expression::Identifier(identifier) => self.constants.get(&identifier.name).cloned(),
+            _ => None,
+        }
+    }
+}
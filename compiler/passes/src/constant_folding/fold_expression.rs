@@ -0,0 +1,144 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantFolder;
+
+use leo_ast::{
+    BinaryExpression,
+    BinaryOperation,
+    CastExpression,
+    Expression,
+    ExpressionReconstructor,
+    Literal,
+    TernaryExpression,
+    Type,
+    UnaryExpression,
+    UnaryOperation,
+    Value,
+};
+use leo_errors::Result;
+use leo_span::Span;
+
+impl ExpressionReconstructor for ConstantFolder<'_> {
+    type AdditionalOutput = ();
+
+    /// Folds a binary expression whose operands are both constants.
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        if let (Some(left), Some(right)) = (self.constant_value(&input.left), self.constant_value(&input.right)) {
+            match fold_binary(input.op, left, right, input.span) {
+                Ok(value) => return (Expression::Literal(Literal::from_value(value, input.id)), Default::default()),
+                Err(error) => self.handler.emit_err(error),
+            }
+        }
+
+        (Expression::Binary(input), Default::default())
+    }
+
+    /// Folds a cast of a constant integer to an integer type.
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        if let (Some(value), Type::Integer(integer_type)) = (self.constant_value(&input.expression), &input.type_) {
+            if !matches!(value, Value::Boolean(..)) {
+                match value.cast(integer_type, input.span) {
+                    Ok(value) => {
+                        return (Expression::Literal(Literal::from_value(value, input.id)), Default::default());
+                    }
+                    Err(error) => self.handler.emit_err(error),
+                }
+            }
+        }
+
+        (Expression::Cast(input), Default::default())
+    }
+
+    /// Folds a ternary expression whose condition and selected branch are both constants.
+    fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
+        let selected = match self.constant_value(&input.condition) {
+            Some(Value::Boolean(true, _)) => self.constant_value(&input.if_true),
+            Some(Value::Boolean(false, _)) => self.constant_value(&input.if_false),
+            _ => None,
+        };
+
+        match selected {
+            Some(value) => (Expression::Literal(Literal::from_value(value, input.id)), Default::default()),
+            None => (Expression::Ternary(input), Default::default()),
+        }
+    }
+
+    /// Folds a unary expression whose operand is a constant.
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        if let Some(value) = self.constant_value(&input.receiver) {
+            let result = match input.op {
+                UnaryOperation::Abs => Some(value.abs(input.span)),
+                UnaryOperation::AbsWrapped => Some(value.abs_wrapped(input.span)),
+                UnaryOperation::Negate => Some(value.neg(input.span)),
+                UnaryOperation::Not => Some(value.not(input.span)),
+                // The remaining operations are not defined on the types that can be folded.
+                _ => None,
+            };
+            match result {
+                Some(Ok(value)) => {
+                    return (Expression::Literal(Literal::from_value(value, input.id)), Default::default());
+                }
+                Some(Err(error)) => self.handler.emit_err(error),
+                None => {}
+            }
+        }
+
+        (Expression::Unary(input), Default::default())
+    }
+}
+
+/// Evaluates a binary operation on constant operands.
+fn fold_binary(op: BinaryOperation, left: Value, right: Value, span: Span) -> Result<Value> {
+    match op {
+        BinaryOperation::Add => left.add(right, span),
+        BinaryOperation::AddWrapped => left.add_wrapped(right, span),
+        BinaryOperation::And | BinaryOperation::BitwiseAnd => left.bitand(right, span),
+        BinaryOperation::Div => left.div(right, span),
+        // Wrapped division by zero still fails, which is reported as for unwrapped division.
+        BinaryOperation::DivWrapped if is_zero(&right) => left.div(right, span),
+        BinaryOperation::DivWrapped => left.div_wrapped(right, span),
+        BinaryOperation::Eq => left.eq(right, span),
+        BinaryOperation::Gte => left.ge(right, span),
+        BinaryOperation::Gt => left.gt(right, span),
+        BinaryOperation::Lte => left.le(right, span),
+        BinaryOperation::Lt => left.lt(right, span),
+        // Note that `mod` is only defined on unsigned integers, for which it is equivalent to `rem`.
+        BinaryOperation::Mod | BinaryOperation::Rem => left.rem(right, span),
+        BinaryOperation::Mul => left.mul(right, span),
+        BinaryOperation::MulWrapped => left.mul_wrapped(right, span),
+        BinaryOperation::Nand => left.bitand(right, span)?.not(span),
+        BinaryOperation::Neq => left.eq(right, span)?.not(span),
+        BinaryOperation::Nor => left.bitor(right, span)?.not(span),
+        BinaryOperation::Or | BinaryOperation::BitwiseOr => left.bitor(right, span),
+        BinaryOperation::Pow => left.pow(right, span),
+        BinaryOperation::PowWrapped => left.pow_wrapped(right, span),
+        BinaryOperation::RemWrapped if is_zero(&right) => left.rem(right, span),
+        BinaryOperation::RemWrapped => left.rem_wrapped(right, span),
+        BinaryOperation::Shl => left.shl(right, span),
+        BinaryOperation::ShlWrapped => left.shl_wrapped(right, span),
+        BinaryOperation::Shr => left.shr(right, span),
+        BinaryOperation::ShrWrapped => left.shr_wrapped(right, span),
+        BinaryOperation::Sub => left.sub(right, span),
+        BinaryOperation::SubWrapped => left.sub_wrapped(right, span),
+        BinaryOperation::Xor => left.xor(right, span),
+    }
+}
+
+/// Returns whether the value is the integer zero.
+fn is_zero(value: &Value) -> bool {
+    matches!(u128::try_from(value), Ok(0))
+}
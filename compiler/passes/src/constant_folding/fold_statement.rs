@@ -0,0 +1,36 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::ConstantFolder;
+
+use leo_ast::{AssignStatement, Expression, ExpressionReconstructor, Statement, StatementReconstructor};
+
+impl StatementReconstructor for ConstantFolder<'_> {
+    /// Folds the right-hand side of an `AssignStatement`, recording its value if it is a constant.
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        let value = self.reconstruct_expression(input.value).0;
+
+        // Note that SSA guarantees that the variable is not assigned anywhere else.
+        if let (Expression::Identifier(place), Some(constant)) = (&input.place, self.constant_value(&value)) {
+            self.constants.insert(place.name, constant);
+        }
+
+        (
+            Statement::Assign(Box::new(AssignStatement { place: input.place, value, span: input.span, id: input.id })),
+            Default::default(),
+        )
+    }
+}
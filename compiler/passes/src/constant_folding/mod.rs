@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Constant Folding pass traverses the AST and evaluates the operations whose operands are known at compile time.
+//! The values of variables assigned constants are propagated to their uses, so that the operations using them can also be folded.
+//! The pass is run after the Static Single Assignment pass.
+//!
+//! Consider the following Leo code, output by the SSA pass.
+//! ```leo
+//! function main(value: u8) -> u8 {
+//!     $var$0 = 1u8 + 2u8;
+//!     $var$1 = $var$0 * 2u8;
+//!     $var$2 = $var$1 > 4u8;
+//!     $var$3 = $var$2 ? $var$1 : value;
+//!     $var$4 = $var$3 + value;
+//!     return $var$4;
+//! }
+//! ```
+//!
+//! The constant folding pass produces the following code.
+//! ```leo
+//! function main(value: u8) -> u8 {
+//!     $var$0 = 3u8;
+//!     $var$1 = 6u8;
+//!     $var$2 = true;
+//!     $var$3 = 6u8;
+//!     $var$4 = $var$3 + value;
+//!     return $var$4;
+//! }
+//! ```
+//! Operations that fail on their constant operands, such as `255u8 + 1u8`, are reported as errors.
+//! Note that variables are only replaced by their values when the entire expression is folded,
+//! since the later passes rely on the operands produced by SSA being identifiers.
+//!
+//! Note this pass relies on the following invariants:
+//! - Unique variable names (provided by SSA)
+
+mod fold_expression;
+
+mod fold_program;
+
+mod fold_statement;
+
+pub mod constant_folder;
+pub use constant_folder::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for ConstantFolder<'a> {
+    type Input = (Ast, &'a Handler);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler): Self::Input) -> Self::Output {
+        let mut reconstructor = ConstantFolder::new(handler);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
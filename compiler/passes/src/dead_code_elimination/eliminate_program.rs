@@ -51,6 +51,7 @@ impl ProgramReconstructor for DeadCodeEliminator<'_> {
             annotations: input.annotations,
             variant: input.variant,
             identifier: input.identifier,
+            const_parameters: input.const_parameters,
             input: input.input,
             output: input.output,
             output_type: input.output_type,
@@ -0,0 +1,225 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    AccessExpression,
+    ArrayExpression,
+    ArrayLength,
+    ArrayType,
+    BinaryExpression,
+    BinaryOperation,
+    Enum,
+    ErrExpression,
+    Expression,
+    GroupLiteral,
+    Identifier,
+    IntegerType,
+    Literal,
+    MappingType,
+    MemberAccess,
+    NodeBuilder,
+    Struct,
+    StructExpression,
+    StructVariableInitializer,
+    TupleType,
+    Type,
+};
+use leo_errors::emitter::Handler;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// The address used as the default value of address payloads.
+const ZERO_ADDRESS: &str = "aleo1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq3ljyzc";
+
+/// The maximum number of variants of an enum, since the tag is a `u8`.
+pub const MAX_ENUM_VARIANTS: usize = 256;
+
+/// The maximum depth of nested structs in a default payload, which bounds the recursion on cyclic structs.
+const MAX_DEFAULT_DEPTH: usize = 32;
+
+pub struct EnumLowerer<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// A counter used to create unique NodeIDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The enums declared in the current program scope.
+    pub(crate) enums: IndexMap<Symbol, Enum>,
+    /// The structs declared in the current program scope and in the stubs, which are used to construct default payloads.
+    pub(crate) structs: IndexMap<Symbol, Struct>,
+    /// A counter used to name the matched expressions that are assigned to new variables.
+    pub(crate) match_counter: usize,
+}
+
+impl<'a> EnumLowerer<'a> {
+    /// Initializes a new `EnumLowerer`.
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder) -> Self {
+        Self { handler, node_builder, enums: Default::default(), structs: Default::default(), match_counter: 0 }
+    }
+
+    /// Returns the name of the member holding the position of the variant.
+    pub(crate) fn tag() -> Symbol {
+        Symbol::intern("tag")
+    }
+
+    /// Returns a copy of the identifier with a new ID.
+    pub(crate) fn identifier(&self, identifier: Identifier) -> Identifier {
+        Identifier { id: self.node_builder.next_id(), ..identifier }
+    }
+
+    /// Returns a copy of the type, whose identifiers have new IDs.
+    pub(crate) fn copy_type(&self, type_: &Type) -> Type {
+        match type_ {
+            Type::Array(array_type) => {
+                let element_type = self.copy_type(array_type.element_type());
+                Type::Array(match array_type.declared_length() {
+                    ArrayLength::Fixed(length) => ArrayType::new(element_type, length.clone()),
+                    ArrayLength::Const(parameter) => {
+                        ArrayType::with_const_length(element_type, self.identifier(*parameter))
+                    }
+                })
+            }
+            Type::Identifier(identifier) => Type::Identifier(self.identifier(*identifier)),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.copy_type(&mapping_type.key)),
+                value: Box::new(self.copy_type(&mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => {
+                Type::Tuple(TupleType::new(tuple_type.elements().iter().map(|type_| self.copy_type(type_)).collect()))
+            }
+            type_ => type_.clone(),
+        }
+    }
+
+    /// Constructs the `u8` literal holding the position of a variant.
+    pub(crate) fn tag_literal(&self, position: usize, span: Span) -> Expression {
+        Expression::Literal(Literal::Integer(IntegerType::U8, position.to_string(), span, self.node_builder.next_id()))
+    }
+
+    /// Constructs `variable.tag == position`.
+    pub(crate) fn tag_equals(&self, variable: Identifier, position: usize, span: Span) -> Expression {
+        Expression::Binary(BinaryExpression {
+            left: Box::new(Expression::Access(AccessExpression::Member(MemberAccess {
+                inner: Box::new(Expression::Identifier(self.identifier(variable))),
+                name: Identifier::new(Self::tag(), self.node_builder.next_id()),
+                span,
+                id: self.node_builder.next_id(),
+            }))),
+            right: Box::new(self.tag_literal(position, span)),
+            op: BinaryOperation::Eq,
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Constructs the value of the variant at `position` of the enum, with the given payload.
+    /// The payload members of the other variants are given default values.
+    pub(crate) fn construct_variant(
+        &self,
+        enum_: &Enum,
+        position: usize,
+        payload: Option<Expression>,
+        span: Span,
+    ) -> Expression {
+        let mut payload = payload;
+        let tag = StructVariableInitializer {
+            identifier: Identifier { span, ..Identifier::new(Self::tag(), self.node_builder.next_id()) },
+            expression: Some(self.tag_literal(position, span)),
+            span,
+            id: self.node_builder.next_id(),
+        };
+        let payloads = enum_.variants.iter().enumerate().filter_map(|(index, variant)| {
+            let type_ = variant.payload.as_ref()?;
+            let expression = match index == position {
+                true => payload.take(),
+                false => None,
+            }
+            .or_else(|| self.default_value(type_, span, 0))
+            .unwrap_or_else(|| Expression::Err(ErrExpression { span, id: self.node_builder.next_id() }));
+            Some(StructVariableInitializer {
+                identifier: Identifier { span, ..self.identifier(variant.identifier) },
+                expression: Some(expression),
+                span,
+                id: self.node_builder.next_id(),
+            })
+        });
+        Expression::Struct(StructExpression {
+            name: Identifier { span, ..self.identifier(enum_.identifier) },
+            members: std::iter::once(tag).chain(payloads).collect(),
+            span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Returns the default value of a payload of the given type, if the type has one.
+    /// The default value of an enum is its first variant.
+    pub(crate) fn default_value(&self, type_: &Type, span: Span, depth: usize) -> Option<Expression> {
+        if depth > MAX_DEFAULT_DEPTH {
+            return None;
+        }
+        let id = self.node_builder.next_id();
+        let literal = match type_ {
+            Type::Address => Literal::Address(ZERO_ADDRESS.to_string(), span, id),
+            Type::Boolean => Literal::Boolean(false, span, id),
+            Type::Field => Literal::Field("0".to_string(), span, id),
+            Type::Group => Literal::Group(Box::new(GroupLiteral::Single("0".to_string(), span, id))),
+            Type::Integer(integer_type) => Literal::Integer(*integer_type, "0".to_string(), span, id),
+            Type::Scalar => Literal::Scalar("0".to_string(), span, id),
+            Type::Array(array_type) => {
+                let length = match array_type.declared_length() {
+                    ArrayLength::Fixed(length) => length.value(),
+                    ArrayLength::Const(_) => return None,
+                };
+                let elements = (0..length)
+                    .map(|_| self.default_value(array_type.element_type(), span, depth + 1))
+                    .collect::<Option<Vec<_>>>()?;
+                return Some(Expression::Array(ArrayExpression { elements, span, id }));
+            }
+            Type::Identifier(identifier) => {
+                if let Some(enum_) = self.enums.get(&identifier.name) {
+                    // Check that the payloads of the enum have default values.
+                    for variant in enum_.variants.iter() {
+                        if let Some(payload) = &variant.payload {
+                            self.default_value(payload, span, depth + 1)?;
+                        }
+                    }
+                    return Some(self.construct_variant(enum_, 0, None, span));
+                }
+                let struct_ = self.structs.get(&identifier.name).filter(|struct_| !struct_.is_record)?;
+                let members = struct_
+                    .members
+                    .iter()
+                    .map(|member| {
+                        Some(StructVariableInitializer {
+                            identifier: Identifier { span, ..self.identifier(member.identifier) },
+                            expression: Some(self.default_value(&member.type_, span, depth + 1)?),
+                            span,
+                            id: self.node_builder.next_id(),
+                        })
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                return Some(Expression::Struct(StructExpression {
+                    name: Identifier { span, ..self.identifier(*identifier) },
+                    members,
+                    span,
+                    id,
+                }));
+            }
+            _ => return None,
+        };
+        Some(Expression::Literal(literal))
+    }
+}
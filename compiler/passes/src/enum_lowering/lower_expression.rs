@@ -0,0 +1,130 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::EnumLowerer;
+
+use leo_ast::{
+    AccessExpression,
+    AssociatedConstant,
+    AssociatedFunction,
+    Enum,
+    ErrExpression,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    StructExpression,
+    StructVariableInitializer,
+    Type,
+};
+use leo_errors::TypeCheckerError;
+use leo_span::Span;
+
+impl EnumLowerer<'_> {
+    /// Returns the enum named by the type, if it is an enum.
+    fn enum_of(&self, type_: &Type) -> Option<Enum> {
+        match type_ {
+            Type::Identifier(identifier) => self.enums.get(&identifier.name).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Lowers the construction of a variant, checking that the arguments match its payload.
+    fn lower_variant(
+        &self,
+        enum_: &Enum,
+        variant: Identifier,
+        arguments: Option<Vec<Expression>>,
+        span: Span,
+    ) -> Expression {
+        let Some((position, definition)) = enum_.variant(variant.name) else {
+            self.handler.emit_err(TypeCheckerError::unknown_enum_variant(enum_.identifier, variant, variant.span));
+            return Expression::Err(ErrExpression { span, id: self.node_builder.next_id() });
+        };
+
+        let payload = match (&definition.payload, arguments) {
+            (None, None) => None,
+            (Some(_), Some(mut arguments)) if arguments.len() == 1 => arguments.pop(),
+            (Some(type_), _) => {
+                self.handler.emit_err(TypeCheckerError::enum_variant_payload_mismatch(
+                    format!("{}::{}", enum_.identifier, variant),
+                    format!("has a payload of type `{type_}`"),
+                    span,
+                ));
+                None
+            }
+            (None, Some(_)) => {
+                self.handler.emit_err(TypeCheckerError::enum_variant_payload_mismatch(
+                    format!("{}::{}", enum_.identifier, variant),
+                    "does not have a payload",
+                    span,
+                ));
+                None
+            }
+        };
+
+        self.construct_variant(enum_, position, payload, span)
+    }
+}
+
+impl ExpressionReconstructor for EnumLowerer<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        // Lower the construction of a variant without a payload, e.g. `Status::Pending`.
+        match self.enum_of(&input.ty) {
+            Some(enum_) => (self.lower_variant(&enum_, input.name, None, input.span), Default::default()),
+            None => (Expression::Access(AccessExpression::AssociatedConstant(input)), Default::default()),
+        }
+    }
+
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        let arguments = input.arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect();
+
+        // Lower the construction of a variant with a payload, e.g. `Status::Filled(amount)`.
+        match self.enum_of(&input.ty) {
+            Some(enum_) => (self.lower_variant(&enum_, input.name, Some(arguments), input.span), Default::default()),
+            None => (
+                Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                    ty: input.ty,
+                    name: input.name,
+                    arguments,
+                    span: input.span,
+                    id: input.id,
+                })),
+                Default::default(),
+            ),
+        }
+    }
+
+    /// Reconstructs the members of a struct initializer, leaving shorthand members as they are.
+    /// Expanding them here would reuse the ID of the member identifier, before the type checker has run.
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        expression: member.expression.map(|expression| self.reconstruct_expression(expression).0),
+                        ..member
+                    })
+                    .collect(),
+                ..input
+            }),
+            Default::default(),
+        )
+    }
+}
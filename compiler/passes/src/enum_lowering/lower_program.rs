@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{EnumLowerer, MAX_ENUM_VARIANTS};
+
+use leo_ast::{
+    Enum,
+    Identifier,
+    IntegerType,
+    Member,
+    Mode,
+    Program,
+    ProgramReconstructor,
+    ProgramScope,
+    Statement,
+    StatementReconstructor,
+    Struct,
+    Type,
+};
+use leo_errors::TypeCheckerError;
+
+use indexmap::IndexSet;
+
+impl EnumLowerer<'_> {
+    /// Checks the variants of an enum, and lowers the enum into a struct.
+    fn lower_enum(&self, enum_: &Enum) -> Struct {
+        // Check that the position of each variant fits in the `u8` tag.
+        if enum_.variants.len() > MAX_ENUM_VARIANTS {
+            self.handler.emit_err(TypeCheckerError::too_many_enum_variants(
+                enum_.identifier,
+                MAX_ENUM_VARIANTS,
+                enum_.span,
+            ));
+        }
+
+        let mut names = IndexSet::new();
+        for variant in enum_.variants.iter() {
+            // Check that the variant does not clash with the tag or with another variant.
+            if variant.name() == Self::tag() {
+                self.handler.emit_err(TypeCheckerError::reserved_enum_variant(variant.identifier, variant.span));
+            } else if !names.insert(variant.name()) {
+                self.handler.emit_err(TypeCheckerError::duplicate_enum_variant(
+                    enum_.identifier,
+                    variant.identifier,
+                    variant.span,
+                ));
+            }
+            // Check that the payload has a default value, which is used when another variant is constructed.
+            if let Some(payload) = &variant.payload {
+                if self.default_value(payload, variant.span, 0).is_none() {
+                    self.handler.emit_err(TypeCheckerError::invalid_enum_payload(payload, variant.span));
+                }
+            }
+        }
+
+        let tag = Member {
+            mode: Mode::None,
+            identifier: Identifier {
+                span: enum_.identifier.span,
+                ..Identifier::new(Self::tag(), self.node_builder.next_id())
+            },
+            type_: Type::Integer(IntegerType::U8),
+            span: enum_.identifier.span,
+            id: self.node_builder.next_id(),
+        };
+        let payloads = enum_.variants.iter().filter_map(|variant| {
+            Some(Member {
+                mode: Mode::None,
+                identifier: variant.identifier,
+                type_: variant.payload.clone()?,
+                span: variant.span,
+                id: variant.id,
+            })
+        });
+
+        Struct {
+            identifier: enum_.identifier,
+            members: std::iter::once(tag).chain(payloads).collect(),
+            is_record: false,
+            span: enum_.span,
+            id: enum_.id,
+        }
+    }
+}
+
+impl ProgramReconstructor for EnumLowerer<'_> {
+    fn reconstruct_program(&mut self, input: Program) -> Program {
+        // Collect the structs of the stubs, which can be payloads of variants.
+        self.structs = input.stubs.values().flat_map(|stub| stub.structs.iter().cloned()).collect();
+
+        Program {
+            program_scopes: input
+                .program_scopes
+                .into_iter()
+                .map(|(id, scope)| (id, self.reconstruct_program_scope(scope)))
+                .collect(),
+            ..input
+        }
+    }
+
+    /// Replaces the enums in the program scope with structs, and lowers their uses in the functions.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        self.structs.extend(input.structs.iter().cloned());
+        self.enums = input.enums.iter().cloned().collect();
+
+        // Lower the enums into structs.
+        let enum_structs = input.enums.iter().map(|(name, enum_)| (*name, self.lower_enum(enum_))).collect::<Vec<_>>();
+
+        ProgramScope {
+            program_id: input.program_id,
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            structs: input.structs.into_iter().chain(enum_structs).collect(),
+            enums: Vec::new(),
+            mappings: input.mappings,
+            functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
+            span: input.span,
+        }
+    }
+}
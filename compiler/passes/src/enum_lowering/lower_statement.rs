@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::EnumLowerer;
+
+use leo_ast::{
+    AccessExpression,
+    Block,
+    ConditionalStatement,
+    DeclarationType,
+    DefinitionStatement,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    MatchArm,
+    MatchPattern,
+    MatchStatement,
+    MemberAccess,
+    Node,
+    Statement,
+    StatementReconstructor,
+    Type,
+};
+use leo_errors::TypeCheckerError;
+use leo_span::Symbol;
+
+use itertools::Itertools;
+
+impl EnumLowerer<'_> {
+    /// Lowers the block of a match arm, first binding the payload of the matched variant if the arm names it.
+    fn lower_arm(&mut self, arm: MatchArm, variable: Identifier) -> Block {
+        let mut block = self.reconstruct_block(arm.block).0;
+
+        if let MatchPattern::Variant { enum_name, variant, binding: Some(binding), .. } = arm.pattern {
+            let payload = self.enums.get(&enum_name.name).and_then(|enum_| enum_.variant(variant.name));
+            if let Some((_, definition)) = payload {
+                let type_ = definition.payload.as_ref().map_or(Type::Err, |type_| self.copy_type(type_));
+                let value = Expression::Access(AccessExpression::Member(MemberAccess {
+                    inner: Box::new(Expression::Identifier(self.identifier(variable))),
+                    name: self.identifier(variant),
+                    span: binding.span,
+                    id: self.node_builder.next_id(),
+                }));
+                block.statements.insert(
+                    0,
+                    Statement::Definition(DefinitionStatement {
+                        declaration_type: DeclarationType::Let,
+                        place: Expression::Identifier(binding),
+                        type_,
+                        value,
+                        span: binding.span,
+                        id: self.node_builder.next_id(),
+                    }),
+                );
+            }
+        }
+
+        block
+    }
+}
+
+impl StatementReconstructor for EnumLowerer<'_> {
+    /// Lowers a match statement into a chain of conditionals on the tag of the matched value.
+    fn reconstruct_match(&mut self, input: MatchStatement) -> (Statement, Self::AdditionalOutput) {
+        // The enum is named by the patterns of the arms.
+        let enum_name = input.arms.iter().find_map(|arm| match &arm.pattern {
+            MatchPattern::Variant { enum_name, .. } => Some(*enum_name),
+            MatchPattern::Wildcard => None,
+        });
+        let Some(enum_name) = enum_name else {
+            self.handler.emit_err(TypeCheckerError::match_without_variants(input.span));
+            return (Statement::dummy(input.span, input.id), Default::default());
+        };
+        let Some(enum_) = self.enums.get(&enum_name.name).cloned() else {
+            self.handler.emit_err(TypeCheckerError::not_an_enum(enum_name, enum_name.span));
+            return (Statement::dummy(input.span, input.id), Default::default());
+        };
+
+        // Check the arms, pairing each arm with the position of the variant it matches, or `None` for the wildcard.
+        let mut matched = vec![false; enum_.variants.len()];
+        let mut arms = Vec::with_capacity(input.arms.len());
+        for arm in input.arms {
+            if matched.iter().all(|matched| *matched) || matches!(arms.last(), Some((None, _))) {
+                self.handler.emit_err(TypeCheckerError::unreachable_match_arm(arm.span));
+                continue;
+            }
+            match &arm.pattern {
+                MatchPattern::Wildcard => arms.push((None, arm)),
+                MatchPattern::Variant { enum_name, variant, has_payload, .. } => {
+                    if enum_name.name != enum_.name() {
+                        self.handler.emit_err(TypeCheckerError::match_arm_enum_mismatch(
+                            enum_.identifier,
+                            enum_name,
+                            enum_name.span,
+                        ));
+                        continue;
+                    }
+                    let Some((position, definition)) = enum_.variant(variant.name) else {
+                        self.handler.emit_err(TypeCheckerError::unknown_enum_variant(
+                            enum_.identifier,
+                            variant,
+                            variant.span,
+                        ));
+                        continue;
+                    };
+                    match (&definition.payload, has_payload) {
+                        (Some(type_), false) => self.handler.emit_err(TypeCheckerError::enum_variant_payload_mismatch(
+                            format!("{enum_name}::{variant}"),
+                            format!("has a payload of type `{type_}`"),
+                            arm.span,
+                        )),
+                        (None, true) => self.handler.emit_err(TypeCheckerError::enum_variant_payload_mismatch(
+                            format!("{enum_name}::{variant}"),
+                            "does not have a payload",
+                            arm.span,
+                        )),
+                        _ => {}
+                    }
+                    if matched[position] {
+                        self.handler.emit_err(TypeCheckerError::unreachable_match_arm(arm.span));
+                        continue;
+                    }
+                    matched[position] = true;
+                    arms.push((Some(position), arm));
+                }
+            }
+        }
+
+        // Check that the match is exhaustive.
+        if !matches!(arms.last(), Some((None, _))) {
+            let missing = enum_
+                .variants
+                .iter()
+                .zip(matched.iter())
+                .filter(|(_, matched)| !**matched)
+                .map(|(variant, _)| format!("`{}::{}`", enum_.identifier, variant.identifier))
+                .join(", ");
+            if !missing.is_empty() {
+                self.handler.emit_err(TypeCheckerError::non_exhaustive_match(missing, input.span));
+            }
+        }
+
+        // Assign the matched expression to a new variable, unless it is a variable.
+        let (variable, definition) = match self.reconstruct_expression(input.expression).0 {
+            Expression::Identifier(identifier) => (identifier, None),
+            expression => {
+                let variable = Identifier {
+                    span: expression.span(),
+                    ..Identifier::new(
+                        Symbol::intern(&format!("$match${}", self.match_counter)),
+                        self.node_builder.next_id(),
+                    )
+                };
+                self.match_counter += 1;
+                let definition = Statement::Definition(DefinitionStatement {
+                    declaration_type: DeclarationType::Let,
+                    place: Expression::Identifier(variable),
+                    type_: Type::Identifier(self.identifier(enum_.identifier)),
+                    span: expression.span(),
+                    value: expression,
+                    id: self.node_builder.next_id(),
+                });
+                (variable, Some(definition))
+            }
+        };
+
+        // Construct the conditionals from the last arm to the first.
+        // Since the match is exhaustive, the last arm is taken whenever none of the other arms are.
+        let mut statement: Option<Statement> = None;
+        for (position, arm) in arms.into_iter().rev() {
+            let span = arm.span;
+            let block = self.lower_arm(arm, variable);
+            statement = Some(match (position, statement) {
+                (Some(position), Some(otherwise)) => Statement::Conditional(ConditionalStatement {
+                    condition: self.tag_equals(variable, position, span),
+                    then: block,
+                    otherwise: Some(Box::new(otherwise)),
+                    span,
+                    id: self.node_builder.next_id(),
+                }),
+                (_, _) => Statement::Block(block),
+            });
+        }
+        let statement = statement.unwrap_or_else(|| Statement::dummy(input.span, input.id));
+
+        match definition {
+            Some(definition) => (
+                Statement::Block(Block { statements: vec![definition, statement], span: input.span, id: input.id }),
+                Default::default(),
+            ),
+            None => (statement, Default::default()),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The enum lowering pass replaces each enum with a struct, and each match statement with a chain of conditionals.
+//! The struct has a `u8` member `tag`, holding the position of the variant, and a member for the payload of each variant.
+//! The payload members of the other variants hold default values.
+//! The pass runs before the symbol table is created, so that the later passes only see structs.
+//! The pass also checks that the variants in a match statement exist, and that every variant is matched.
+//! The payloads of variants are type checked by the `TypeChecker`, as members of the lowered struct.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! enum Status {
+//!     Pending,
+//!     Filled(u64),
+//! }
+//!
+//! transition main(amount: u64) -> u64 {
+//!     let status: Status = Status::Filled(amount);
+//!     match status {
+//!         Status::Pending => { return 0u64; }
+//!         Status::Filled(filled) => { return filled; }
+//!     }
+//! }
+//! ```
+//!
+//! The enum lowering pass produces the following code.
+//! ```leo
+//! struct Status {
+//!     tag: u8,
+//!     Filled: u64,
+//! }
+//!
+//! transition main(amount: u64) -> u64 {
+//!     let status: Status = Status { tag: 1u8, Filled: amount };
+//!     if status.tag == 0u8 {
+//!         return 0u64;
+//!     } else {
+//!         let filled: u64 = status.Filled;
+//!         return filled;
+//!     }
+//! }
+//! ```
+//! Note that a matched expression which is not a variable is first assigned to a new variable.
+
+mod lower_expression;
+
+mod lower_program;
+
+mod lower_statement;
+
+pub mod enum_lowerer;
+pub use enum_lowerer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for EnumLowerer<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = EnumLowerer::new(handler, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
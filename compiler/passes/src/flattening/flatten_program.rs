@@ -57,6 +57,7 @@ impl ProgramReconstructor for Flattener<'_> {
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
+            const_parameters: function.const_parameters,
             input: function.input,
             output: function.output,
             output_type: function.output_type,
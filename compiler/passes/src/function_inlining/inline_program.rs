@@ -51,6 +51,7 @@ impl ProgramReconstructor for FunctionInliner<'_> {
         ProgramScope {
             program_id: input.program_id,
             structs: input.structs,
+            enums: input.enums,
             mappings: input.mappings,
             functions,
             consts: input.consts,
@@ -23,24 +23,39 @@ pub use code_generation::*;
 pub mod common;
 pub use common::*;
 
+pub mod constant_folding;
+pub use constant_folding::*;
+
 pub mod dead_code_elimination;
 pub use dead_code_elimination::*;
 
 pub mod destructuring;
 pub use destructuring::*;
 
+pub mod enum_lowering;
+pub use enum_lowering::*;
+
 pub mod flattening;
 pub use flattening::*;
 
 pub mod function_inlining;
 pub use function_inlining::*;
 
+pub mod linting;
+pub use linting::*;
+
 pub mod loop_unrolling;
 pub use self::loop_unrolling::*;
 
+pub mod monomorphization;
+pub use monomorphization::*;
+
 pub mod pass;
 pub use self::pass::*;
 
+pub mod privacy_analysis;
+pub use privacy_analysis::*;
+
 pub mod static_single_assignment;
 pub use static_single_assignment::*;
 
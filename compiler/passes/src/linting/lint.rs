@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use indexmap::IndexSet;
+use std::fmt;
+
+/// A rule checked by the [`Linter`](crate::Linter).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// A variable that is declared but never read.
+    UnusedVariable,
+    /// An inline function that is never called.
+    UnusedInline,
+    /// A mapping that is never read or written.
+    UnusedMapping,
+    /// A name that is bound more than once in the same function.
+    ShadowedBinding,
+    /// An assertion whose condition is known at compile time.
+    ConstantAssert,
+    /// A record input of a transition that is never used.
+    UnusedRecord,
+}
+
+impl Lint {
+    /// All of the lints, in the order they are documented.
+    pub const ALL: [Lint; 6] = [
+        Lint::UnusedVariable,
+        Lint::UnusedInline,
+        Lint::UnusedMapping,
+        Lint::ShadowedBinding,
+        Lint::ConstantAssert,
+        Lint::UnusedRecord,
+    ];
+
+    /// Returns the name of the lint, as written in `@allow(...)` and `program.json`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariable => "unused_variable",
+            Lint::UnusedInline => "unused_inline",
+            Lint::UnusedMapping => "unused_mapping",
+            Lint::ShadowedBinding => "shadowed_binding",
+            Lint::ConstantAssert => "constant_assert",
+            Lint::UnusedRecord => "unused_record",
+        }
+    }
+
+    /// Returns the lint with the given name, if there is one.
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+
+    /// Returns the names of all of the lints, separated by commas.
+    pub fn names() -> String {
+        Lint::ALL.iter().map(|lint| format!("`{}`", lint.name())).collect::<Vec<_>>().join(", ")
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// The set of lints that are enabled for a package. All lints are enabled by default.
+#[derive(Clone, Debug, Default)]
+pub struct LintConfig {
+    /// The lints that have been disabled.
+    disabled: IndexSet<Lint>,
+}
+
+impl LintConfig {
+    /// Enables or disables a lint.
+    pub fn set(&mut self, lint: Lint, enabled: bool) {
+        match enabled {
+            true => self.disabled.shift_remove(&lint),
+            false => self.disabled.insert(lint),
+        };
+    }
+
+    /// Returns whether or not the lint is enabled.
+    pub fn is_enabled(&self, lint: Lint) -> bool {
+        !self.disabled.contains(&lint)
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::Linter;
+
+use leo_ast::*;
+use leo_span::sym;
+
+impl<'a> ExpressionVisitor<'a> for Linter<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Record the mappings that are read or written.
+        if let AccessExpression::AssociatedFunction(function) = input {
+            if let (Type::Identifier(Identifier { name: sym::Mapping, .. }), Some(Expression::Identifier(mapping))) =
+                (&function.ty, function.arguments.first())
+            {
+                self.used_mappings.insert(mapping.name);
+            }
+        }
+
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => self.visit_expression(&member.inner, additional),
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        input.members.iter().for_each(|member| match &member.expression {
+            Some(expression) => self.visit_expression(expression, additional),
+            // A member initialized with the shorthand `Foo { x }` reads the variable `x`.
+            None => {
+                self.used.insert(member.identifier.name);
+            }
+        });
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.used.insert(input.name);
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{Lint, Linter};
+
+use leo_ast::*;
+use leo_errors::StaticAnalyzerWarning;
+
+impl<'a> ProgramVisitor<'a> for Linter<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        // Imported programs and stubs are linted when they are built.
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        self.used_mappings.clear();
+
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+
+        // Check that every inline function is called.
+        for (name, function) in input.functions.iter() {
+            if function.variant == Variant::Inline && !self.call_graph.has_predecessors(*name) {
+                self.allow(function);
+                self.emit_warning(
+                    Lint::UnusedInline,
+                    StaticAnalyzerWarning::unused_inline(name, Lint::UnusedInline, function.identifier.span),
+                );
+            }
+        }
+
+        // Mappings cannot be annotated, so they are only subject to the package configuration.
+        self.allowed.clear();
+        for (name, mapping) in input.mappings.iter() {
+            if !self.used_mappings.contains(name) {
+                self.emit_warning(
+                    Lint::UnusedMapping,
+                    StaticAnalyzerWarning::unused_mapping(name, Lint::UnusedMapping, mapping.identifier.span),
+                );
+            }
+        }
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.allow(input);
+
+        self.visit_block(&input.block);
+
+        // Check that the transition uses every record it receives.
+        if input.variant == Variant::Transition {
+            for function_input in input.input.iter() {
+                let is_record = match function_input {
+                    Input::External(_) => true,
+                    Input::Internal(internal) => match &internal.type_ {
+                        Type::Identifier(identifier) => {
+                            self.symbol_table.lookup_struct(identifier.name).map_or(false, |struct_| struct_.is_record)
+                        }
+                        _ => false,
+                    },
+                };
+                let name = function_input.identifier();
+                if is_record && !self.used.contains(&name.name) {
+                    self.emit_warning(
+                        Lint::UnusedRecord,
+                        StaticAnalyzerWarning::unused_record(name, Lint::UnusedRecord, function_input.span()),
+                    );
+                }
+            }
+        }
+
+        self.check_unused_variables();
+
+        if let Some(finalize) = &input.finalize {
+            self.visit_block(&finalize.block);
+            self.check_unused_variables();
+        }
+    }
+}
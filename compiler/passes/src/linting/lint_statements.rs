@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{Lint, Linter};
+
+use leo_ast::*;
+use leo_errors::StaticAnalyzerWarning;
+
+impl<'a> StatementVisitor<'a> for Linter<'a> {
+    fn visit_assert(&mut self, input: &'a AssertStatement) {
+        // Check whether the assertion can be decided without running the program.
+        let value = match &input.variant {
+            AssertVariant::Assert(Expression::Literal(Literal::Boolean(value, ..))) => Some(*value),
+            AssertVariant::AssertEq(left, right) => constant_equality(left, right),
+            AssertVariant::AssertNeq(left, right) => constant_equality(left, right).map(|equal| !equal),
+            _ => None,
+        };
+        if let Some(value) = value {
+            self.emit_warning(
+                Lint::ConstantAssert,
+                StaticAnalyzerWarning::constant_assert(value, Lint::ConstantAssert, input.span),
+            );
+        }
+
+        match &input.variant {
+            AssertVariant::Assert(expr) => self.visit_expression(expr, &()),
+            AssertVariant::AssertEq(left, right) | AssertVariant::AssertNeq(left, right) => {
+                self.visit_expression(left, &());
+                self.visit_expression(right, &());
+            }
+        };
+    }
+
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        // Assigning to a variable does not read it, but the indices of the place are read.
+        self.visit_place(&input.place);
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_expression(&input.value, &());
+        self.declare(&Expression::Identifier(input.place));
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.value, &());
+        self.declare(&input.place);
+    }
+}
+
+impl<'a> Linter<'a> {
+    /// Visits the expressions read when assigning to `place`.
+    fn visit_place(&mut self, place: &'a Expression) {
+        match place {
+            Expression::Access(AccessExpression::Array(access)) => {
+                self.visit_place(&access.array);
+                self.visit_expression(&access.index, &());
+            }
+            Expression::Access(AccessExpression::Member(access)) => self.visit_place(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => self.visit_place(&access.tuple),
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.visit_place(element)),
+            _ => {}
+        }
+    }
+}
+
+/// Returns whether `left` and `right` are equal, if it is known at compile time.
+fn constant_equality(left: &Expression, right: &Expression) -> Option<bool> {
+    match (left, right) {
+        (Expression::Literal(Literal::Boolean(left, ..)), Expression::Literal(Literal::Boolean(right, ..))) => {
+            Some(left == right)
+        }
+        (
+            Expression::Literal(Literal::Integer(left_type, left, ..)),
+            Expression::Literal(Literal::Integer(right_type, right, ..)),
+        ) if left_type == right_type => {
+            let parse = |value: &String| value.replace('_', "").parse::<u128>().ok();
+            Some(parse(left)? == parse(right)?)
+        }
+        (Expression::Identifier(left), Expression::Identifier(right)) if left.name == right.name => Some(true),
+        _ => None,
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{CallGraph, Lint, LintConfig, SymbolTable};
+
+use leo_ast::{Expression, Function, Identifier};
+use leo_errors::{emitter::Handler, StaticAnalyzerWarning};
+use leo_span::{sym, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// Checks a type-checked program against the enabled lints, reporting violations as warnings.
+pub struct Linter<'a> {
+    /// The symbol table for the program.
+    pub(crate) symbol_table: &'a SymbolTable,
+    /// The call graph for the program.
+    pub(crate) call_graph: &'a CallGraph,
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// The lints enabled for the package.
+    pub(crate) config: &'a LintConfig,
+    /// The lints allowed by the `@allow` annotations of the function that we are currently traversing.
+    pub(crate) allowed: IndexSet<Lint>,
+    /// The variables declared in the current function, in order of declaration.
+    pub(crate) declared: IndexMap<Symbol, Span>,
+    /// The variables read in the current function.
+    pub(crate) used: IndexSet<Symbol>,
+    /// The mappings read or written in the program.
+    pub(crate) used_mappings: IndexSet<Symbol>,
+}
+
+impl<'a> Linter<'a> {
+    /// Returns a new linter given a symbol table, call graph, error handler, and lint configuration.
+    pub fn new(
+        symbol_table: &'a SymbolTable,
+        call_graph: &'a CallGraph,
+        handler: &'a Handler,
+        config: &'a LintConfig,
+    ) -> Self {
+        Self {
+            symbol_table,
+            call_graph,
+            handler,
+            config,
+            allowed: IndexSet::new(),
+            declared: IndexMap::new(),
+            used: IndexSet::new(),
+            used_mappings: IndexSet::new(),
+        }
+    }
+
+    /// Returns whether or not the lint is enabled in the current context.
+    pub(crate) fn is_enabled(&self, lint: Lint) -> bool {
+        self.config.is_enabled(lint) && !self.allowed.contains(&lint)
+    }
+
+    /// Emits a warning for the lint, if it is enabled.
+    pub(crate) fn emit_warning(&self, lint: Lint, warning: StaticAnalyzerWarning) {
+        if self.is_enabled(lint) {
+            self.handler.emit_warning(warning.into());
+        }
+    }
+
+    /// Sets the lints allowed by the `@allow` annotations of `function`.
+    pub(crate) fn allow(&mut self, function: &Function) {
+        self.allowed = function
+            .annotations
+            .iter()
+            .filter(|annotation| annotation.identifier.name == sym::allow)
+            .flat_map(|annotation| annotation.arguments.iter())
+            .filter_map(|argument| Lint::from_name(&argument.name.to_string()))
+            .collect();
+    }
+
+    /// Records the variables bound by `place`, warning if a name is bound more than once.
+    pub(crate) fn declare(&mut self, place: &Expression) {
+        match place {
+            Expression::Identifier(Identifier { name, span, .. }) => {
+                if self.declared.contains_key(name) {
+                    self.emit_warning(
+                        Lint::ShadowedBinding,
+                        StaticAnalyzerWarning::shadowed_binding(name, Lint::ShadowedBinding, *span),
+                    );
+                }
+                self.declared.insert(*name, *span);
+            }
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.declare(element)),
+            _ => {}
+        }
+    }
+
+    /// Warns about the variables declared in the current function that were never read, and resets the state.
+    pub(crate) fn check_unused_variables(&mut self) {
+        for (name, span) in std::mem::take(&mut self.declared) {
+            if !self.used.contains(&name) {
+                self.emit_warning(
+                    Lint::UnusedVariable,
+                    StaticAnalyzerWarning::unused_variable(name, Lint::UnusedVariable, span),
+                );
+            }
+        }
+        self.used.clear();
+    }
+}
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+pub mod lint;
+pub use lint::*;
+
+pub mod lint_expressions;
+pub use lint_expressions::*;
+
+pub mod lint_program;
+pub use lint_program::*;
+
+pub mod lint_statements;
+pub use lint_statements::*;
+
+pub mod linter;
+pub use linter::*;
+
+use crate::{CallGraph, Pass, SymbolTable};
+
+use leo_ast::{Ast, ProgramVisitor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for Linter<'a> {
+    type Input = (&'a Ast, &'a Handler, &'a SymbolTable, &'a CallGraph, &'a LintConfig);
+    type Output = Result<()>;
+
+    fn do_pass((ast, handler, st, call_graph, config): Self::Input) -> Self::Output {
+        let mut visitor = Linter::new(st, call_graph, handler, config);
+        visitor.visit_program(ast.as_repr());
+
+        Ok(())
+    }
+}
@@ -14,8 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use num_traits::One;
-use std::{fmt::Display, ops::Add};
+use num_traits::{CheckedAdd, CheckedSub, One};
+use std::fmt::Display;
 
 use leo_ast::Value;
 use leo_errors::LeoError;
@@ -24,7 +24,7 @@ use leo_errors::LeoError;
 // TODO: Better name.
 /// A trait for whose implementors are concrete values for loop bounds.
 pub(crate) trait LoopBound:
-    Add<Output = Self> + Copy + Display + One + PartialOrd + TryFrom<Value, Error = LeoError>
+    CheckedAdd + CheckedSub + Copy + Display + One + PartialOrd + TryFrom<Value, Error = LeoError>
 {
 }
 
@@ -37,16 +37,18 @@ pub(crate) enum Clusivity {
     Exclusive,
 }
 
-/// An iterator over a range of values.
+/// An iterator over a range of values, which counts down if the start of the range is greater than its end.
 pub(crate) struct RangeIterator<I: LoopBound> {
     end: I,
     current: Option<I>,
+    step: I,
+    descending: bool,
     clusivity: Clusivity,
 }
 
 impl<I: LoopBound> RangeIterator<I> {
-    pub(crate) fn new(start: I, end: I, clusivity: Clusivity) -> Self {
-        Self { end, current: Some(start), clusivity }
+    pub(crate) fn new(start: I, end: I, step: I, clusivity: Clusivity) -> Self {
+        Self { end, current: Some(start), step, descending: start > end, clusivity }
     }
 }
 
@@ -54,19 +56,22 @@ impl<I: LoopBound> Iterator for RangeIterator<I> {
     type Item = I;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.current {
-            None => None,
-            Some(value) if value < self.end => {
-                self.current = Some(value.add(I::one()));
-                Some(value)
-            }
-            Some(value) => {
-                self.current = None;
-                match self.clusivity {
-                    Clusivity::Exclusive => None,
-                    Clusivity::Inclusive => Some(value),
-                }
-            }
+        let value = self.current?;
+        let in_range = match (self.descending, &self.clusivity) {
+            (false, Clusivity::Exclusive) => value < self.end,
+            (false, Clusivity::Inclusive) => value <= self.end,
+            (true, Clusivity::Exclusive) => value > self.end,
+            (true, Clusivity::Inclusive) => value >= self.end,
+        };
+        if !in_range {
+            self.current = None;
+            return None;
         }
+        // Note that the range ends if the next value overflows, since it would be past the end of the range.
+        self.current = match self.descending {
+            false => value.checked_add(&self.step),
+            true => value.checked_sub(&self.step),
+        };
+        Some(value)
     }
 }
@@ -15,7 +15,6 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::*;
-use leo_errors::LoopUnrollerError;
 
 use crate::Unroller;
 
@@ -24,11 +23,8 @@ impl ExpressionReconstructor for Unroller<'_> {
 
     fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
         // Reconstruct the index.
+        // Note that indices which are not literals after unrolling are lowered during static single assignment.
         let index = self.reconstruct_expression(*input.index).0;
-        // If the index is not a literal, then emit an error.
-        if !matches!(index, Expression::Literal(_)) {
-            self.emit_err(LoopUnrollerError::variable_array_access(input.span));
-        }
 
         (
             Expression::Access(AccessExpression::Array(ArrayAccess {
@@ -27,6 +27,7 @@ impl ProgramReconstructor for Unroller<'_> {
         ProgramScope {
             program_id: input.program_id,
             structs: input.structs,
+            enums: input.enums,
             mappings: input.mappings,
             functions: input.functions.into_iter().map(|(i, f)| (i, self.reconstruct_function(f))).collect(),
             consts: Vec::new(),
@@ -86,6 +87,7 @@ impl ProgramReconstructor for Unroller<'_> {
             annotations: function.annotations,
             variant: function.variant,
             identifier: function.identifier,
+            const_parameters: function.const_parameters,
             input: function.input,
             output: function.output,
             output_type: function.output_type,
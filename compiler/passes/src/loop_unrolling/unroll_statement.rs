@@ -15,13 +15,34 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use itertools::Itertools;
-use leo_ast::{Expression::Literal, Type::Integer, *};
-use leo_errors::loop_unroller::LoopUnrollerError;
+use leo_ast::{Expression::Literal, *};
 use leo_span::{Span, Symbol};
 
 use crate::{unroller::Unroller, VariableSymbol, VariableType};
 
 impl StatementReconstructor for Unroller<'_> {
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        // Reconstruct the place if it is a member, tuple element, or array element, so that array indices become constant.
+        let place = match input.place {
+            Expression::Identifier(_) => input.place,
+            place => {
+                let place = self.reconstruct_expression(place).0;
+                self.check_assignment_bounds(&place);
+                place
+            }
+        };
+
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: input.id,
+            })),
+            Default::default(),
+        )
+    }
+
     fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
         let scope_index = self.current_scope_index();
 
@@ -128,70 +149,44 @@ impl StatementReconstructor for Unroller<'_> {
     }
 
     fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
-        // Reconstruct the bound expressions
-        let (new_start, _) = self.reconstruct_expression(input.start);
-        let (new_stop, _) = self.reconstruct_expression(input.stop);
-
-        // Convert into values
-        match (new_start.clone(), new_stop.clone()) {
-            (Literal(start_lit), Literal(stop_lit)) => {
-                input.start_value.replace(Some(Value::try_from(&start_lit).unwrap()));
-                input.stop_value.replace(Some(Value::try_from(&stop_lit).unwrap()));
-            }
-            _ => unreachable!("Type checking guarantees that the loop bounds are literals."),
-        };
+        let range = match input.range {
+            IterationRange::Range { start, start_value, stop, stop_value, inclusive, step, step_value } => {
+                // Reconstruct the bound expressions
+                let (new_start, _) = self.reconstruct_expression(start);
+                let (new_stop, _) = self.reconstruct_expression(stop);
+                let new_step = step.map(|step| self.reconstruct_expression(step).0);
+
+                // Convert into values
+                let bounds = [(&new_start, &start_value), (&new_stop, &stop_value)];
+                for (bound, value) in bounds.into_iter().chain(new_step.as_ref().map(|step| (step, &step_value))) {
+                    match bound {
+                        Literal(literal) => value.replace(Some(Value::try_from(literal).unwrap())),
+                        _ => unreachable!("Type checking guarantees that the loop bounds are literals."),
+                    };
+                }
 
-        // Ensure loop bounds are increasing. This cannot be done in the type checker because constant propagation occurs in this pass.
-        if match (input.type_.clone(), input.start_value.borrow().as_ref(), input.stop_value.borrow().as_ref()) {
-            (Integer(IntegerType::I8), Some(Value::I8(lower_bound, _)), Some(Value::I8(upper_bound, _))) => {
-                lower_bound >= upper_bound
-            }
-            (Integer(IntegerType::I16), Some(Value::I16(lower_bound, _)), Some(Value::I16(upper_bound, _))) => {
-                lower_bound >= upper_bound
-            }
-            (Integer(IntegerType::I32), Some(Value::I32(lower_bound, _)), Some(Value::I32(upper_bound, _))) => {
-                lower_bound >= upper_bound
-            }
-            (Integer(IntegerType::I64), Some(Value::I64(lower_bound, _)), Some(Value::I64(upper_bound, _))) => {
-                lower_bound >= upper_bound
-            }
-            (Integer(IntegerType::I128), Some(Value::I128(lower_bound, _)), Some(Value::I128(upper_bound, _))) => {
-                lower_bound >= upper_bound
-            }
-            (Integer(IntegerType::U8), Some(Value::U8(lower_bound, _)), Some(Value::U8(upper_bound, _))) => {
-                lower_bound >= upper_bound
-            }
-            (Integer(IntegerType::U16), Some(Value::U16(lower_bound, _)), Some(Value::U16(upper_bound, _))) => {
-                lower_bound >= upper_bound
-            }
-            (Integer(IntegerType::U32), Some(Value::U32(lower_bound, _)), Some(Value::U32(upper_bound, _))) => {
-                lower_bound >= upper_bound
-            }
-            (Integer(IntegerType::U64), Some(Value::U64(lower_bound, _)), Some(Value::U64(upper_bound, _))) => {
-                lower_bound >= upper_bound
+                IterationRange::Range {
+                    start: new_start,
+                    start_value,
+                    stop: new_stop,
+                    stop_value,
+                    inclusive,
+                    step: new_step,
+                    step_value,
+                }
             }
-            (Integer(IntegerType::U128), Some(Value::U128(lower_bound, _)), Some(Value::U128(upper_bound, _))) => {
-                lower_bound >= upper_bound
+            IterationRange::Array { array } => {
+                // Note that the type of the array is looked up before constant propagation replaces it.
+                let array_type = match self.type_table.get(&array.id()) {
+                    Some(Type::Array(array_type)) => array_type,
+                    _ => unreachable!("Type checking guarantees that loops iterate over ranges or arrays."),
+                };
+                let (array, _) = self.reconstruct_expression(array);
+                let input = IterationStatement { range: IterationRange::Array { array }, ..input };
+                return (self.unroll_array_iteration(input, array_type), Default::default());
             }
-            _ => unreachable!("Type checking guarantees that the loop bounds have same type as loop variable."),
-        } {
-            self.emit_err(LoopUnrollerError::loop_range_decreasing(new_stop.span()));
-        }
+        };
 
-        (
-            self.unroll_iteration_statement::<i128>(IterationStatement {
-                variable: input.variable,
-                type_: input.type_,
-                start: new_start,
-                stop: new_stop,
-                start_value: input.start_value.clone(),
-                stop_value: input.stop_value.clone(),
-                inclusive: false,
-                block: input.block,
-                span: input.span,
-                id: input.id,
-            }),
-            Default::default(),
-        )
+        (self.unroll_iteration_statement::<i128>(IterationStatement { range, ..input }), Default::default())
     }
 }
@@ -15,17 +15,26 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayType,
     Block,
+    DeclarationType,
+    DefinitionStatement,
     Expression,
+    Identifier,
     IntegerType,
+    IterationRange,
     IterationStatement,
     Literal,
+    Node,
     NodeBuilder,
     Statement,
     StatementReconstructor,
     Type,
     Value,
 };
+use leo_span::Symbol;
 use std::cell::RefCell;
 
 use leo_errors::{emitter::Handler, loop_unroller::LoopUnrollerError};
@@ -114,10 +123,38 @@ impl<'a> Unroller<'a> {
         self.handler.emit_err(err);
     }
 
-    /// Unrolls an IterationStatement.
+    /// Emits an error if the place of an assignment assigns to an element past the end of an array.
+    pub(crate) fn check_assignment_bounds(&self, place: &Expression) {
+        match place {
+            Expression::Access(AccessExpression::Array(access)) => {
+                if let (Expression::Literal(Literal::Integer(_, index, ..)), Some(Type::Array(array_type))) =
+                    (&*access.index, self.type_table.get(&access.array.id()))
+                {
+                    if !matches!(index.replace('_', "").parse::<usize>(), Ok(index) if index < array_type.length()) {
+                        self.emit_err(LoopUnrollerError::array_assignment_out_of_bounds(
+                            index,
+                            array_type.length(),
+                            access.span,
+                        ));
+                    }
+                }
+                self.check_assignment_bounds(&access.array);
+            }
+            Expression::Access(AccessExpression::Member(access)) => self.check_assignment_bounds(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => self.check_assignment_bounds(&access.tuple),
+            _ => {}
+        }
+    }
+
+    /// Unrolls an IterationStatement over a range.
     pub(crate) fn unroll_iteration_statement<I: LoopBound>(&mut self, input: IterationStatement) -> Statement {
-        let start: Value = input.start_value.borrow().as_ref().expect("Failed to get start value").clone();
-        let stop: Value = input.stop_value.borrow().as_ref().expect("Failed to get stop value").clone();
+        let IterationRange::Range { start_value, stop_value, inclusive, step, step_value, .. } = &input.range else {
+            unreachable!("Only loops over ranges are unrolled by `unroll_iteration_statement`.")
+        };
+        let start: Value = start_value.borrow().as_ref().expect("Failed to get start value").clone();
+        let stop: Value = stop_value.borrow().as_ref().expect("Failed to get stop value").clone();
+        let step: Option<Value> =
+            step.as_ref().map(|_| step_value.borrow().as_ref().expect("Failed to get step value").clone());
 
         // Closure to check that the constant values are valid u128.
         // We already know these are integers since loop unrolling occurs after type checking.
@@ -141,7 +178,103 @@ impl<'a> Unroller<'a> {
             Ok(v) => v,
             Err(s) => return s,
         };
+        // Cast `step` to `I`, defaulting to one.
+        let step = match step.map(cast_to_number) {
+            None => I::one(),
+            Some(Ok(v)) => v,
+            Some(Err(s)) => return s,
+        };
+
+        // Ensure the step is positive, since the direction of the loop is given by its bounds.
+        // This cannot be done in the type checker because constant propagation occurs in this pass.
+        if step < I::one() {
+            let IterationRange::Range { step: Some(step), .. } = &input.range else {
+                unreachable!("The default step is one.")
+            };
+            self.emit_err(LoopUnrollerError::loop_step_not_positive(step.span()));
+            return Statement::dummy(input.span, self.node_builder.next_id());
+        }
+
+        let clusivity = match inclusive {
+            true => Clusivity::Inclusive,
+            false => Clusivity::Exclusive,
+        };
+        let values = RangeIterator::new(start, stop, step, clusivity)
+            .map(|value| {
+                // Construct a new node ID.
+                let id = self.node_builder.next_id();
+                // Update the type table.
+                self.type_table.insert(id, input.type_.clone());
+
+                // Reconstruct `value` as a `Literal`.
+                let integer_type = match input.type_ {
+                    Type::Integer(integer_type) => integer_type,
+                    _ => unreachable!(
+                        "The iteration variable must be an integer type. This should be enforced by type checking."
+                    ),
+                };
+                Expression::Literal(Literal::Integer(integer_type, value.to_string(), Default::default(), id))
+            })
+            .collect();
+
+        self.unroll_iterations(&input, values)
+    }
+
+    /// Unrolls an IterationStatement over the elements of an array, whose type is given.
+    pub(crate) fn unroll_array_iteration(&mut self, input: IterationStatement, array_type: ArrayType) -> Statement {
+        let IterationRange::Array { array } = &input.range else {
+            unreachable!("Only loops over arrays are unrolled by `unroll_array_iteration`.")
+        };
+
+        // Bind the array to a new variable, so that the loop iterates over its elements even if the body assigns to it.
+        let array_variable = Identifier {
+            name: Symbol::intern(&format!("{}$array", input.variable.name)),
+            span: array.span(),
+            id: self.node_builder.next_id(),
+        };
+        self.type_table.insert(array_variable.id, Type::Array(array_type.clone()));
+        let (definition, _) = self.reconstruct_definition(DefinitionStatement {
+            declaration_type: DeclarationType::Let,
+            place: Expression::Identifier(array_variable),
+            type_: Type::Array(array_type.clone()),
+            value: array.clone(),
+            span: array.span(),
+            id: self.node_builder.next_id(),
+        });
+
+        let values = (0..array_type.length())
+            .map(|index| {
+                let variable = Identifier { id: self.node_builder.next_id(), ..array_variable };
+                self.type_table.insert(variable.id, Type::Array(array_type.clone()));
+                let index_id = self.node_builder.next_id();
+                self.type_table.insert(index_id, Type::Integer(IntegerType::U32));
+                let id = self.node_builder.next_id();
+                self.type_table.insert(id, array_type.element_type().clone());
+                Expression::Access(AccessExpression::Array(ArrayAccess {
+                    array: Box::new(Expression::Identifier(variable)),
+                    index: Box::new(Expression::Literal(Literal::Integer(
+                        IntegerType::U32,
+                        index.to_string(),
+                        Default::default(),
+                        index_id,
+                    ))),
+                    span: input.variable.span,
+                    id,
+                }))
+            })
+            .collect();
+
+        let iterations = self.unroll_iterations(&input, values);
 
+        Statement::Block(Block {
+            statements: vec![definition, iterations],
+            span: input.span,
+            id: self.node_builder.next_id(),
+        })
+    }
+
+    /// Unrolls an IterationStatement into a block, with one block per value of the loop variable.
+    fn unroll_iterations(&mut self, input: &IterationStatement, values: Vec<Expression>) -> Statement {
         // Get the index of the current scope.
         let scope_index = self.current_scope_index();
 
@@ -158,16 +291,7 @@ impl<'a> Unroller<'a> {
         // Creates a new block per iteration inside the outer block statement.
         let iter_blocks = Statement::Block(Block {
             span: input.span,
-            statements: match input.inclusive {
-                true => {
-                    let iter = RangeIterator::new(start, stop, Clusivity::Inclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
-                }
-                false => {
-                    let iter = RangeIterator::new(start, stop, Clusivity::Exclusive);
-                    iter.map(|iteration_count| self.unroll_single_iteration(&input, iteration_count)).collect()
-                }
-            },
+            statements: values.into_iter().map(|value| self.unroll_single_iteration(input, value)).collect(),
             id: input.id,
         });
 
@@ -178,7 +302,7 @@ impl<'a> Unroller<'a> {
     }
 
     /// A helper function to unroll a single iteration an IterationStatement.
-    fn unroll_single_iteration<I: LoopBound>(&mut self, input: &IterationStatement, iteration_count: I) -> Statement {
+    fn unroll_single_iteration(&mut self, input: &IterationStatement, value: Expression) -> Statement {
         // Create a scope for a single unrolling of the `IterationStatement`.
         let scope_index = self.symbol_table.borrow_mut().insert_block();
         let previous_scope_index = self.enter_scope(scope_index);
@@ -186,69 +310,39 @@ impl<'a> Unroller<'a> {
         let prior_is_unrolling = self.is_unrolling;
         self.is_unrolling = true;
 
-        // Construct a new node ID.
-        let id = self.node_builder.next_id();
-        // Update the type table.
-        self.type_table.insert(id, input.type_.clone());
-
-        // Reconstruct `iteration_count` as a `Literal`.
-        let value = match input.type_ {
-            Type::Integer(IntegerType::I8) => {
-                Literal::Integer(IntegerType::I8, iteration_count.to_string(), Default::default(), id)
-            }
-            Type::Integer(IntegerType::I16) => {
-                Literal::Integer(IntegerType::I16, iteration_count.to_string(), Default::default(), id)
-            }
-            Type::Integer(IntegerType::I32) => {
-                Literal::Integer(IntegerType::I32, iteration_count.to_string(), Default::default(), id)
-            }
-            Type::Integer(IntegerType::I64) => {
-                Literal::Integer(IntegerType::I64, iteration_count.to_string(), Default::default(), id)
-            }
-            Type::Integer(IntegerType::I128) => {
-                Literal::Integer(IntegerType::I128, iteration_count.to_string(), Default::default(), id)
-            }
-            Type::Integer(IntegerType::U8) => {
-                Literal::Integer(IntegerType::U8, iteration_count.to_string(), Default::default(), id)
-            }
-            Type::Integer(IntegerType::U16) => {
-                Literal::Integer(IntegerType::U16, iteration_count.to_string(), Default::default(), id)
+        let mut statements = Vec::new();
+        match input.range {
+            // Add the loop variable as a constant for the current scope
+            IterationRange::Range { .. } => self
+                .constant_propagation_table
+                .borrow_mut()
+                .insert_constant(input.variable.name, value)
+                .expect("Failed to insert constant into CPT"),
+            // Define the loop variable as the element of the array.
+            IterationRange::Array { .. } => {
+                let place = Identifier { id: self.node_builder.next_id(), ..input.variable };
+                self.type_table.insert(place.id, input.type_.clone());
+                let (definition, _) = self.reconstruct_definition(DefinitionStatement {
+                    declaration_type: DeclarationType::Let,
+                    place: Expression::Identifier(place),
+                    type_: input.type_.clone(),
+                    span: value.span(),
+                    value,
+                    id: self.node_builder.next_id(),
+                });
+                statements.push(definition);
             }
-            Type::Integer(IntegerType::U32) => {
-                Literal::Integer(IntegerType::U32, iteration_count.to_string(), Default::default(), id)
-            }
-            Type::Integer(IntegerType::U64) => {
-                Literal::Integer(IntegerType::U64, iteration_count.to_string(), Default::default(), id)
-            }
-            Type::Integer(IntegerType::U128) => {
-                Literal::Integer(IntegerType::U128, iteration_count.to_string(), Default::default(), id)
-            }
-            _ => unreachable!(
-                "The iteration variable must be an integer type. This should be enforced by type checking."
-            ),
-        };
-
-        // Add the loop variable as a constant for the current scope
-        self.constant_propagation_table
-            .borrow_mut()
-            .insert_constant(input.variable.name, Expression::Literal(value.clone()))
-            .expect("Failed to insert constant into CPT");
+        }
 
         // Reconstruct the statements in the loop body.
-        let statements: Vec<_> = input
-            .block
-            .statements
-            .clone()
-            .into_iter()
-            .filter_map(|s| {
-                let (reconstructed_statement, additional_output) = self.reconstruct_statement(s);
-                if additional_output {
-                    None // Exclude this statement from the block since it is a constant variable definition
-                } else {
-                    Some(reconstructed_statement)
-                }
-            })
-            .collect();
+        statements.extend(input.block.statements.clone().into_iter().filter_map(|s| {
+            let (reconstructed_statement, additional_output) = self.reconstruct_statement(s);
+            if additional_output {
+                None // Exclude this statement from the block since it is a constant variable definition
+            } else {
+                Some(reconstructed_statement)
+            }
+        }));
 
         let block = Statement::Block(Block { statements, span: input.block.span, id: input.block.id });
 
@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Monomorphization pass replaces each generic `inline` function with an instance for each set of const arguments it is called with.
+//! The const parameters of an instance are replaced by their values, both in its body and in the lengths of its array types.
+//! The pass runs before the symbol table is created, so that the later passes, including the `FunctionInliner`, only see concrete functions.
+//! Generic functions that are never called are removed from the program.
+//!
+//! Consider the following Leo code.
+//! ```leo
+//! inline sum::[N: u32](a: [u8; N]) -> u8 {
+//!     let total: u8 = 0u8;
+//!     for i: u32 in 0u32..N {
+//!         total += a[i];
+//!     }
+//!     return total;
+//! }
+//!
+//! transition main(a: [u8; 4], b: [u8; 8]) -> u8 {
+//!     return sum::[4u32](a) + sum::[8u32](b);
+//! }
+//! ```
+//!
+//! The monomorphization pass produces the following code.
+//! ```leo
+//! inline sum::[4u32](a: [u8; 4]) -> u8 {
+//!     let total: u8 = 0u8;
+//!     for i: u32 in 0u32..4u32 {
+//!         total += a[i];
+//!     }
+//!     return total;
+//! }
+//!
+//! inline sum::[8u32](a: [u8; 8]) -> u8 {
+//!     let total: u8 = 0u8;
+//!     for i: u32 in 0u32..8u32 {
+//!         total += a[i];
+//!     }
+//!     return total;
+//! }
+//!
+//! transition main(a: [u8; 4], b: [u8; 8]) -> u8 {
+//!     return sum::[4u32](a) + sum::[8u32](b);
+//! }
+//! ```
+//! Note that each instance is a copy of the generic function, so its nodes are given new IDs.
+
+mod monomorphize_expression;
+
+mod monomorphize_program;
+
+mod monomorphize_statement;
+
+pub mod monomorphizer;
+pub use monomorphizer::*;
+
+use crate::Pass;
+
+use leo_ast::{Ast, NodeBuilder, ProgramReconstructor};
+use leo_errors::{emitter::Handler, Result};
+
+impl<'a> Pass for Monomorphizer<'a> {
+    type Input = (Ast, &'a Handler, &'a NodeBuilder);
+    type Output = Result<Ast>;
+
+    fn do_pass((ast, handler, node_builder): Self::Input) -> Self::Output {
+        let mut reconstructor = Monomorphizer::new(handler, node_builder);
+        let program = reconstructor.reconstruct_program(ast.into_repr());
+        handler.last_err().map_err(|e| *e)?;
+
+        Ok(Ast::new(program))
+    }
+}
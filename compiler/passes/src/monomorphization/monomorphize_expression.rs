@@ -0,0 +1,330 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Monomorphizer;
+
+use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayExpression,
+    AssociatedConstant,
+    AssociatedFunction,
+    BinaryExpression,
+    CallExpression,
+    CastExpression,
+    Expression,
+    ExpressionReconstructor,
+    Identifier,
+    Literal,
+    MemberAccess,
+    Node,
+    ProgramReconstructor,
+    StructExpression,
+    StructVariableInitializer,
+    TernaryExpression,
+    TupleAccess,
+    TupleExpression,
+    UnaryExpression,
+    UnitExpression,
+};
+use leo_errors::TypeCheckerError;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+use itertools::Itertools;
+
+impl Monomorphizer<'_> {
+    /// Returns the name of the function called with the given const arguments.
+    /// If the callee is generic, this is the name of its instance, e.g. `sum::[4u32]`, which is created if it does not exist yet.
+    fn monomorphize_callee(&mut self, callee: Identifier, const_arguments: Vec<Expression>, span: Span) -> Symbol {
+        let generic = match self.generic_functions.get(&callee.name) {
+            Some(generic) => generic.clone(),
+            None => {
+                if !const_arguments.is_empty() {
+                    self.handler.emit_err(TypeCheckerError::not_a_generic_function(callee.name, span));
+                }
+                return callee.name;
+            }
+        };
+
+        if const_arguments.is_empty() {
+            self.handler.emit_err(TypeCheckerError::missing_const_arguments(callee.name, span));
+            return callee.name;
+        }
+        if const_arguments.len() != generic.const_parameters.len() {
+            self.handler.emit_err(TypeCheckerError::incorrect_num_const_args(
+                generic.const_parameters.len(),
+                const_arguments.len(),
+                span,
+            ));
+            return callee.name;
+        }
+
+        // Check that each const argument is a literal of the type of its parameter.
+        let mut arguments = IndexMap::new();
+        for (parameter, argument) in generic.const_parameters.iter().zip_eq(&const_arguments) {
+            match self.const_argument(argument) {
+                Some(literal) => {
+                    let type_ = Self::literal_type(&literal);
+                    if type_ != parameter.type_ {
+                        self.handler.emit_err(TypeCheckerError::type_should_be(
+                            type_,
+                            &parameter.type_,
+                            argument.span(),
+                        ));
+                    }
+                    arguments.insert(parameter.identifier.name, literal);
+                }
+                None => self.handler.emit_err(TypeCheckerError::invalid_const_argument(argument.span())),
+            }
+        }
+        if arguments.len() != const_arguments.len() {
+            return callee.name;
+        }
+
+        // Create the instance, if it does not exist yet.
+        let name = Symbol::intern(&format!("{}::[{}]", callee.name, arguments.values().join(", ")));
+        if !self.instances.contains_key(&name) {
+            // Note that the instance is registered before its body is monomorphized, so that recursive calls resolve to it.
+            self.instances.insert(name, None);
+            let previous_const_arguments = std::mem::replace(&mut self.const_arguments, arguments);
+            let mut instance = self.reconstruct_function(generic);
+            self.const_arguments = previous_const_arguments;
+            instance.identifier.name = name;
+            instance.const_parameters.clear();
+            self.instances.insert(name, Some(instance));
+        }
+        name
+    }
+}
+
+impl ExpressionReconstructor for Monomorphizer<'_> {
+    type AdditionalOutput = ();
+
+    fn reconstruct_array_access(&mut self, input: ArrayAccess) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::Array(ArrayAccess {
+                array: Box::new(self.reconstruct_expression(*input.array).0),
+                index: Box::new(self.reconstruct_expression(*input.index).0),
+                span: input.span,
+                id: self.id(input.id),
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_associated_constant(&mut self, input: AssociatedConstant) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::AssociatedConstant(AssociatedConstant {
+                ty: self.reconstruct_type(input.ty),
+                name: self.identifier(input.name),
+                span: input.span,
+                id: self.id(input.id),
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_associated_function(&mut self, input: AssociatedFunction) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::AssociatedFunction(AssociatedFunction {
+                ty: self.reconstruct_type(input.ty),
+                name: self.identifier(input.name),
+                arguments: input
+                    .arguments
+                    .into_iter()
+                    .map(|argument| self.reconstruct_expression(argument).0)
+                    .collect(),
+                span: input.span,
+                id: self.id(input.id),
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_member_access(&mut self, input: MemberAccess) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::Member(MemberAccess {
+                inner: Box::new(self.reconstruct_expression(*input.inner).0),
+                name: self.identifier(input.name),
+                span: input.span,
+                id: self.id(input.id),
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_tuple_access(&mut self, input: TupleAccess) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Access(AccessExpression::Tuple(TupleAccess {
+                tuple: Box::new(self.reconstruct_expression(*input.tuple).0),
+                index: input.index,
+                span: input.span,
+                id: self.id(input.id),
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_array(&mut self, input: ArrayExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Array(ArrayExpression {
+                elements: input.elements.into_iter().map(|element| self.reconstruct_expression(element).0).collect(),
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_binary(&mut self, input: BinaryExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Binary(BinaryExpression {
+                left: Box::new(self.reconstruct_expression(*input.left).0),
+                right: Box::new(self.reconstruct_expression(*input.right).0),
+                op: input.op,
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Replaces a call to a generic function with a call to the instance for its const arguments.
+    fn reconstruct_call(&mut self, input: CallExpression) -> (Expression, Self::AdditionalOutput) {
+        let const_arguments =
+            input.const_arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect();
+        let function = match *input.function {
+            Expression::Identifier(identifier) if input.external.is_none() => {
+                let name = self.monomorphize_callee(identifier, const_arguments, input.span);
+                Expression::Identifier(Identifier { name, ..self.identifier(identifier) })
+            }
+            function => self.reconstruct_expression(function).0,
+        };
+        (
+            Expression::Call(CallExpression {
+                function: Box::new(function),
+                const_arguments: Vec::new(),
+                arguments: input
+                    .arguments
+                    .into_iter()
+                    .map(|argument| self.reconstruct_expression(argument).0)
+                    .collect(),
+                external: input.external,
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_cast(&mut self, input: CastExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Cast(CastExpression {
+                expression: Box::new(self.reconstruct_expression(*input.expression).0),
+                type_: self.reconstruct_type(input.type_),
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_struct_init(&mut self, input: StructExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Struct(StructExpression {
+                name: self.identifier(input.name),
+                members: input
+                    .members
+                    .into_iter()
+                    .map(|member| StructVariableInitializer {
+                        identifier: self.identifier(member.identifier),
+                        expression: match member.expression {
+                            Some(expression) => Some(self.reconstruct_expression(expression).0),
+                            // A member initialized by a const parameter of the same name is given its value.
+                            None if self.const_arguments.contains_key(&member.identifier.name) => {
+                                Some(self.reconstruct_identifier(member.identifier).0)
+                            }
+                            None => None,
+                        },
+                        span: member.span,
+                        id: self.id(member.id),
+                    })
+                    .collect(),
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    /// Replaces a const parameter with its value.
+    fn reconstruct_identifier(&mut self, input: Identifier) -> (Expression, Self::AdditionalOutput) {
+        match self.const_arguments.get(&input.name).cloned() {
+            Some(mut literal) => {
+                literal.set_span(input.span);
+                literal.set_id(self.node_builder.next_id());
+                (Expression::Literal(literal), Default::default())
+            }
+            None => (Expression::Identifier(self.identifier(input)), Default::default()),
+        }
+    }
+
+    fn reconstruct_literal(&mut self, mut input: Literal) -> (Expression, Self::AdditionalOutput) {
+        input.set_id(self.id(input.id()));
+        (Expression::Literal(input), Default::default())
+    }
+
+    fn reconstruct_ternary(&mut self, input: TernaryExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Ternary(TernaryExpression {
+                condition: Box::new(self.reconstruct_expression(*input.condition).0),
+                if_true: Box::new(self.reconstruct_expression(*input.if_true).0),
+                if_false: Box::new(self.reconstruct_expression(*input.if_false).0),
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_tuple(&mut self, input: TupleExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Tuple(TupleExpression {
+                elements: input.elements.into_iter().map(|element| self.reconstruct_expression(element).0).collect(),
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_unary(&mut self, input: UnaryExpression) -> (Expression, Self::AdditionalOutput) {
+        (
+            Expression::Unary(UnaryExpression {
+                receiver: Box::new(self.reconstruct_expression(*input.receiver).0),
+                op: input.op,
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_unit(&mut self, input: UnitExpression) -> (Expression, Self::AdditionalOutput) {
+        (Expression::Unit(UnitExpression { span: input.span, id: self.id(input.id) }), Default::default())
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Monomorphizer;
+
+use leo_ast::{
+    Annotation,
+    Expression,
+    External,
+    Finalize,
+    Function,
+    FunctionInput,
+    FunctionOutput,
+    Input,
+    Mapping,
+    Member,
+    Output,
+    ProgramReconstructor,
+    ProgramScope,
+    Statement,
+    StatementReconstructor,
+    Struct,
+};
+
+impl Monomorphizer<'_> {
+    fn reconstruct_input(&mut self, input: Input) -> Input {
+        match input {
+            Input::Internal(input) => Input::Internal(FunctionInput {
+                identifier: self.definition(input.identifier),
+                mode: input.mode,
+                type_: self.reconstruct_type(input.type_),
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Input::External(input) => Input::External(self.reconstruct_external(input)),
+        }
+    }
+
+    fn reconstruct_output(&mut self, output: Output) -> Output {
+        match output {
+            Output::Internal(output) => Output::Internal(FunctionOutput {
+                mode: output.mode,
+                type_: self.reconstruct_type(output.type_),
+                span: output.span,
+                id: self.id(output.id),
+            }),
+            Output::External(output) => Output::External(self.reconstruct_external(output)),
+        }
+    }
+
+    fn reconstruct_external(&mut self, external: External) -> External {
+        External {
+            identifier: self.identifier(external.identifier),
+            program_name: self.identifier(external.program_name),
+            record: self.identifier(external.record),
+            span: external.span,
+            id: self.id(external.id),
+        }
+    }
+}
+
+impl ProgramReconstructor for Monomorphizer<'_> {
+    /// Replaces the generic functions in the program scope with their instances.
+    fn reconstruct_program_scope(&mut self, input: ProgramScope) -> ProgramScope {
+        // Collect the values of the consts, which can be passed as const arguments.
+        self.consts = input
+            .consts
+            .iter()
+            .filter_map(|(name, declaration)| match &declaration.value {
+                Expression::Literal(literal) => Some((*name, literal.clone())),
+                _ => None,
+            })
+            .collect();
+        // Note that the generic functions are only reconstructed when they are instantiated.
+        self.generic_functions = input
+            .functions
+            .iter()
+            .filter(|(_, function)| function.is_generic())
+            .map(|(name, function)| (*name, function.clone()))
+            .collect();
+
+        let functions = input
+            .functions
+            .into_iter()
+            .filter(|(_, function)| !function.is_generic())
+            .map(|(name, function)| (name, self.reconstruct_function(function)))
+            .collect::<Vec<_>>();
+
+        // The generic functions are replaced by their instances.
+        // Note that this intentionally clears `self.instances` for the next program scope.
+        self.generic_functions.clear();
+        let functions = std::mem::take(&mut self.instances)
+            .into_iter()
+            .filter_map(|(name, instance)| instance.map(|instance| (name, instance)))
+            .chain(functions)
+            .collect();
+
+        ProgramScope {
+            program_id: input.program_id,
+            structs: input.structs.into_iter().map(|(i, c)| (i, self.reconstruct_struct(c))).collect(),
+            enums: input.enums,
+            mappings: input.mappings.into_iter().map(|(id, mapping)| (id, self.reconstruct_mapping(mapping))).collect(),
+            functions,
+            consts: input
+                .consts
+                .into_iter()
+                .map(|(i, c)| match self.reconstruct_const(c) {
+                    (Statement::Const(declaration), _) => (i, declaration),
+                    _ => unreachable!("`reconstruct_const` can only return `Statement::Const`"),
+                })
+                .collect(),
+            span: input.span,
+        }
+    }
+
+    fn reconstruct_function(&mut self, input: Function) -> Function {
+        let output = input.output.into_iter().map(|output| self.reconstruct_output(output)).collect();
+        Function {
+            annotations: input
+                .annotations
+                .into_iter()
+                .map(|annotation| Annotation {
+                    identifier: self.identifier(annotation.identifier),
+                    arguments: annotation.arguments.into_iter().map(|argument| self.identifier(argument)).collect(),
+                    span: annotation.span,
+                    id: self.id(annotation.id),
+                })
+                .collect(),
+            variant: input.variant,
+            identifier: self.identifier(input.identifier),
+            const_parameters: input.const_parameters,
+            input: input.input.into_iter().map(|input| self.reconstruct_input(input)).collect(),
+            output,
+            output_type: self.reconstruct_type(input.output_type),
+            block: self.reconstruct_block(input.block).0,
+            finalize: input.finalize.map(|finalize| Finalize {
+                identifier: self.identifier(finalize.identifier),
+                input: finalize.input.into_iter().map(|input| self.reconstruct_input(input)).collect(),
+                output: finalize.output.into_iter().map(|output| self.reconstruct_output(output)).collect(),
+                output_type: self.reconstruct_type(finalize.output_type),
+                block: self.reconstruct_block(finalize.block).0,
+                span: finalize.span,
+                id: self.id(finalize.id),
+            }),
+            span: input.span,
+            id: self.id(input.id),
+        }
+    }
+
+    /// Checks that the types of the members do not use const parameters.
+    fn reconstruct_struct(&mut self, input: Struct) -> Struct {
+        Struct {
+            members: input
+                .members
+                .into_iter()
+                .map(|member| Member { type_: self.reconstruct_type(member.type_), ..member })
+                .collect(),
+            ..input
+        }
+    }
+
+    /// Checks that the key and value types do not use const parameters.
+    fn reconstruct_mapping(&mut self, input: Mapping) -> Mapping {
+        Mapping {
+            key_type: self.reconstruct_type(input.key_type),
+            value_type: self.reconstruct_type(input.value_type),
+            ..input
+        }
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Monomorphizer;
+
+use leo_ast::{
+    AssertStatement,
+    AssertVariant,
+    AssignStatement,
+    Block,
+    ConditionalStatement,
+    ConsoleFunction,
+    ConsoleStatement,
+    ConstDeclaration,
+    DefinitionStatement,
+    Expression,
+    ExpressionReconstructor,
+    ExpressionStatement,
+    IterationRange,
+    IterationStatement,
+    ReturnStatement,
+    Statement,
+    StatementReconstructor,
+    TupleExpression,
+};
+
+impl Monomorphizer<'_> {
+    /// Reconstructs the place of a definition, checking that the variables it introduces do not shadow a const parameter.
+    fn reconstruct_place(&mut self, place: Expression) -> Expression {
+        match place {
+            Expression::Identifier(identifier) => Expression::Identifier(self.definition(identifier)),
+            Expression::Tuple(tuple) => Expression::Tuple(TupleExpression {
+                elements: tuple.elements.into_iter().map(|element| self.reconstruct_place(element)).collect(),
+                span: tuple.span,
+                id: self.id(tuple.id),
+            }),
+            place => self.reconstruct_expression(place).0,
+        }
+    }
+}
+
+impl StatementReconstructor for Monomorphizer<'_> {
+    fn reconstruct_assert(&mut self, input: AssertStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assert(AssertStatement {
+                variant: match input.variant {
+                    AssertVariant::Assert(expr) => AssertVariant::Assert(self.reconstruct_expression(expr).0),
+                    AssertVariant::AssertEq(left, right) => AssertVariant::AssertEq(
+                        self.reconstruct_expression(left).0,
+                        self.reconstruct_expression(right).0,
+                    ),
+                    AssertVariant::AssertNeq(left, right) => AssertVariant::AssertNeq(
+                        self.reconstruct_expression(left).0,
+                        self.reconstruct_expression(right).0,
+                    ),
+                },
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_assign(&mut self, input: AssignStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Assign(Box::new(AssignStatement {
+                place: self.reconstruct_expression(input.place).0,
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: self.id(input.id),
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_block(&mut self, input: Block) -> (Block, Self::AdditionalOutput) {
+        (
+            Block {
+                statements: input.statements.into_iter().map(|s| self.reconstruct_statement(s).0).collect(),
+                span: input.span,
+                id: self.id(input.id),
+            },
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_conditional(&mut self, input: ConditionalStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Conditional(ConditionalStatement {
+                condition: self.reconstruct_expression(input.condition).0,
+                then: self.reconstruct_block(input.then).0,
+                otherwise: input.otherwise.map(|n| Box::new(self.reconstruct_statement(*n).0)),
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_console(&mut self, input: ConsoleStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Console(ConsoleStatement {
+                function: match input.function {
+                    ConsoleFunction::Assert(expr) => ConsoleFunction::Assert(self.reconstruct_expression(expr).0),
+                    ConsoleFunction::AssertEq(left, right) => ConsoleFunction::AssertEq(
+                        self.reconstruct_expression(left).0,
+                        self.reconstruct_expression(right).0,
+                    ),
+                    ConsoleFunction::AssertNeq(left, right) => ConsoleFunction::AssertNeq(
+                        self.reconstruct_expression(left).0,
+                        self.reconstruct_expression(right).0,
+                    ),
+                },
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_const(&mut self, input: ConstDeclaration) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Const(ConstDeclaration {
+                place: self.definition(input.place),
+                type_: self.reconstruct_type(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_definition(&mut self, input: DefinitionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Definition(DefinitionStatement {
+                declaration_type: input.declaration_type,
+                place: self.reconstruct_place(input.place),
+                type_: self.reconstruct_type(input.type_),
+                value: self.reconstruct_expression(input.value).0,
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_expression_statement(&mut self, input: ExpressionStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Expression(ExpressionStatement {
+                expression: self.reconstruct_expression(input.expression).0,
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_iteration(&mut self, input: IterationStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Iteration(Box::new(IterationStatement {
+                variable: self.definition(input.variable),
+                type_: self.reconstruct_type(input.type_),
+                range: match input.range {
+                    IterationRange::Range { start, start_value, stop, stop_value, inclusive, step, step_value } => {
+                        IterationRange::Range {
+                            start: self.reconstruct_expression(start).0,
+                            start_value,
+                            stop: self.reconstruct_expression(stop).0,
+                            stop_value,
+                            inclusive,
+                            step: step.map(|step| self.reconstruct_expression(step).0),
+                            step_value,
+                        }
+                    }
+                    IterationRange::Array { array } => {
+                        IterationRange::Array { array: self.reconstruct_expression(array).0 }
+                    }
+                },
+                block: self.reconstruct_block(input.block).0,
+                span: input.span,
+                id: self.id(input.id),
+            })),
+            Default::default(),
+        )
+    }
+
+    fn reconstruct_return(&mut self, input: ReturnStatement) -> (Statement, Self::AdditionalOutput) {
+        (
+            Statement::Return(ReturnStatement {
+                expression: self.reconstruct_expression(input.expression).0,
+                finalize_arguments: input.finalize_arguments.map(|arguments| {
+                    arguments.into_iter().map(|argument| self.reconstruct_expression(argument).0).collect()
+                }),
+                span: input.span,
+                id: self.id(input.id),
+            }),
+            Default::default(),
+        )
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    ArrayLength,
+    ArrayType,
+    Expression,
+    Function,
+    Identifier,
+    Literal,
+    MappingType,
+    NodeBuilder,
+    NodeID,
+    NonNegativeNumber,
+    TupleType,
+    Type,
+};
+use leo_errors::{emitter::Handler, AstError, TypeCheckerError};
+use leo_span::Symbol;
+
+use indexmap::IndexMap;
+use snarkvm::console::network::{Network, Testnet3};
+
+pub struct Monomorphizer<'a> {
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// A counter used to create unique NodeIDs.
+    pub(crate) node_builder: &'a NodeBuilder,
+    /// The values of the consts declared in the current program scope.
+    pub(crate) consts: IndexMap<Symbol, Literal>,
+    /// The generic functions in the current program scope.
+    pub(crate) generic_functions: IndexMap<Symbol, Function>,
+    /// The instances of the generic functions, keyed by their names, e.g. `sum::[4u32]`.
+    /// Note that an instance is `None` while its body is being monomorphized.
+    pub(crate) instances: IndexMap<Symbol, Option<Function>>,
+    /// The const arguments of the instance being monomorphized, keyed by the names of the const parameters.
+    pub(crate) const_arguments: IndexMap<Symbol, Literal>,
+}
+
+impl<'a> Monomorphizer<'a> {
+    /// Initializes a new `Monomorphizer`.
+    pub fn new(handler: &'a Handler, node_builder: &'a NodeBuilder) -> Self {
+        Self {
+            handler,
+            node_builder,
+            consts: Default::default(),
+            generic_functions: Default::default(),
+            instances: Default::default(),
+            const_arguments: Default::default(),
+        }
+    }
+
+    /// Returns `true` if the body of an instance is being monomorphized.
+    fn is_instantiating(&self) -> bool {
+        !self.const_arguments.is_empty()
+    }
+
+    /// Returns the ID of a node.
+    /// Each instance is a copy of the generic function, so its nodes are given new IDs.
+    pub(crate) fn id(&self, id: NodeID) -> NodeID {
+        match self.is_instantiating() {
+            true => self.node_builder.next_id(),
+            false => id,
+        }
+    }
+
+    /// Returns the identifier, with a new ID if an instance is being monomorphized.
+    pub(crate) fn identifier(&self, identifier: Identifier) -> Identifier {
+        Identifier { id: self.id(identifier.id), ..identifier }
+    }
+
+    /// Returns the identifier introduced by a definition, checking that it does not shadow a const parameter.
+    pub(crate) fn definition(&self, identifier: Identifier) -> Identifier {
+        if self.const_arguments.contains_key(&identifier.name) {
+            self.handler.emit_err(AstError::shadowed_variable(identifier.name, identifier.span));
+        }
+        self.identifier(identifier)
+    }
+
+    /// Returns the value of a const parameter, a const, or a literal.
+    /// Note that const parameters have already been replaced by their values when the argument is reconstructed.
+    pub(crate) fn const_argument(&self, argument: &Expression) -> Option<Literal> {
+        match argument {
+            Expression::Literal(literal) => Some(literal.clone()),
+            Expression::Identifier(identifier) => self.consts.get(&identifier.name).cloned(),
+            _ => None,
+        }
+    }
+
+    /// Replaces the const parameters in the lengths of the array types with their values.
+    pub(crate) fn reconstruct_type(&self, type_: Type) -> Type {
+        match type_ {
+            Type::Array(array_type) => {
+                let element_type = self.reconstruct_type(array_type.element_type().clone());
+                match array_type.declared_length() {
+                    ArrayLength::Fixed(length) => Type::Array(ArrayType::new(element_type, length.clone())),
+                    ArrayLength::Const(parameter) => match self.const_arguments.get(&parameter.name) {
+                        Some(Literal::Integer(_, value, ..)) => match value.replace('_', "").parse::<usize>() {
+                            Ok(length) => Type::Array(ArrayType::new(element_type, NonNegativeNumber::from(length))),
+                            Err(_) => {
+                                self.handler.emit_err(TypeCheckerError::array_too_large(
+                                    value,
+                                    Testnet3::MAX_ARRAY_ELEMENTS,
+                                    parameter.span,
+                                ));
+                                Type::Err
+                            }
+                        },
+                        _ => {
+                            self.handler.emit_err(TypeCheckerError::unknown_const_parameter(parameter, parameter.span));
+                            Type::Err
+                        }
+                    },
+                }
+            }
+            Type::Identifier(identifier) => Type::Identifier(self.identifier(identifier)),
+            Type::Mapping(mapping_type) => Type::Mapping(MappingType {
+                key: Box::new(self.reconstruct_type(*mapping_type.key)),
+                value: Box::new(self.reconstruct_type(*mapping_type.value)),
+            }),
+            Type::Tuple(tuple_type) => Type::Tuple(TupleType::new(
+                tuple_type.elements().iter().map(|type_| self.reconstruct_type(type_.clone())).collect(),
+            )),
+            type_ => type_,
+        }
+    }
+
+    /// Returns the type of a literal.
+    pub(crate) fn literal_type(literal: &Literal) -> Type {
+        match literal {
+            Literal::Address(..) => Type::Address,
+            Literal::Boolean(..) => Type::Boolean,
+            Literal::Field(..) => Type::Field,
+            Literal::Group(..) => Type::Group,
+            Literal::Integer(type_, ..) => Type::Integer(*type_),
+            Literal::Scalar(..) => Type::Scalar,
+            Literal::String(..) => Type::String,
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::PrivacyAnalyzer;
+
+use leo_ast::*;
+use leo_errors::StaticAnalyzerWarning;
+
+impl<'a> ExpressionVisitor<'a> for PrivacyAnalyzer<'a> {
+    type AdditionalInput = ();
+    /// Whether or not the expression is derived from private data.
+    type Output = bool;
+
+    fn visit_access(&mut self, input: &'a AccessExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(access) => {
+                let array = self.visit_expression(&access.array, &());
+                let index = self.visit_expression(&access.index, &());
+                array || index
+            }
+            AccessExpression::AssociatedConstant(_) => false,
+            AccessExpression::AssociatedFunction(access) => {
+                let arguments =
+                    access.arguments.iter().map(|argument| self.visit_expression(argument, &())).collect::<Vec<_>>();
+
+                let core_function = match &access.ty {
+                    Type::Identifier(identifier) => CoreFunction::from_symbols(identifier.name, access.name.name),
+                    _ => None,
+                };
+                match core_function {
+                    Some(
+                        core_function @ (CoreFunction::MappingGet
+                        | CoreFunction::MappingGetOrUse
+                        | CoreFunction::MappingSet
+                        | CoreFunction::MappingRemove
+                        | CoreFunction::MappingContains),
+                    ) => {
+                        // Writes made under a private condition reveal the condition.
+                        let is_write = matches!(core_function, CoreFunction::MappingSet | CoreFunction::MappingRemove);
+                        if arguments.iter().skip(1).any(|tainted| *tainted) || (is_write && self.is_guarded) {
+                            self.emit_warning(StaticAnalyzerWarning::private_value_in_mapping(access, access.span));
+                        }
+                        // The contents of a mapping are public.
+                        false
+                    }
+                    _ => arguments.into_iter().any(|tainted| tainted),
+                }
+            }
+            AccessExpression::Member(access) => {
+                let inner = self.visit_expression(&access.inner, &());
+                inner && !self.is_public_record_field(&access.inner, access.name.name)
+            }
+            AccessExpression::Tuple(access) => self.visit_expression(&access.tuple, &()),
+        }
+    }
+
+    fn visit_array(&mut self, input: &'a ArrayExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        input
+            .elements
+            .iter()
+            .map(|element| self.visit_expression(element, &()))
+            .fold(false, |acc, tainted| acc || tainted)
+    }
+
+    fn visit_binary(&mut self, input: &'a BinaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        let left = self.visit_expression(&input.left, &());
+        let right = self.visit_expression(&input.right, &());
+        left || right
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        // Conservatively assume that the result of a call depends on all of its arguments.
+        input
+            .arguments
+            .iter()
+            .map(|argument| self.visit_expression(argument, &()))
+            .fold(false, |acc, tainted| acc || tainted)
+    }
+
+    fn visit_cast(&mut self, input: &'a CastExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.expression, &())
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        input
+            .members
+            .iter()
+            .map(|member| match &member.expression {
+                Some(expression) => self.visit_expression(expression, &()),
+                None => self.tainted.contains(&member.identifier.name),
+            })
+            .fold(false, |acc, tainted| acc || tainted)
+    }
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.tainted.contains(&input.name)
+    }
+
+    fn visit_literal(&mut self, _input: &'a Literal, _additional: &Self::AdditionalInput) -> Self::Output {
+        false
+    }
+
+    fn visit_ternary(&mut self, input: &'a TernaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        let condition = self.visit_expression(&input.condition, &());
+        let if_true = self.visit_expression(&input.if_true, &());
+        let if_false = self.visit_expression(&input.if_false, &());
+        condition || if_true || if_false
+    }
+
+    fn visit_tuple(&mut self, input: &'a TupleExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        input
+            .elements
+            .iter()
+            .map(|element| self.visit_expression(element, &()))
+            .fold(false, |acc, tainted| acc || tainted)
+    }
+
+    fn visit_unary(&mut self, input: &'a UnaryExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.visit_expression(&input.receiver, &())
+    }
+
+    fn visit_unit(&mut self, _input: &'a UnitExpression, _additional: &Self::AdditionalInput) -> Self::Output {
+        false
+    }
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::PrivacyAnalyzer;
+
+use leo_ast::*;
+
+impl<'a> ProgramVisitor<'a> for PrivacyAnalyzer<'a> {
+    fn visit_program(&mut self, input: &'a Program) {
+        // Imported programs and stubs are analyzed when they are built.
+        input.program_scopes.values().for_each(|scope| self.visit_program_scope(scope));
+    }
+
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        // Inline functions are accounted for at their call sites.
+        if input.variant == Variant::Inline {
+            return;
+        }
+
+        self.function = Some(input.name());
+        self.output_modes = input.output.iter().map(|output| output.mode()).collect();
+        self.finalize_arguments = Vec::new();
+
+        // Inputs without a mode are private.
+        let sources = input
+            .input
+            .iter()
+            .filter(|input| matches!(input.mode(), Mode::None | Mode::Private))
+            .map(|input| input.identifier().name)
+            .collect();
+        self.analyze_block(&input.block, sources);
+
+        if let Some(finalize) = &input.finalize {
+            self.is_finalize = true;
+
+            // The inputs of the finalize block are private if any argument passed to them is.
+            let sources = finalize
+                .input
+                .iter()
+                .zip(self.finalize_arguments.clone())
+                .filter(|(_, tainted)| *tainted)
+                .map(|(input, _)| input.identifier().name)
+                .collect();
+            self.analyze_block(&finalize.block, sources);
+
+            self.is_finalize = false;
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::PrivacyAnalyzer;
+
+use leo_ast::*;
+use leo_errors::StaticAnalyzerWarning;
+
+impl<'a> StatementVisitor<'a> for PrivacyAnalyzer<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        // Note that visiting the place also accounts for private array indices.
+        let place = self.visit_expression(&input.place, &());
+        let value = self.visit_expression(&input.value, &());
+        if place || value || self.is_guarded {
+            self.taint_place(&input.place);
+        }
+    }
+
+    fn visit_conditional(&mut self, input: &'a ConditionalStatement) {
+        let condition = self.visit_expression(&input.condition, &());
+
+        // Every assignment made under a private condition depends on that condition.
+        let is_guarded = self.is_guarded;
+        self.is_guarded |= condition;
+
+        self.visit_block(&input.then);
+        if let Some(otherwise) = input.otherwise.as_ref() {
+            self.visit_statement(otherwise);
+        }
+
+        self.is_guarded = is_guarded;
+    }
+
+    fn visit_const(&mut self, _input: &'a ConstDeclaration) {}
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        if self.visit_expression(&input.value, &()) || self.is_guarded {
+            self.taint_place(&input.place);
+        }
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        let is_guarded = self.is_guarded;
+        match &input.range {
+            IterationRange::Range { start, stop, step, .. } => {
+                let start = self.visit_expression(start, &());
+                let stop = self.visit_expression(stop, &());
+                let step = step.as_ref().is_some_and(|step| self.visit_expression(step, &()));
+                self.is_guarded |= start || stop || step;
+            }
+            // The loop variable is bound to each element of the array in turn.
+            IterationRange::Array { array } => {
+                if self.visit_expression(array, &()) {
+                    self.tainted.insert(input.variable.name);
+                }
+            }
+        }
+
+        self.visit_block(&input.block);
+
+        self.is_guarded = is_guarded;
+    }
+
+    fn visit_return(&mut self, input: &'a ReturnStatement) {
+        // The outputs of a finalize block are not observable.
+        if self.is_finalize {
+            self.visit_expression(&input.expression, &());
+            return;
+        }
+
+        let function = self.function.unwrap();
+
+        // Check each public output, matching the elements of a tuple to the outputs of the function when possible.
+        match &input.expression {
+            Expression::Tuple(tuple) if tuple.elements.len() == self.output_modes.len() => {
+                tuple.elements.iter().zip(self.output_modes.clone()).for_each(|(element, mode)| {
+                    if (self.visit_expression(element, &()) || self.is_guarded) && mode == Mode::Public {
+                        self.emit_warning(StaticAnalyzerWarning::private_value_in_public_output(
+                            function,
+                            element.span(),
+                        ));
+                    }
+                });
+            }
+            expression => {
+                if (self.visit_expression(expression, &()) || self.is_guarded)
+                    && self.output_modes.iter().any(|mode| *mode == Mode::Public)
+                {
+                    self.emit_warning(StaticAnalyzerWarning::private_value_in_public_output(
+                        function,
+                        expression.span(),
+                    ));
+                }
+            }
+        }
+
+        // Check the arguments to the finalize block.
+        if let Some(arguments) = &input.finalize_arguments {
+            if self.finalize_arguments.len() < arguments.len() {
+                self.finalize_arguments.resize(arguments.len(), false);
+            }
+            arguments.iter().enumerate().for_each(|(i, argument)| {
+                if self.visit_expression(argument, &()) || self.is_guarded {
+                    self.finalize_arguments[i] = true;
+                    self.emit_warning(StaticAnalyzerWarning::private_value_in_finalize_argument(
+                        function,
+                        argument.span(),
+                    ));
+                }
+            });
+        }
+    }
+}
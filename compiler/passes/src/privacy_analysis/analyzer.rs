@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use crate::{SymbolTable, TypeTable};
+
+use leo_ast::{AccessExpression, Block, Expression, Identifier, Mode, Node, StatementVisitor, Type};
+use leo_errors::{emitter::Handler, StaticAnalyzerWarning};
+use leo_span::Symbol;
+
+use indexmap::IndexSet;
+
+/// Tracks the flow of private data through a program and warns when it reaches a public sink.
+///
+/// A value is tainted if it is derived from a private (or default-mode) transition input or from a
+/// non-public record field. Taint propagates through every expression that reads a tainted value and,
+/// implicitly, through every assignment made under a tainted condition. Taint is never removed, so
+/// each function body is re-analyzed until the set of tainted variables stops growing.
+pub struct PrivacyAnalyzer<'a> {
+    /// The symbol table for the program.
+    pub(crate) symbol_table: &'a SymbolTable,
+    /// A mapping from node IDs to their types.
+    pub(crate) type_table: &'a TypeTable,
+    /// The error handler.
+    pub(crate) handler: &'a Handler,
+    /// The variables of the current function that hold private data.
+    pub(crate) tainted: IndexSet<Symbol>,
+    /// Whether or not the current statement is guarded by a condition that depends on private data.
+    pub(crate) is_guarded: bool,
+    /// The name of the function that we are currently traversing.
+    pub(crate) function: Option<Symbol>,
+    /// The modes of the outputs of the function that we are currently traversing.
+    pub(crate) output_modes: Vec<Mode>,
+    /// For each finalize argument, whether or not it is derived from private data.
+    pub(crate) finalize_arguments: Vec<bool>,
+    /// Whether or not we are currently traversing a finalize block.
+    pub(crate) is_finalize: bool,
+    /// Whether or not warnings are emitted. Warnings are only emitted once the analysis has reached a fixpoint.
+    pub(crate) emit_warnings: bool,
+}
+
+impl<'a> PrivacyAnalyzer<'a> {
+    /// Returns a new privacy analyzer given a symbol table, type table, and error handler.
+    pub fn new(symbol_table: &'a SymbolTable, type_table: &'a TypeTable, handler: &'a Handler) -> Self {
+        Self {
+            symbol_table,
+            type_table,
+            handler,
+            tainted: IndexSet::new(),
+            is_guarded: false,
+            function: None,
+            output_modes: Vec::new(),
+            finalize_arguments: Vec::new(),
+            is_finalize: false,
+            emit_warnings: false,
+        }
+    }
+
+    /// Emits a warning, if warnings are enabled.
+    pub(crate) fn emit_warning(&self, warning: StaticAnalyzerWarning) {
+        if self.emit_warnings {
+            self.handler.emit_warning(warning.into());
+        }
+    }
+
+    /// Analyzes \`block\` until the variables holding private data reach a fixpoint, then emits warnings.
+    pub(crate) fn analyze_block(&mut self, block: &'a Block, sources: IndexSet<Symbol>) {
+        self.tainted = sources;
+        self.is_guarded = false;
+
+        loop {
+            let num_tainted = self.tainted.len();
+            self.visit_block(block);
+            if self.tainted.len() == num_tainted {
+                break;
+            }
+        }
+
+        self.emit_warnings = true;
+        self.visit_block(block);
+        self.emit_warnings = false;
+    }
+
+    /// Marks the variable at the root of `place` as holding private data.
+    pub(crate) fn taint_place(&mut self, place: &Expression) {
+        match place {
+            Expression::Identifier(Identifier { name, .. }) => {
+                self.tainted.insert(*name);
+            }
+            Expression::Access(AccessExpression::Array(access)) => self.taint_place(&access.array),
+            Expression::Access(AccessExpression::Member(access)) => self.taint_place(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => self.taint_place(&access.tuple),
+            Expression::Tuple(tuple) => tuple.elements.iter().for_each(|element| self.taint_place(element)),
+            _ => {}
+        }
+    }
+
+    /// Returns whether or not the member `name` of `inner` is a public record field.
+    pub(crate) fn is_public_record_field(&self, inner: &Expression, name: Symbol) -> bool {
+        match self.type_table.get(&inner.id()) {
+            Some(Type::Identifier(identifier)) => match self.symbol_table.lookup_struct(identifier.name) {
+                Some(struct_) if struct_.is_record => {
+                    struct_.members.iter().any(|member| member.name() == name && member.mode == Mode::Public)
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
@@ -29,6 +29,7 @@ use leo_ast::{
     Identifier,
     Literal,
     MemberAccess,
+    Node,
     Statement,
     Struct,
     StructExpression,
@@ -36,6 +37,7 @@ use leo_ast::{
     TernaryExpression,
     TupleAccess,
     TupleExpression,
+    Type,
     UnaryExpression,
     UnitExpression,
 };
@@ -103,7 +105,24 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
                 )
             }
             AccessExpression::Array(input) => {
-                let (array, statements) = self.consume_expression(*input.array);
+                let (array, mut statements) = self.consume_expression(*input.array);
+
+                // If the index is only known at runtime, lower the access into a bounds-checked selection of an element.
+                if !matches!(*input.index, Expression::Literal(_)) {
+                    let length = match self.type_table.get(&array.id()) {
+                        Some(Type::Array(array_type)) => array_type.length(),
+                        _ => unreachable!(
+                            "Type checking guarantees that the inner expression of an array access is an array."
+                        ),
+                    };
+                    let (array, array_statements) = self.bind_array(array);
+                    statements.extend(array_statements);
+                    let (index, index_statements) = self.consume_variable_index(*input.index, length);
+                    statements.extend(index_statements);
+                    let (element, element_statements) = self.consume_expression(self.select_element(&array, &index));
+                    statements.extend(element_statements);
+                    return (element, statements);
+                }
 
                 (
                     AccessExpression::Array(ArrayAccess {
@@ -190,6 +209,7 @@ impl ExpressionConsumer for StaticSingleAssigner<'_> {
         let (place, statement) = self.unique_simple_assign_statement(Expression::Call(CallExpression {
             // Note that we do not rename the function name.
             function: input.function,
+            const_arguments: input.const_arguments,
             // Consume the arguments.
             arguments,
             external: input.external,
@@ -33,6 +33,7 @@ use leo_ast::{
     ExpressionStatement,
     Identifier,
     IterationStatement,
+    MatchStatement,
     Node,
     ReturnStatement,
     Statement,
@@ -86,15 +87,19 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
 
     /// Consume all `AssignStatement`s, renaming as necessary.
     fn consume_assign(&mut self, assign: AssignStatement) -> Self::Output {
+        // Lower assignments to members, tuple elements, and array elements into assignments to the entire variable.
+        let (place, value, mut statements) = self.lower_assignment(assign.place, assign.value);
+
         // First consume the right-hand-side of the assignment.
-        let (value, mut statements) = self.consume_expression(assign.value);
+        let (value, value_statements) = self.consume_expression(value);
+        statements.extend(value_statements);
 
         // Then assign a new unique name to the left-hand-side of the assignment.
         // Note that this order is necessary to ensure that the right-hand-side uses the correct name when consuming a complex assignment.
         self.is_lhs = true;
-        let place = match self.consume_expression(assign.place).0 {
+        let place = match self.consume_expression(place).0 {
             Expression::Identifier(identifier) => identifier,
-            _ => panic!("Type checking guarantees that the left-hand-side of an assignment is an identifier."),
+            _ => panic!("The left-hand-side of an assignment is lowered to an identifier."),
         };
         self.is_lhs = false;
 
@@ -343,6 +348,7 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
                 statements.push(Statement::Expression(ExpressionStatement {
                     expression: Expression::Call(CallExpression {
                         function: call.function,
+                        const_arguments: call.const_arguments,
                         arguments,
                         external: call.external,
                         span: call.span,
@@ -381,6 +387,10 @@ impl StatementConsumer for StaticSingleAssigner<'_> {
         unreachable!("`IterationStatement`s should not be in the AST at this phase of compilation.");
     }
 
+    fn consume_match(&mut self, _input: MatchStatement) -> Self::Output {
+        unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.");
+    }
+
     /// Reconstructs the expression associated with the return statement, returning a simplified `ReturnStatement`.
     /// Note that type checking guarantees that there is at most one `ReturnStatement` in a block.
     fn consume_return(&mut self, input: ReturnStatement) -> Self::Output {
@@ -16,7 +16,32 @@
 
 use crate::{Assigner, RenameTable, SymbolTable, TypeTable};
 
-use leo_ast::{Expression, Identifier, Node, NodeBuilder, Statement};
+use leo_ast::{
+    AccessExpression,
+    ArrayAccess,
+    ArrayExpression,
+    AssertStatement,
+    AssertVariant,
+    BinaryExpression,
+    BinaryOperation,
+    CastExpression,
+    Expression,
+    ExpressionConsumer,
+    Identifier,
+    IntegerType,
+    Literal,
+    MemberAccess,
+    Node,
+    NodeBuilder,
+    Statement,
+    StatementConsumer,
+    StructExpression,
+    StructVariableInitializer,
+    TernaryExpression,
+    TupleAccess,
+    TupleExpression,
+    Type,
+};
 
 pub struct StaticSingleAssigner<'a> {
     /// A counter used to generate unique node IDs.
@@ -84,4 +109,288 @@ impl<'a> StaticSingleAssigner<'a> {
 
         (place, statement)
     }
+
+    /// Lowers an assignment to a member, tuple element, or array element of a variable into an assignment to the variable itself.
+    /// For example,
+    ///   - `s.a = v` becomes `s = S { a: v, b: s.b }`.
+    ///   - `t.0 = v` becomes `t = (v, t.1)`.
+    ///   - `a[1u32] = v` becomes `a = [a[0u32], v, a[2u32]]`.
+    ///
+    ///   - `a[i] = v` becomes `a = [i == 0u32 ? v : a[0u32], i == 1u32 ? v : a[1u32], i == 2u32 ? v : a[2u32]]`.
+    ///
+    /// Nested places are lowered from the outermost access inwards.
+    /// Also returns the statements produced when checking variable indices.
+    pub(crate) fn lower_assignment(
+        &mut self,
+        place: Expression,
+        value: Expression,
+    ) -> (Expression, Expression, Vec<Statement>) {
+        let mut statements = Vec::new();
+        match place {
+            Expression::Access(AccessExpression::Member(access)) => {
+                // Type checking guarantees that the inner expression is a struct.
+                let struct_name = match self.type_table.get(&access.inner.id()) {
+                    Some(Type::Identifier(identifier)) => identifier,
+                    _ => unreachable!(
+                        "Type checking guarantees that the inner expression of a member access is a struct."
+                    ),
+                };
+                let struct_definition = self.symbol_table.lookup_struct(struct_name.name).unwrap().clone();
+
+                // Construct the struct, replacing the assigned member with `value`.
+                let mut value = Some(value);
+                let members = struct_definition
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let expression = match member.identifier.name == access.name.name {
+                            true => value.take().unwrap(),
+                            false => {
+                                let id = self.node_builder.next_id();
+                                self.type_table.insert(id, member.type_.clone());
+                                Expression::Access(AccessExpression::Member(MemberAccess {
+                                    inner: access.inner.clone(),
+                                    name: Identifier { id: self.node_builder.next_id(), ..member.identifier },
+                                    span: Default::default(),
+                                    id,
+                                }))
+                            }
+                        };
+                        StructVariableInitializer {
+                            identifier: Identifier { id: self.node_builder.next_id(), ..member.identifier },
+                            expression: Some(expression),
+                            span: Default::default(),
+                            id: self.node_builder.next_id(),
+                        }
+                    })
+                    .collect();
+                let struct_expression = StructExpression {
+                    name: struct_name,
+                    members,
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                };
+                self.type_table.insert(struct_expression.id, Type::Identifier(struct_name));
+
+                self.lower_assignment(*access.inner, Expression::Struct(struct_expression))
+            }
+            Expression::Access(AccessExpression::Tuple(access)) => {
+                // Type checking guarantees that the inner expression is a tuple.
+                let tuple_type = match self.type_table.get(&access.tuple.id()) {
+                    Some(Type::Tuple(tuple_type)) => tuple_type,
+                    _ => {
+                        unreachable!("Type checking guarantees that the inner expression of a tuple access is a tuple.")
+                    }
+                };
+
+                // Construct the tuple, replacing the assigned element with `value`.
+                let mut value = Some(value);
+                let elements = tuple_type
+                    .elements()
+                    .iter()
+                    .enumerate()
+                    .map(|(index, type_)| match index == access.index.value() {
+                        true => value.take().unwrap(),
+                        false => {
+                            let id = self.node_builder.next_id();
+                            self.type_table.insert(id, type_.clone());
+                            Expression::Access(AccessExpression::Tuple(TupleAccess {
+                                tuple: access.tuple.clone(),
+                                index: index.into(),
+                                span: Default::default(),
+                                id,
+                            }))
+                        }
+                    })
+                    .collect();
+                let tuple_expression =
+                    TupleExpression { elements, span: Default::default(), id: self.node_builder.next_id() };
+                self.type_table.insert(tuple_expression.id, Type::Tuple(tuple_type));
+
+                self.lower_assignment(*access.tuple, Expression::Tuple(tuple_expression))
+            }
+            Expression::Access(AccessExpression::Array(access)) => {
+                // Type checking guarantees that the inner expression is an array.
+                let array_type = match self.type_table.get(&access.array.id()) {
+                    Some(Type::Array(array_type)) => array_type,
+                    _ => unreachable!(
+                        "Type checking guarantees that the inner expression of an array access is an array."
+                    ),
+                };
+
+                // Bind the current value of the array to a variable once, so that it is not duplicated for each element.
+                let (array, mut array_statements) = self.consume_expression(*access.array.clone());
+                statements.append(&mut array_statements);
+                let (array, mut array_statements) = self.bind_array(array);
+                statements.append(&mut array_statements);
+
+                let elements = match *access.index {
+                    // Loop unrolling guarantees that a literal index is within the bounds of the array.
+                    Expression::Literal(Literal::Integer(_, index, ..)) => {
+                        // Construct the array, replacing the assigned element with `value`.
+                        let assigned_index = index.replace('_', "").parse::<usize>().unwrap();
+                        let mut value = Some(value);
+                        (0..array_type.length())
+                            .map(|index| match index == assigned_index {
+                                true => value.take().unwrap(),
+                                false => self.array_element(&array, index, array_type.element_type()),
+                            })
+                            .collect()
+                    }
+                    // Otherwise, the index is only known at runtime.
+                    index => {
+                        // Check the index and consume the value, so that neither is duplicated below.
+                        let (index, index_statements) = self.consume_variable_index(index, array_type.length());
+                        statements.extend(index_statements);
+                        let (value, value_statements) = self.consume_expression(value);
+                        statements.extend(value_statements);
+
+                        // Construct the array, selecting `value` for the element at the index.
+                        (0..array_type.length())
+                            .map(|position| {
+                                let element = self.array_element(&array, position, array_type.element_type());
+                                self.select(
+                                    self.index_equals(&index, position),
+                                    value.clone(),
+                                    element,
+                                    array_type.element_type(),
+                                )
+                            })
+                            .collect()
+                    }
+                };
+                let array_expression =
+                    ArrayExpression { elements, span: Default::default(), id: self.node_builder.next_id() };
+                self.type_table.insert(array_expression.id, Type::Array(array_type));
+
+                let (place, value, mut place_statements) =
+                    self.lower_assignment(*access.array, Expression::Array(array_expression));
+                statements.append(&mut place_statements);
+                (place, value, statements)
+            }
+            place => (place, value, statements),
+        }
+    }
+
+    /// Consumes an array index that is only known at runtime, asserting that it is within the bounds of an array of length `length`.
+    /// The index is cast to a `u32`, which also rejects negative indices.
+    pub(crate) fn consume_variable_index(&mut self, index: Expression, length: usize) -> (Expression, Vec<Statement>) {
+        let index = match self.type_table.get(&index.id()) {
+            Some(Type::Integer(IntegerType::U32)) => index,
+            _ => {
+                let cast = CastExpression {
+                    expression: Box::new(index),
+                    type_: Type::Integer(IntegerType::U32),
+                    span: Default::default(),
+                    id: self.node_builder.next_id(),
+                };
+                self.type_table.insert(cast.id, Type::Integer(IntegerType::U32));
+                Expression::Cast(cast)
+            }
+        };
+        let (index, mut statements) = self.consume_expression(index);
+
+        // Construct and consume `assert(index < length)`.
+        let condition = BinaryExpression {
+            left: Box::new(index.clone()),
+            right: Box::new(self.u32_literal(length)),
+            op: BinaryOperation::Lt,
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        };
+        self.type_table.insert(condition.id, Type::Boolean);
+        statements.extend(self.consume_statement(Statement::Assert(AssertStatement {
+            variant: AssertVariant::Assert(Expression::Binary(condition)),
+            span: Default::default(),
+            id: self.node_builder.next_id(),
+        })));
+
+        (index, statements)
+    }
+
+    /// Assigns a consumed array to a new variable, unless it already is a variable.
+    /// This allows the elements of the array to be accessed without duplicating the expression.
+    pub(crate) fn bind_array(&mut self, array: Expression) -> (Identifier, Vec<Statement>) {
+        match array {
+            Expression::Identifier(identifier) => (identifier, Vec::new()),
+            array => {
+                let (place, statement) = self.unique_simple_assign_statement(array);
+                (place, vec![statement])
+            }
+        }
+    }
+
+    /// Lowers an access of an array at an index that is only known at runtime into a chain of ternary expressions.
+    /// For example, `a[i]` on an array of length 3 becomes `i == 0u32 ? a[0u32] : i == 1u32 ? a[1u32] : a[2u32]`.
+    /// The array must be bound to a variable, and `index` must already be consumed.
+    pub(crate) fn select_element(&self, array: &Identifier, index: &Expression) -> Expression {
+        // Type checking guarantees that the expression is an array.
+        let array_type = match self.type_table.get(&array.id) {
+            Some(Type::Array(array_type)) => array_type,
+            _ => unreachable!("Type checking guarantees that the inner expression of an array access is an array."),
+        };
+        let element_type = array_type.element_type();
+
+        // The last element is selected if no other element is, since the index has been checked.
+        let last = array_type.length() - 1;
+        (0..last).rev().fold(self.array_element(array, last, element_type), |otherwise, position| {
+            self.select(
+                self.index_equals(index, position),
+                self.array_element(array, position, element_type),
+                otherwise,
+                element_type,
+            )
+        })
+    }
+
+    /// Constructs `array[position]`, where `position` is a `u32` literal.
+    /// Each access gets its own copy of the array variable, with a new ID.
+    fn array_element(&self, array: &Identifier, position: usize, element_type: &Type) -> Expression {
+        let variable = Identifier { id: self.node_builder.next_id(), ..*array };
+        if let Some(array_type) = self.type_table.get(&array.id) {
+            self.type_table.insert(variable.id, array_type);
+        }
+        let index = self.u32_literal(position);
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, element_type.clone());
+        Expression::Access(AccessExpression::Array(ArrayAccess {
+            array: Box::new(Expression::Identifier(variable)),
+            index: Box::new(index),
+            span: Default::default(),
+            id,
+        }))
+    }
+
+    /// Constructs `index == position`, where `position` is a `u32` literal.
+    fn index_equals(&self, index: &Expression, position: usize) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Boolean);
+        Expression::Binary(BinaryExpression {
+            left: Box::new(index.clone()),
+            right: Box::new(self.u32_literal(position)),
+            op: BinaryOperation::Eq,
+            span: Default::default(),
+            id,
+        })
+    }
+
+    /// Constructs `condition ? if_true : if_false`.
+    fn select(&self, condition: Expression, if_true: Expression, if_false: Expression, type_: &Type) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, type_.clone());
+        Expression::Ternary(TernaryExpression {
+            condition: Box::new(condition),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false),
+            span: Default::default(),
+            id,
+        })
+    }
+
+    /// Constructs a `u32` literal.
+    fn u32_literal(&self, value: usize) -> Expression {
+        let id = self.node_builder.next_id();
+        self.type_table.insert(id, Type::Integer(IntegerType::U32));
+        Expression::Literal(Literal::Integer(IntegerType::U32, value.to_string(), Default::default(), id))
+    }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{DiGraphError, TypeChecker};
+use crate::{DiGraphError, Lint, TypeChecker};
 
 use leo_ast::*;
 use leo_errors::TypeCheckerError;
@@ -255,10 +255,36 @@ impl<'a> ProgramVisitor<'a> for TypeChecker<'a> {
 
     fn visit_function(&mut self, function: &'a Function) {
         // Check that the function's annotations are valid.
-        // Note that Leo does not natively support any specific annotations.
+        // Note that Leo only supports the `@test` and `@should_fail` annotations, which mark test transitions,
+        // and the `@allow` annotation, which allows lints.
         for annotation in function.annotations.iter() {
-            // TODO: Change to compiler warning.
-            self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span))
+            match annotation.identifier.name {
+                sym::allow => {
+                    if annotation.arguments.is_empty() {
+                        self.emit_err(TypeCheckerError::allow_requires_lints(annotation.span));
+                    }
+                    for argument in annotation.arguments.iter() {
+                        if Lint::from_name(&argument.name.to_string()).is_none() {
+                            self.emit_err(TypeCheckerError::unknown_lint(argument, Lint::names(), argument.span));
+                        }
+                    }
+                }
+                sym::test => {
+                    if function.variant != Variant::Transition {
+                        self.emit_err(TypeCheckerError::test_function_must_be_transition(annotation.span));
+                    }
+                    if !function.input.is_empty() {
+                        self.emit_err(TypeCheckerError::test_function_cannot_have_inputs(annotation.span));
+                    }
+                }
+                sym::should_fail => {
+                    if !function.is_test() {
+                        self.emit_err(TypeCheckerError::should_fail_requires_test(annotation.span));
+                    }
+                }
+                // TODO: Change to compiler warning.
+                _ => self.emit_err(TypeCheckerError::unknown_annotation(annotation, annotation.span)),
+            }
         }
 
         self.variant = Some(function.variant);
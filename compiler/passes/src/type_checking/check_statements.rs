@@ -39,6 +39,9 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             Statement::Definition(stmt) => self.visit_definition(stmt),
             Statement::Expression(stmt) => self.visit_expression_statement(stmt),
             Statement::Iteration(stmt) => self.visit_iteration(stmt),
+            Statement::Match(_) => {
+                unreachable!("`MatchStatement`s should not be in the AST at this phase of compilation.")
+            }
             Statement::Return(stmt) => self.visit_return(stmt),
         }
     }
@@ -60,9 +63,10 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
     }
 
     fn visit_assign(&mut self, input: &'a AssignStatement) {
-        let var_name = match input.place {
-            Expression::Identifier(id) => id,
-            _ => {
+        // The place is either a variable, or a member, tuple element, or array element of a variable.
+        let var_name = match assigned_variable(&input.place) {
+            Some(id) => id,
+            None => {
                 self.emit_err(TypeCheckerError::invalid_assignment_target(input.place.span()));
                 return;
             }
@@ -84,8 +88,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
             None
         };
 
-        if var_type.is_some() {
-            self.visit_expression(&input.value, &var_type);
+        // If part of the variable is assigned to, check the place to get the type of that part.
+        let place_type = match &input.place {
+            Expression::Identifier(_) => var_type,
+            place if var_type.is_some() => self.visit_expression(place, &None),
+            _ => None,
+        };
+
+        if place_type.is_some() {
+            self.visit_expression(&input.value, &place_type);
         }
     }
 
@@ -293,16 +304,24 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
 
     fn visit_iteration(&mut self, input: &'a IterationStatement) {
         let iter_type = &Some(input.type_.clone());
-        self.assert_int_type(iter_type, input.variable.span);
+        // The variable of a range is an integer. The variable of an array may be of any type.
+        if matches!(input.range, IterationRange::Range { .. }) {
+            self.assert_int_type(iter_type, input.variable.span);
+        }
 
         // Create a new scope for the loop body.
         let scope_index = self.create_child_scope();
 
         // Add the loop variable to the scope of the loop body.
+        // Note that the elements of an array are not constants, so they cannot be used as loop bounds.
+        let declaration = match input.range {
+            IterationRange::Range { .. } => VariableType::Const,
+            IterationRange::Array { .. } => VariableType::Mut,
+        };
         if let Err(err) = self.symbol_table.borrow_mut().insert_variable(input.variable.name, VariableSymbol {
             type_: input.type_.clone(),
             span: input.span(),
-            declaration: VariableType::Const,
+            declaration,
         }) {
             self.handler.emit_err(err);
         }
@@ -326,45 +345,23 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         // Exit the scope.
         self.exit_scope(scope_index);
 
-        // Check that the literal is valid.
-        self.visit_expression(&input.start, iter_type);
-
-        // If `input.start` is a valid literal, instantiate it as a value.
-        match &input.start {
-            Expression::Literal(literal) => {
-                // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
-                    input.start_value.replace(Some(value));
-                }
-            }
-            Expression::Identifier(id) => {
-                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
-                    if VariableType::Const != var.declaration {
-                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
-                    }
-                }
-            }
-            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(input.start.span())),
-        }
-
-        self.visit_expression(&input.stop, iter_type);
-
-        // If `input.stop` is a valid literal, instantiate it as a value.
-        match &input.stop {
-            Expression::Literal(literal) => {
-                // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
-                if let Ok(value) = Value::try_from(literal) {
-                    input.stop_value.replace(Some(value));
+        match &input.range {
+            IterationRange::Range { start, start_value, stop, stop_value, step, step_value, .. } => {
+                self.check_loop_bound(start, start_value, iter_type);
+                self.check_loop_bound(stop, stop_value, iter_type);
+                if let Some(step) = step {
+                    self.check_loop_bound(step, step_value, iter_type);
                 }
             }
-            Expression::Identifier(id) => {
-                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
-                    if VariableType::Const != var.declaration {
-                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
+            IterationRange::Array { array } => {
+                // Check that the array is an array of elements of the type of the loop variable.
+                match self.visit_expression(array, &None) {
+                    Some(Type::Array(array_type)) => {
+                        self.assert_type(&Some(array_type.element_type().clone()), &input.type_, input.variable.span)
                     }
+                    type_ => self.assert_array_type(&type_, array.span()),
                 }
             }
-            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(input.stop.span())),
         }
     }
 
@@ -432,3 +429,15 @@ impl<'a> StatementVisitor<'a> for TypeChecker<'a> {
         }
     }
 }
+
+/// Returns the variable that is assigned to when assigning to `place`, if `place` is a valid assignment target.
+/// Valid targets are variables, and members, tuple elements, and array elements of valid targets.
+fn assigned_variable(place: &Expression) -> Option<Identifier> {
+    match place {
+        Expression::Identifier(identifier) => Some(*identifier),
+        Expression::Access(AccessExpression::Array(access)) => assigned_variable(&access.array),
+        Expression::Access(AccessExpression::Member(access)) => assigned_variable(&access.inner),
+        Expression::Access(AccessExpression::Tuple(access)) => assigned_variable(&access.tuple),
+        _ => None,
+    }
+}
@@ -19,6 +19,8 @@ use crate::{CallGraph, StructGraph, SymbolTable, TypeTable, VariableSymbol, Vari
 use leo_ast::{
     CoreConstant,
     CoreFunction,
+    Expression,
+    ExpressionVisitor,
     Finalize,
     Function,
     Identifier,
@@ -28,6 +30,7 @@ use leo_ast::{
     Node,
     Output,
     Type,
+    Value,
     Variant,
 };
 use leo_errors::{emitter::Handler, TypeCheckerError};
@@ -1141,6 +1144,35 @@ impl<'a> TypeChecker<'a> {
         self.check_type(|type_| matches!(type_, Type::Array(_)), "array".to_string(), type_, span);
     }
 
+    /// Type checks a bound or step of a loop, which must be a literal or a constant, and stores its value if it is a literal.
+    pub(crate) fn check_loop_bound(
+        &mut self,
+        bound: &'a Expression,
+        value: &RefCell<Option<Value>>,
+        iter_type: &Option<Type>,
+    ) {
+        // Check that the literal is valid.
+        self.visit_expression(bound, iter_type);
+
+        // If `bound` is a valid literal, instantiate it as a value.
+        match bound {
+            Expression::Literal(literal) => {
+                // Note that this check is needed because the pass attempts to make progress, even though the literal may be invalid.
+                if let Ok(literal_value) = Value::try_from(literal) {
+                    value.replace(Some(literal_value));
+                }
+            }
+            Expression::Identifier(id) => {
+                if let Some(var) = self.symbol_table.borrow().lookup_variable(id.name) {
+                    if VariableType::Const != var.declaration {
+                        self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(id.span));
+                    }
+                }
+            }
+            _ => self.emit_err(TypeCheckerError::loop_bound_must_be_literal_or_const(bound.span())),
+        }
+    }
+
     /// Helper function to check that the input and output of function are valid
     pub(crate) fn check_function_signature(&mut self, function: &Function) {
         self.variant = Some(function.variant);
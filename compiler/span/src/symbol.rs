@@ -239,6 +239,7 @@ symbols! {
     constant,
     decrement,
     Else: "else",
+    Enum: "enum",
     finalize,
     For: "for",
     function,
@@ -252,12 +253,14 @@ symbols! {
     leo,
     main,
     mapping,
+    Match: "match",
     Mut: "mut",
     Return: "return",
     SelfLower: "self",
     SelfUpper: "Self",
     signer,
     Star: "*",
+    step,
     then,
     transition,
     Type: "type",
@@ -271,6 +274,11 @@ symbols! {
     stub,
     block,
     height,
+
+    // annotations
+    allow,
+    should_fail,
+    test,
 }
 
 /// An interned string.
@@ -88,6 +88,22 @@ macro_rules! create_messages {
 
         // Steps over the list of functions with an initial code of 0.
         impl $type_ {
+            /// Returns the span of the message, if it was reported at a location in the source.
+            pub fn span(&self) -> Option<leo_span::Span> {
+                match self {
+                    Self::Formatted(formatted) => Some(formatted.span),
+                    Self::Backtraced(_) => None,
+                }
+            }
+
+            /// Returns the message, without its location in the source.
+            pub fn backtraced(&self) -> &Backtraced {
+                match self {
+                    Self::Formatted(formatted) => &formatted.backtrace,
+                    Self::Backtraced(backtraced) => backtraced,
+                }
+            }
+
             create_messages!(@step 0i32, $(($(#[$docs])* $formatted_or_backtraced_list, $names($($arg_names: $arg_types,)*), $messages, $helps),)*);
         }
     };
@@ -18,6 +18,8 @@ use crate::LeoWarning;
 
 use super::LeoError;
 use core::{default::Default, fmt};
+use leo_span::{symbol::with_session_globals, Span};
+use serde::Serialize;
 use std::{cell::RefCell, rc::Rc};
 
 /// Types that are sinks for compiler errors.
@@ -53,6 +55,131 @@ impl Emitter for StderrEmitter {
     }
 }
 
+/// An `Emitter` that writes each error and warning to the standard error as a JSON object on its own line.
+#[derive(Default)]
+pub struct JsonEmitter {
+    /// Exit code of the last emitted error.
+    last_error_code: Option<i32>,
+}
+
+impl JsonEmitter {
+    /// Returns a new JSON emitter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Emitter for JsonEmitter {
+    fn emit_err(&mut self, err: LeoError) {
+        // Note that the last error code only signals that errors were already emitted, so there is nothing to print.
+        if let LeoError::LastErrorCode(code) = err {
+            self.last_error_code = Some(code);
+            return;
+        }
+        let diagnostic = Diagnostic::from_error(&err);
+        self.last_error_code = Some(diagnostic.exit_code.unwrap_or(1));
+        eprintln!("{diagnostic}");
+    }
+
+    fn last_emitted_err_code(&self) -> Option<i32> {
+        self.last_error_code
+    }
+
+    fn emit_warning(&mut self, warning: LeoWarning) {
+        eprintln!("{}", Diagnostic::from_warning(&warning));
+    }
+}
+
+/// An error or warning, as written by the `JsonEmitter`.
+#[derive(Serialize)]
+struct Diagnostic {
+    /// Either `error` or `warning`.
+    level: &'static str,
+    /// The unique identifier of the error or warning, e.g. `EPAR0370005`.
+    code: Option<String>,
+    /// The exit code of the error.
+    exit_code: Option<i32>,
+    /// The message of the error or warning.
+    message: String,
+    /// The help message, if any.
+    help: Option<String>,
+    /// The location in the source, if the error or warning was reported at one.
+    location: Option<Location>,
+}
+
+/// A range in a source file, where lines and columns start at 1.
+#[derive(Serialize)]
+struct Location {
+    /// The name of the source file.
+    file: String,
+    /// The line on which the range starts.
+    line_start: usize,
+    /// The column at which the range starts.
+    column_start: usize,
+    /// The line on which the range stops.
+    line_stop: usize,
+    /// The column after the end of the range.
+    column_stop: usize,
+}
+
+impl Diagnostic {
+    /// Returns the diagnostic of an error other than the last error code.
+    fn from_error(err: &LeoError) -> Self {
+        match err.backtraced() {
+            Some(backtraced) => Self {
+                level: "error",
+                code: Some(backtraced.error_code()),
+                exit_code: Some(backtraced.exit_code()),
+                message: backtraced.message.clone(),
+                help: backtraced.help.clone(),
+                location: err.span().and_then(Location::new),
+            },
+            // Note that `anyhow` errors have neither a code nor a location.
+            None => Self {
+                level: "error",
+                code: None,
+                exit_code: None,
+                message: err.to_string(),
+                help: None,
+                location: None,
+            },
+        }
+    }
+
+    /// Returns the diagnostic of a warning.
+    fn from_warning(warning: &LeoWarning) -> Self {
+        let backtraced = warning.backtraced();
+        Self {
+            level: "warning",
+            code: Some(backtraced.warning_code()),
+            exit_code: None,
+            message: backtraced.message.clone(),
+            help: backtraced.help.clone(),
+            location: warning.span().and_then(Location::new),
+        }
+    }
+}
+
+impl Location {
+    /// Returns the location of the span, if it is in a source known to the source map.
+    fn new(span: Span) -> Option<Self> {
+        let location = with_session_globals(|s| s.source_map.span_to_location(span))?;
+        Some(Self {
+            file: location.source_file.name.to_string(),
+            line_start: location.line_start,
+            column_start: location.col_start,
+            line_stop: location.line_stop,
+            column_stop: location.col_stop,
+        })
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&serde_json::to_string(self).map_err(|_| fmt::Error)?)
+    }
+}
+
 /// A buffer of `T`s.
 #[derive(Debug)]
 pub struct Buffer<T>(Vec<T>);
@@ -184,6 +311,11 @@ impl Handler {
         Self { inner }
     }
 
+    /// Construct a `Handler` that writes errors and warnings as JSON objects.
+    pub fn json() -> Self {
+        Self::new(Box::new(JsonEmitter::new()))
+    }
+
     /// Construct a `Handler` that will append to `buf`.
     pub fn new_with_buf() -> (Self, BufferEmitter) {
         let buf = BufferEmitter::default();
@@ -259,7 +391,7 @@ impl Handler {
 mod tests {
     use super::*;
     use crate::ParserError;
-    use leo_span::{symbol::create_session_if_not_set_then, Span};
+    use leo_span::{source_map::FileName, span::BytePos, symbol::create_session_if_not_set_then, Span};
 
     #[test]
     fn fresh_no_errors() {
@@ -296,4 +428,35 @@ mod tests {
             Handler::with(|_| Ok(())).unwrap();
         })
     }
+
+    #[test]
+    fn json_diagnostic() {
+        create_session_if_not_set_then(|s| {
+            // Errors reported at a span outside of the sources have no location.
+            let diagnostic = Diagnostic::from_error(&ParserError::unexpected_eof(Span::default()).into());
+            assert!(diagnostic.location.is_none());
+
+            let source =
+                s.source_map.new_source("program test.aleo {\n    import\n}\n", FileName::Custom("main.leo".into()));
+            let span = Span::new(source.start_pos + BytePos(24), source.start_pos + BytePos(30));
+
+            let diagnostic = Diagnostic::from_error(&ParserError::unexpected_eof(span).into());
+            let json: serde_json::Value = serde_json::from_str(&diagnostic.to_string()).unwrap();
+            assert_eq!(json["level"], "error");
+            assert_eq!(json["code"], "EPAR0370003");
+            assert_eq!(json["exit_code"], 370003);
+            assert_eq!(json["message"], "unexpected EOF");
+            assert_eq!(json["help"], serde_json::Value::Null);
+            assert_eq!(
+                json["location"],
+                serde_json::json!({
+                    "file": "main.leo",
+                    "line_start": 2,
+                    "column_start": 5,
+                    "line_stop": 2,
+                    "column_stop": 11,
+                })
+            );
+        })
+    }
 }
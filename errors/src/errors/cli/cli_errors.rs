@@ -178,4 +178,123 @@ create_messages!(
         msg: format!("Failed to write file.\nIO Error: {error}"),
         help: None,
     }
+
+    @backtraced
+    failed_to_run_tests {
+        args: (error: impl Display),
+        msg: format!("Failed to run the tests.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @formatted
+    test_failed {
+        args: (test: impl Display, error: impl Display),
+        msg: format!("Test `{test}` failed.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @formatted
+    test_did_not_fail {
+        args: (test: impl Display),
+        msg: format!("Test `{test}` was expected to fail, but it passed."),
+        help: Some("Remove the `@should_fail` annotation if the test is expected to pass.".to_string()),
+    }
+
+    @backtraced
+    tests_failed {
+        args: (failed: impl Display, total: impl Display),
+        msg: format!("{failed} of {total} tests failed."),
+        help: None,
+    }
+
+    @backtraced
+    language_server_error {
+        args: (error: impl Display),
+        msg: format!("The language server failed.\nError: {error}"),
+        help: None,
+    }
+
+    @formatted
+    import_not_built {
+        args: (dependency_name: impl Display),
+        msg: format!("The imported program `{dependency_name}.aleo` has not been built."),
+        help: Some("Run `leo build` to build the dependencies of the package.".to_string()),
+    }
+
+    @backtraced
+    unformatted_files {
+        args: (count: impl Display),
+        msg: format!("{count} file(s) are not formatted."),
+        help: Some("Run `leo fmt` to format them.".to_string()),
+    }
+
+    @backtraced
+    unknown_lint {
+        args: (lint: impl Display, lints: impl Display),
+        msg: format!("Unknown lint `{lint}` in `program.json`."),
+        help: Some(format!("The available lints are: {lints}.")),
+    }
+
+    @backtraced
+    failed_to_write_source_map {
+        args: (error: impl Display),
+        msg: format!("Failed to write the source map of the compiled Aleo instructions.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_abi {
+        args: (error: impl Display),
+        msg: format!("Failed to write the ABI of the program.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_generate_bindings {
+        args: (error: impl Display),
+        msg: format!("Failed to generate bindings to the program.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_debug {
+        args: (error: impl Display),
+        msg: format!("Failed to debug the program.\nSnarkVM Error: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_read_state {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to read the mappings stored in `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_write_state {
+        args: (path: impl Display, error: impl Display),
+        msg: format!("Failed to write the mappings stored in `{path}`.\nError: {error}"),
+        help: None,
+    }
+
+    @backtraced
+    failed_to_finalize {
+        args: (error: impl Display),
+        msg: format!("Failed to run the finalize block against the stored mappings.\nSnarkVM Error: {error}"),
+        help: Some("None of the updates of the finalize block to the mappings were stored.".to_string()),
+    }
+
+    @backtraced
+    mapping_not_in_state {
+        args: (mapping: impl Display, state: impl Display),
+        msg: format!("The mapping `{mapping}` is not stored in `{state}`."),
+        help: Some("Mappings are stored once a transition of their program is run with `leo run --state`.".to_string()),
+    }
+
+    @backtraced
+    key_not_in_mapping {
+        args: (key: impl Display, mapping: impl Display),
+        msg: format!("The key `{key}` is not in the mapping `{mapping}`."),
+        help: None,
+    }
 );
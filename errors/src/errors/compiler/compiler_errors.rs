@@ -77,4 +77,18 @@ create_messages!(
         msg: format!("`{main_program_name}` imports `{dependency_name}.aleo`, but `{dependency_name}.aleo` is not found in `program.json`."),
         help: None,
     }
+
+    @formatted
+    module_not_found {
+        args: (module_name: impl Display, path: impl Display),
+        msg: format!("The module `{module_name}` could not be found at `{path}`."),
+        help: Some(format!("Add a `{module_name}.leo` file next to the file that imports it.")),
+    }
+
+    @formatted
+    cyclic_module_imports {
+        args: (cycle: impl Display),
+        msg: format!("Modules cannot import each other in a cycle: {cycle}."),
+        help: None,
+    }
 );
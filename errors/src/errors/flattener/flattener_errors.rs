@@ -53,4 +53,12 @@ create_messages!(
         ),
         help: None,
     }
+
+    /// For when a constant cast would cause an overflow.
+    @formatted
+    cast_overflow {
+        args: (value: impl Display, value_type: impl Display, cast_type: impl Display),
+        msg: format!("The const operation `{value}{value_type} as {cast_type}` causes an overflow."),
+        help: None,
+    }
 );
@@ -0,0 +1,81 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+use std::fmt::{Debug, Display};
+
+create_messages!(
+    /// InterpreterError enum that represents all the errors for evaluating programs in the `leo-interpreter` crate.
+    InterpreterError,
+    code_mask: 11000i32,
+    code_prefix: "INT",
+
+    @backtraced
+    function_not_found {
+        args: (program: impl Display, function: impl Display),
+        msg: format!("The program `{program}` has no function named `{function}`."),
+        help: None,
+    }
+
+    @backtraced
+    incorrect_number_of_inputs {
+        args: (function: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The function `{function}` expects {expected} input(s), but {found} were given."),
+        help: None,
+    }
+
+    @backtraced
+    invalid_input {
+        args: (input: impl Display, type_: impl Display, error: impl Display),
+        msg: format!("The input `{input}` is not a valid `{type_}`: {error}"),
+        help: None,
+    }
+
+    @formatted
+    failed_to_evaluate {
+        args: (expression: impl Display, error: impl Display),
+        msg: format!("Failed to evaluate `{expression}`: {error}"),
+        help: None,
+    }
+
+    @formatted
+    assertion_failed {
+        args: (assertion: impl Display),
+        msg: format!("The assertion `{assertion}` failed."),
+        help: None,
+    }
+
+    @formatted
+    mapping_key_not_found {
+        args: (mapping: impl Display, key: impl Display),
+        msg: format!("The mapping `{mapping}` has no value for the key `{key}`."),
+        help: None,
+    }
+
+    @formatted
+    array_index_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("The index `{index}` is out of bounds for an array of length `{length}`."),
+        help: None,
+    }
+
+    @formatted
+    unsupported {
+        args: (construct: impl Display),
+        msg: format!("The interpreter does not support {construct}."),
+        help: None,
+    }
+);
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::create_messages;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 
 create_messages!(
     /// LoopUnrollerError enum that represents all the errors for the loop unrolling errors in the `leo-loop_unroller` crate.
@@ -36,4 +36,18 @@ create_messages!(
         msg: format!("The array index must be constant."),
         help: None,
     }
+
+    @formatted
+    array_assignment_out_of_bounds {
+        args: (index: impl Display, length: impl Display),
+        msg: format!("Cannot assign to index `{index}` of an array of length `{length}`."),
+        help: None,
+    }
+
+    @formatted
+    loop_step_not_positive {
+        args: (),
+        msg: format!("The loop step must be positive."),
+        help: Some("The loop counts down if the start of the range is greater than its end.".to_string()),
+    }
 );
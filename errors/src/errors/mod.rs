@@ -15,7 +15,9 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 /// Contains the ASG error definitions.
-use crate::LeoMessageCode;
+use crate::{Backtraced, LeoMessageCode};
+
+use leo_span::Span;
 
 /// Contains the AST error definitions.
 pub mod ast;
@@ -33,6 +35,10 @@ pub use self::compiler::*;
 pub mod flattener;
 pub use self::flattener::*;
 
+/// Contains the Interpreter error definitions.
+pub mod interpreter;
+pub use self::interpreter::*;
+
 /// Contains the Loop Unroller error definitions.
 pub mod loop_unroller;
 pub use self::loop_unroller::*;
@@ -45,6 +51,10 @@ pub use self::package::*;
 pub mod parser;
 pub use self::parser::*;
 
+/// Contains the Static Analyzer warning definitions.
+pub mod static_analyzer;
+pub use self::static_analyzer::*;
+
 /// Contains the Type Checker error definitions.
 pub mod type_checker;
 pub use self::type_checker::*;
@@ -81,6 +91,9 @@ pub enum LeoError {
     /// Represents a Flatten Error in a Leo Error.
     #[error(transparent)]
     FlattenError(#[from] FlattenError),
+    /// Represents an Interpreter Error in a Leo Error.
+    #[error(transparent)]
+    InterpreterError(#[from] InterpreterError),
     /// Purely for just exiting with the correct status code and
     /// not re-displaying an error.
     #[error("")]
@@ -107,6 +120,7 @@ impl LeoError {
             TypeCheckerError(error) => error.error_code(),
             LoopUnrollerError(error) => error.error_code(),
             FlattenError(error) => error.error_code(),
+            InterpreterError(error) => error.error_code(),
             UtilError(error) => error.error_code(),
             LastErrorCode(_) => unreachable!(),
             Anyhow(_) => unimplemented!(), // todo: implement error codes for snarkvm errors.
@@ -126,11 +140,50 @@ impl LeoError {
             TypeCheckerError(error) => error.exit_code(),
             LoopUnrollerError(error) => error.exit_code(),
             FlattenError(error) => error.exit_code(),
+            InterpreterError(error) => error.exit_code(),
             UtilError(error) => error.exit_code(),
             LastErrorCode(code) => *code,
             Anyhow(_) => unimplemented!(), // todo: implement exit codes for snarkvm errors.
         }
     }
+
+    /// Returns the span of the error, if it was reported at a location in the source.
+    pub fn span(&self) -> Option<Span> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => error.span(),
+            CompilerError(error) => error.span(),
+            CliError(error) => error.span(),
+            ParserError(error) => error.span(),
+            PackageError(error) => error.span(),
+            TypeCheckerError(error) => error.span(),
+            LoopUnrollerError(error) => error.span(),
+            FlattenError(error) => error.span(),
+            InterpreterError(error) => error.span(),
+            UtilError(error) => error.span(),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
+
+    /// Returns the message of the error, without its location in the source.
+    pub fn backtraced(&self) -> Option<&Backtraced> {
+        use LeoError::*;
+
+        match self {
+            AstError(error) => Some(error.backtraced()),
+            CompilerError(error) => Some(error.backtraced()),
+            CliError(error) => Some(error.backtraced()),
+            ParserError(error) => Some(error.backtraced()),
+            PackageError(error) => Some(error.backtraced()),
+            TypeCheckerError(error) => Some(error.backtraced()),
+            LoopUnrollerError(error) => Some(error.backtraced()),
+            FlattenError(error) => Some(error.backtraced()),
+            InterpreterError(error) => Some(error.backtraced()),
+            UtilError(error) => Some(error.backtraced()),
+            LastErrorCode(_) | Anyhow(_) => None,
+        }
+    }
 }
 
 /// The LeoWarning type that contains all sub error types.
@@ -140,6 +193,9 @@ pub enum LeoWarning {
     /// Represents an Parser Error in a Leo Error.
     #[error(transparent)]
     ParserWarning(#[from] ParserWarning),
+    /// Represents a Static Analyzer Warning in a Leo Warning.
+    #[error(transparent)]
+    StaticAnalyzerWarning(#[from] StaticAnalyzerWarning),
 }
 
 impl LeoWarning {
@@ -149,6 +205,27 @@ impl LeoWarning {
 
         match self {
             ParserWarning(warning) => warning.warning_code(),
+            StaticAnalyzerWarning(warning) => warning.warning_code(),
+        }
+    }
+
+    /// Returns the span of the warning, if it was reported at a location in the source.
+    pub fn span(&self) -> Option<Span> {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.span(),
+            StaticAnalyzerWarning(warning) => warning.span(),
+        }
+    }
+
+    /// Returns the message of the warning, without its location in the source.
+    pub fn backtraced(&self) -> &Backtraced {
+        use LeoWarning::*;
+
+        match self {
+            ParserWarning(warning) => warning.backtraced(),
+            StaticAnalyzerWarning(warning) => warning.backtraced(),
         }
     }
 }
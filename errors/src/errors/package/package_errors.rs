@@ -348,4 +348,11 @@ create_messages!(
         msg: format!("Failed to update `program.json` from the provided file path {path} - {error}"),
         help: None,
     }
+
+    @backtraced
+    missing_main_file {
+        args: (),
+        msg: "The `src/` directory does not contain a `main.leo` file.".to_string(),
+        help: Some("Add a `main.leo` file to the `src/` directory.".to_string()),
+    }
 );
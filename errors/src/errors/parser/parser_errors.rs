@@ -335,4 +335,32 @@ create_messages!(
         msg: format!("Only external calls to `.aleo` programs are supported."),
         help: None,
     }
+
+    @formatted
+    cannot_import_program_in_module {
+        args: (),
+        msg: format!("Cannot import a program in a module."),
+        help: Some("Import the program in `main.leo` instead.".to_string()),
+    }
+
+    @formatted
+    only_inline_functions_in_modules {
+        args: (),
+        msg: format!("Only `inline` functions can be declared in a module."),
+        help: Some("Declare the function in the program scope of `main.leo` instead.".to_string()),
+    }
+
+    @formatted
+    const_parameters_only_on_inline_functions {
+        args: (),
+        msg: format!("Only `inline` functions can have const parameters."),
+        help: None,
+    }
+
+    @formatted
+    const_parameter_must_be_integer {
+        args: (type_: impl Display),
+        msg: format!("Const parameters must have an integer type, found `{type_}`."),
+        help: None,
+    }
 );
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::create_messages;
+
+use std::fmt::Display;
+
+create_messages!(
+    /// StaticAnalyzerWarning enum that represents all the warnings for the static analysis passes.
+    StaticAnalyzerWarning,
+    code_mask: 8000i32,
+    code_prefix: "SAZ",
+
+    /// For when a value derived from private data is returned through a public output.
+    @formatted
+    private_value_in_public_output {
+        args: (function: impl Display),
+        msg: format!("A value derived from private data is returned as a public output of `{function}`."),
+        help: Some("Public outputs are visible on-chain. Declare the output as `private`, or remove its dependency on private inputs and record fields.".to_string()),
+    }
+
+    /// For when a value derived from private data is passed to a finalize block.
+    @formatted
+    private_value_in_finalize_argument {
+        args: (function: impl Display),
+        msg: format!("A value derived from private data is passed to the finalize block of `{function}`."),
+        help: Some("Finalize arguments are visible on-chain. Only pass values that do not depend on private inputs and record fields.".to_string()),
+    }
+
+    /// For when a value derived from private data is used as a mapping key or value.
+    @formatted
+    private_value_in_mapping {
+        args: (operation: impl Display),
+        msg: format!("A value derived from private data is used as a mapping key or value in `{operation}`."),
+        help: Some("Mapping contents are visible on-chain.".to_string()),
+    }
+
+    /// For when a variable is declared but never read.
+    @formatted
+    unused_variable {
+        args: (name: impl Display, lint: impl Display),
+        msg: format!("The variable `{name}` is never used."),
+        help: Some(format!("Remove it, or allow the lint with `@allow({lint})`.")),
+    }
+
+    /// For when an inline function is never called.
+    @formatted
+    unused_inline {
+        args: (name: impl Display, lint: impl Display),
+        msg: format!("The inline function `{name}` is never called."),
+        help: Some(format!("Remove it, or allow the lint with `@allow({lint})`.")),
+    }
+
+    /// For when a mapping is never read or written.
+    @formatted
+    unused_mapping {
+        args: (name: impl Display, lint: impl Display),
+        msg: format!("The mapping `{name}` is never used."),
+        help: Some(format!("Remove it, or allow the lint by setting `\"{lint}\": false` under `lints` in `program.json`.")),
+    }
+
+    /// For when a name is bound more than once in the same function.
+    @formatted
+    shadowed_binding {
+        args: (name: impl Display, lint: impl Display),
+        msg: format!("`{name}` is bound more than once in this function."),
+        help: Some(format!("Rename one of the bindings, or allow the lint with `@allow({lint})`.")),
+    }
+
+    /// For when the condition of an assertion is known at compile time.
+    @formatted
+    constant_assert {
+        args: (value: bool, lint: impl Display),
+        msg: format!("This assertion always {}.", if value { "holds" } else { "fails" }),
+        help: Some(format!("Remove the assertion, or allow the lint with `@allow({lint})`.")),
+    }
+
+    /// For when a transition receives a record that it never uses.
+    @formatted
+    unused_record {
+        args: (name: impl Display, lint: impl Display),
+        msg: format!("The record `{name}` is consumed by this transition but never used."),
+        help: Some(format!("Its contents are lost once the transition executes. Use it, or allow the lint with `@allow({lint})`.")),
+    }
+);
@@ -747,4 +747,151 @@ create_messages!(
         msg: format!("`stub` name `{stub_name}` does not match program name `{program_name}`"),
         help: Some("Check that the name you used as a dependency in program.json matches the name you used to import the program in the main leo file.".to_string()),
     }
+
+    @formatted
+    test_function_must_be_transition {
+        args: (),
+        msg: format!("Only transitions can be annotated with `@test`."),
+        help: None,
+    }
+
+    @formatted
+    test_function_cannot_have_inputs {
+        args: (),
+        msg: format!("A test transition cannot have inputs."),
+        help: Some("Declare the values the test needs inside its body.".to_string()),
+    }
+
+    @formatted
+    should_fail_requires_test {
+        args: (),
+        msg: format!("The `@should_fail` annotation can only be used together with `@test`."),
+        help: None,
+    }
+
+    @formatted
+    not_a_generic_function {
+        args: (function: impl Display),
+        msg: format!("The function `{function}` does not have const parameters."),
+        help: None,
+    }
+
+    @formatted
+    missing_const_arguments {
+        args: (function: impl Display),
+        msg: format!("The generic function `{function}` must be called with const arguments."),
+        help: Some(format!("Pass the const arguments in brackets, e.g. `{function}::[4u32](...)`.")),
+    }
+
+    @formatted
+    incorrect_num_const_args {
+        args: (expected: impl Display, received: impl Display),
+        msg: format!("Call expected `{expected}` const args, but got `{received}`"),
+        help: None,
+    }
+
+    @formatted
+    invalid_const_argument {
+        args: (),
+        msg: format!("A const argument must be a literal, a const, or a const parameter."),
+        help: None,
+    }
+
+    @formatted
+    unknown_const_parameter {
+        args: (name: impl Display),
+        msg: format!("The array length `{name}` is not a const parameter of the enclosing function."),
+        help: None,
+    }
+
+    @formatted
+    duplicate_enum_variant {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("The enum `{enum_}` has more than one variant named `{variant}`."),
+        help: None,
+    }
+
+    @formatted
+    reserved_enum_variant {
+        args: (variant: impl Display),
+        msg: format!("An enum variant cannot be named `{variant}`."),
+        help: Some("The name is reserved for the member holding the variant of the lowered struct.".to_string()),
+    }
+
+    @formatted
+    too_many_enum_variants {
+        args: (enum_: impl Display, max: impl Display),
+        msg: format!("The enum `{enum_}` has more than {max} variants."),
+        help: None,
+    }
+
+    @formatted
+    invalid_enum_payload {
+        args: (type_: impl Display),
+        msg: format!("The type `{type_}` cannot be the payload of an enum variant."),
+        help: Some("Payloads must be plaintext types other than strings and signatures, e.g. integers, arrays, and structs.".to_string()),
+    }
+
+    @formatted
+    not_an_enum {
+        args: (name: impl Display),
+        msg: format!("`{name}` is not an enum."),
+        help: None,
+    }
+
+    @formatted
+    unknown_enum_variant {
+        args: (enum_: impl Display, variant: impl Display),
+        msg: format!("The enum `{enum_}` does not have a variant named `{variant}`."),
+        help: None,
+    }
+
+    @formatted
+    enum_variant_payload_mismatch {
+        args: (variant: impl Display, payload: impl Display),
+        msg: format!("The variant `{variant}` {payload}."),
+        help: None,
+    }
+
+    @formatted
+    non_exhaustive_match {
+        args: (missing: impl Display),
+        msg: format!("The match does not cover the variants {missing}."),
+        help: Some("Add an arm for each missing variant, or a wildcard arm `_ => { ... }`.".to_string()),
+    }
+
+    @formatted
+    unreachable_match_arm {
+        args: (),
+        msg: format!("This match arm is unreachable, since its variants are matched by a previous arm."),
+        help: None,
+    }
+
+    @formatted
+    match_arm_enum_mismatch {
+        args: (expected: impl Display, found: impl Display),
+        msg: format!("Expected a variant of the enum `{expected}`, but found a variant of `{found}`."),
+        help: None,
+    }
+
+    @formatted
+    match_without_variants {
+        args: (),
+        msg: format!("A match must have at least one arm that matches a variant."),
+        help: None,
+    }
+
+    @formatted
+    unknown_lint {
+        args: (lint: impl Display, lints: impl Display),
+        msg: format!("Unknown lint: `{lint}`."),
+        help: Some(format!("The available lints are: {lints}.")),
+    }
+
+    @formatted
+    allow_requires_lints {
+        args: (),
+        msg: format!("The `@allow` annotation requires a list of lints, e.g. `@allow(unused_variable)`."),
+        help: None,
+    }
 );
@@ -144,4 +144,46 @@ create_messages!(
         msg: format!("Failed to retrieve from endpoint `{endpoint}`. Error: {error}"),
         help: None,
     }
+
+    @formatted
+    program_not_found_in_registry {
+        args: (program: impl Display, network: impl Display, searched: impl Display),
+        msg: format!("Could not find `{program}` on `{network}`: {searched}"),
+        help: Some("Vendor the program into the `imports/` directory of the package, add it to a mirror passed with `--mirror`, or build without `--offline`.".to_string()),
+    }
+
+    @formatted
+    checksum_mismatch {
+        args: (program: impl Display, expected: impl Display, found: impl Display),
+        msg: format!("The checksum of `{program}` does not match `leo.lock`. Expected `{expected}`, found `{found}`"),
+        help: Some("If the change is intended, run `leo update-deps` to update `leo.lock`.".to_string()),
+    }
+
+    @formatted
+    lock_file_out_of_date {
+        args: (reason: impl Display),
+        msg: format!("`leo.lock` needs to be updated but `--locked` was passed: {reason}"),
+        help: Some("Run `leo update-deps` to update `leo.lock`.".to_string()),
+    }
+
+    @formatted
+    missing_git_error {
+        args: (dependency: impl Display),
+        msg: format!("Git dependency {dependency} is missing a repository specification"),
+        help: Some("Add a repository to the dependency in the `program.json` file. Example: `git: { \"url\": \"https://github.com/org/repo.git\", \"rev\": \"4f2c1e7\" }`".to_string()),
+    }
+
+    @formatted
+    failed_to_run_git {
+        args: (command: impl Display, error: impl Display),
+        msg: format!("Failed to run `git {command}`. Error: {error}"),
+        help: None,
+    }
+
+    @formatted
+    invalid_git_revision {
+        args: (revision: impl Display),
+        msg: format!("`{revision}` is not a valid Git revision for a dependency"),
+        help: None,
+    }
 );
@@ -17,7 +17,7 @@
 use crate::cli::{commands::*, context::*, helpers::*};
 use clap::Parser;
 use colored::Colorize;
-use leo_errors::Result;
+use leo_errors::{emitter::Handler, Result};
 use std::{path::PathBuf, process::exit};
 
 /// CLI Arguments entry point - includes global parameters and subcommands
@@ -38,6 +38,9 @@ pub struct CLI {
 
     #[clap(long, global = true, help = "Optional path to aleo program registry.")]
     pub home: Option<PathBuf>,
+
+    #[clap(long, global = true, value_enum, default_value = "human", help = "The format of errors and warnings")]
+    message_format: MessageFormat,
 }
 
 ///Leo compiler and package manager
@@ -63,6 +66,11 @@ enum Commands {
         #[clap(subcommand)]
         command: Example,
     },
+    #[clap(about = "Generate typed client bindings to a program")]
+    Bindgen {
+        #[clap(flatten)]
+        command: Bindgen,
+    },
     #[clap(about = "Compile the current package as a program")]
     Build {
         #[clap(flatten)]
@@ -78,11 +86,46 @@ enum Commands {
         #[clap(flatten)]
         command: Run,
     },
+    #[clap(about = "Inspect the mappings stored by `leo run --state`")]
+    State {
+        #[clap(subcommand)]
+        command: State,
+    },
+    #[clap(about = "Debug a transition with the Leo interpreter, pausing at breakpoints")]
+    Debug {
+        #[clap(flatten)]
+        command: Debug,
+    },
     #[clap(about = "Execute a program with input variables")]
     Execute {
         #[clap(flatten)]
         command: Execute,
     },
+    #[clap(about = "Format the Leo files of the current package")]
+    Fmt {
+        #[clap(flatten)]
+        command: Fmt,
+    },
+    #[clap(about = "Check the current package against the lints configured in `program.json`")]
+    Lint {
+        #[clap(flatten)]
+        command: Lint,
+    },
+    #[clap(about = "Run a language server for Leo files over stdin and stdout")]
+    Lsp {
+        #[clap(flatten)]
+        command: Lsp,
+    },
+    #[clap(about = "Run the tests of the current package")]
+    Test {
+        #[clap(flatten)]
+        command: Test,
+    },
+    #[clap(about = "Update the checksums of the dependencies in `leo.lock`")]
+    UpdateDeps {
+        #[clap(flatten)]
+        command: UpdateDeps,
+    },
     #[clap(about = "Update the Leo CLI")]
     Update {
         #[clap(flatten)]
@@ -102,7 +145,8 @@ pub fn handle_error<T>(res: Result<T>) -> T {
 
 /// Run command with custom build arguments.
 pub fn run_with_args(cli: CLI) -> Result<()> {
-    if !cli.quiet {
+    // Note that the language server communicates over stdout, so nothing else may be written to it.
+    if !cli.quiet && !matches!(cli.command, Commands::Lsp { .. }) {
         // Init logger with optional debug flag.
         logger::init_logger("leo", match cli.debug {
             false => 1,
@@ -112,9 +156,9 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
 
     // Get custom root folder and create context for it.
     // If not specified, default context will be created in cwd.
-    let context = handle_error(Context::new(cli.path, cli.home));
+    let context = handle_error(Context::new(cli.path, cli.home, cli.message_format));
 
-    match cli.command {
+    let result = match cli.command {
         Commands::Add { command } => command.try_execute(context),
         Commands::Account { command } => command.try_execute(context),
         Commands::New { command } => command.try_execute(context),
@@ -134,23 +178,51 @@ pub fn run_with_args(cli: CLI) -> Result<()> {
 
             command.try_execute(context)
         }
+        Commands::Bindgen { command } => command.try_execute(context),
         Commands::Clean { command } => command.try_execute(context),
         Commands::Example { command } => command.try_execute(context),
         Commands::Run { command } => command.try_execute(context),
+        Commands::State { command } => command.try_execute(context),
+        Commands::Debug { command } => command.try_execute(context),
         Commands::Execute { command } => command.try_execute(context),
+        Commands::Fmt { command } => command.try_execute(context),
+        Commands::Lint { command } => command.try_execute(context),
+        Commands::Lsp { command } => command.try_execute(context),
+        Commands::Test { command } => command.try_execute(context),
+        Commands::UpdateDeps { command } => command.try_execute(context),
         Commands::Update { command } => command.try_execute(context),
+    };
+
+    // The error returned by a command is written in the same format as the errors emitted while running it.
+    match (cli.message_format, result) {
+        (MessageFormat::Json, Err(error)) => {
+            let handler = Handler::json();
+            handler.emit_err(error);
+            handler.last_err().map_err(|error| *error)
+        }
+        (_, result) => result,
     }
 }
 #[cfg(test)]
 mod tests {
     use crate::cli::{
         cli::{test_helpers, Commands},
+        commands::bindgen::Language,
+        context::Context,
+        formatter,
         run_with_args,
+        Analysis,
         CLI,
     };
+    use leo_ast::{Abi, AbiParameter};
+    use leo_errors::emitter::Handler;
     use leo_span::symbol::create_session_if_not_set_then;
+    use lsp_types::{Position, Range};
     use serial_test::serial;
-    use std::env::temp_dir;
+    use std::{
+        env::temp_dir,
+        path::{Path, PathBuf},
+    };
 
     #[test]
     #[serial]
@@ -171,11 +243,13 @@ mod tests {
                     name: "example".to_string(),
                     inputs: vec!["1u32".to_string(), "2u32".to_string()],
                     file: None,
+                    state: None,
                     compiler_options: Default::default(),
                 },
             },
             path: Some(project_directory.clone()),
             home: Some(temp_dir.join(".aleo")),
+            message_format: Default::default(),
         };
 
         create_session_if_not_set_then(|_| {
@@ -188,6 +262,93 @@ mod tests {
         // std::fs::remove_dir_all(project_directory).unwrap();
     }
 
+    #[test]
+    #[serial]
+    fn locked_network_dependency_build_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("nested");
+
+        // Create file structure
+        test_helpers::sample_nested_package(&temp_dir);
+
+        let build = |locked: bool| CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Build {
+                command: crate::cli::commands::Build {
+                    options: crate::cli::commands::BuildOptions { locked, ..Default::default() },
+                },
+            },
+            path: Some(project_directory.clone()),
+            home: Some(temp_dir.join(".aleo")),
+            message_format: Default::default(),
+        };
+        let update_deps = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::UpdateDeps {
+                command: crate::cli::commands::UpdateDeps { compiler_options: Default::default() },
+            },
+            path: Some(project_directory.clone()),
+            home: Some(temp_dir.join(".aleo")),
+            message_format: Default::default(),
+        };
+
+        create_session_if_not_set_then(|_| {
+            // The dependencies are missing from the new lock file.
+            let error = run_with_args(build(true)).expect_err("Expected `leo build --locked` to fail");
+            assert!(error.to_string().contains("is missing from the lock file"), "{error}");
+
+            // Building writes the lock file, which is then up to date.
+            run_with_args(build(false)).expect("Failed to execute `leo build`");
+            run_with_args(build(true)).expect("Failed to execute `leo build --locked`");
+
+            // A changed dependency is rejected until the lock file is updated.
+            let registry = temp_dir.join(".aleo").join("registry").join("testnet3");
+            let program = std::fs::read_to_string(registry.join("nested_example_layer_2.aleo")).unwrap();
+            std::fs::write(registry.join("nested_example_layer_2.aleo"), format!("{program}\n")).unwrap();
+            let error = run_with_args(build(false)).expect_err("Expected `leo build` to fail");
+            assert!(error.to_string().contains("The checksum of `nested_example_layer_2.aleo` does not match"));
+
+            run_with_args(update_deps).expect("Failed to execute `leo update-deps`");
+            run_with_args(build(true)).expect("Failed to execute `leo build --locked`");
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn git_dependency_build_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("git_main");
+
+        // Create file structure
+        test_helpers::sample_git_package(&temp_dir);
+
+        // Build program
+        let build = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Build { command: crate::cli::commands::Build { options: Default::default() } },
+            path: Some(project_directory.clone()),
+            home: Some(temp_dir.join(".aleo_git")),
+            message_format: Default::default(),
+        };
+
+        create_session_if_not_set_then(|_| {
+            run_with_args(build).expect("Failed to execute `leo build`");
+        });
+
+        // The library is checked out in the registry, compiled, and imported by the program.
+        let checkouts = std::fs::read_dir(temp_dir.join(".aleo_git").join("registry").join("git")).unwrap();
+        assert_eq!(checkouts.count(), 1);
+        let import = std::fs::read_to_string(project_directory.join("build").join("imports").join("git_library.aleo"));
+        assert!(import.unwrap().contains("function twice:"));
+        let lock_file = std::fs::read_to_string(project_directory.join("leo.lock")).unwrap();
+        assert!(lock_file.contains("location = \"git\""));
+    }
+
     #[test]
     #[serial]
     fn nested_local_dependency_run_test() {
@@ -216,11 +377,13 @@ mod tests {
                         "2u32".to_string(),
                     ],
                     file: None,
+                    state: None,
                     compiler_options: Default::default(),
                 },
             },
             path: Some(project_directory.clone()),
             home: None,
+            message_format: Default::default(),
         };
 
         create_session_if_not_set_then(|_| {
@@ -230,13 +393,512 @@ mod tests {
         // TODO: Clear tmp directory
         // std::fs::remove_dir_all(project_directory).unwrap();
     }
+
+    #[test]
+    #[serial]
+    fn failing_run_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("counter");
+
+        // Create file structure
+        test_helpers::sample_test_package(&temp_dir, "assert_eq(sum, 3u32);");
+
+        // Run a transition that overflows
+        let run = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Run {
+                command: crate::cli::commands::Run {
+                    name: "compute".to_string(),
+                    inputs: vec!["4294967295u32".to_string(), "1u32".to_string()],
+                    file: None,
+                    state: None,
+                    compiler_options: Default::default(),
+                },
+            },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        // The failure is traced back to the statement of the inline function that overflows.
+        create_session_if_not_set_then(|_| {
+            let error = run_with_args(run).expect_err("Expected `leo run` to fail").to_string();
+            assert!(error.contains("main.leo:6:16"), "{error}");
+            assert!(error.contains("return a + b;"), "{error}");
+        });
+        assert!(project_directory.join("build").join("main.aleo.map").exists());
+    }
+
+    #[test]
+    #[serial]
+    fn passing_tests_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("counter");
+
+        // Create file structure
+        test_helpers::sample_test_package(&temp_dir, "assert_eq(sum, 3u32);");
+
+        // Run tests
+        let test = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Test {
+                command: crate::cli::commands::Test { filter: None, compiler_options: Default::default() },
+            },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        create_session_if_not_set_then(|_| {
+            run_with_args(test).expect("Failed to execute `leo test`");
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn failing_tests_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("counter");
+
+        // Create file structure
+        test_helpers::sample_test_package(&temp_dir, "assert_eq(sum, 4u32);");
+
+        // Run tests
+        let test = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Test {
+                command: crate::cli::commands::Test { filter: None, compiler_options: Default::default() },
+            },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        create_session_if_not_set_then(|_| {
+            let error = run_with_args(test).expect_err("Expected `leo test` to fail");
+            assert!(error.to_string().contains("1 of 5 tests failed"));
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn debug_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("counter");
+
+        // Create file structure
+        test_helpers::sample_test_package(&temp_dir, "assert_eq(sum, 3u32);");
+
+        // Build the package, which `leo debug` does before interpreting it
+        let build = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Build { command: crate::cli::commands::Build { options: Default::default() } },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+        let debug = |name: &str, inputs: &[&str], breakpoints: Vec<usize>, commands: &str| {
+            let command = crate::cli::commands::Debug {
+                name: name.to_string(),
+                inputs: inputs.iter().map(|input| input.to_string()).collect(),
+                breakpoints,
+                compiler_options: Default::default(),
+            };
+            let context = Context::new(Some(project_directory.clone()), None, Default::default()).unwrap();
+            let mut output = Vec::new();
+            command.debug(context, commands.as_bytes(), &mut output).expect("Failed to execute `leo debug`");
+            String::from_utf8(output).unwrap()
+        };
+
+        create_session_if_not_set_then(|_| {
+            run_with_args(build).expect("Failed to execute `leo build`");
+
+            // Pause in the inline function, inspect its inputs, and run to the end.
+            let output = debug("compute", &["1u32", "2u32"], vec![6], "p a\nl\nc\n");
+            assert!(output.contains("main.leo:6 in `add_numbers`"), "{output}");
+            assert!(output.contains("6 |         return a + b;"), "{output}");
+            assert!(output.contains("a = 1u32\n"), "{output}");
+            assert!(output.contains("a = 1u32\nb = 2u32\n"), "{output}");
+            assert!(output.ends_with("Output: 3u32\n"), "{output}");
+
+            // Pause before the first statement, and step over the statements of the transition and its finalize block.
+            let output = debug("increment", &[], vec![], "n\nn\n\np count\nc\n");
+            assert!(output.contains("main.leo:14 in `increment`"), "{output}");
+            assert!(output.contains("main.leo:17 in `increment`"), "{output}");
+            assert!(output.contains("main.leo:19 in `increment`"), "{output}");
+            assert!(output.contains("count = 0u32"), "{output}");
+            assert!(output.ends_with("Output: ()\ncounts[0u8] = 1u32\n"), "{output}");
+
+            // Quitting stops the evaluation.
+            let output = debug("increment", &[], vec![], "q\n");
+            assert!(!output.contains("Output"), "{output}");
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn state_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("counter");
+
+        // Create file structure
+        test_helpers::sample_test_package(&temp_dir, "assert_eq(sum, 3u32);");
+
+        // Run `increment`, whose finalize block fails unless the count is `0u32`, against the mappings in `state/`
+        let run = || CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Run {
+                command: crate::cli::commands::Run {
+                    name: "increment".to_string(),
+                    inputs: vec![],
+                    file: None,
+                    state: Some(PathBuf::from("state")),
+                    compiler_options: Default::default(),
+                },
+            },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+        let show = |mapping: &str, key: Option<&str>| {
+            let command = crate::cli::commands::State::Show {
+                mapping: mapping.to_string(),
+                key: key.map(|key| key.to_string()),
+                state: PathBuf::from("state"),
+            };
+            let context = Context::new(Some(project_directory.clone()), None, Default::default()).unwrap();
+            let mut output = Vec::new();
+            command.show(context, &mut output).map(|_| String::from_utf8(output).unwrap())
+        };
+
+        create_session_if_not_set_then(|_| {
+            run_with_args(run()).expect("Failed to execute `leo run --state`");
+            assert!(project_directory.join("state").join("counter.aleo.json").exists());
+            assert_eq!(show("counts", None).unwrap(), "counts[0u8] = 1u32\n");
+            assert_eq!(show("counter.aleo/counts", Some("0u8")).unwrap(), "1u32\n");
+
+            // The count persists, so the finalize block now fails, and the mappings are left as they were.
+            let error = run_with_args(run()).expect_err("Expected `leo run --state` to fail").to_string();
+            assert!(error.contains("Failed to run the finalize block"), "{error}");
+            assert_eq!(show("counts", Some("0u8")).unwrap(), "1u32\n");
+
+            assert!(show("counts", Some("1u8")).unwrap_err().to_string().contains("The key `1u8` is not in"));
+            assert!(show("balances", None).unwrap_err().to_string().contains("is not stored in"));
+        });
+    }
+
+    #[test]
+    #[serial]
+    fn abi_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("token");
+        if project_directory.exists() {
+            std::fs::remove_dir_all(project_directory.clone()).unwrap();
+        }
+
+        // Create the token example, and build it
+        let example = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Example { command: crate::cli::commands::Example::Token },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+        let build = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Build { command: crate::cli::commands::Build { options: Default::default() } },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        create_session_if_not_set_then(|_| {
+            run_with_args(example).expect("Failed to execute `leo example`");
+            run_with_args(build).expect("Failed to execute `leo build`");
+        });
+
+        let abi = std::fs::read_to_string(project_directory.join("build").join("token.abi.json")).unwrap();
+        let abi: Abi = serde_json::from_str(&abi).unwrap();
+        let parameter = |name: Option<&str>, type_: &str, mode: Option<&str>| AbiParameter {
+            name: name.map(|name| name.to_string()),
+            type_: type_.to_string(),
+            mode: mode.map(|mode| mode.to_string()),
+        };
+        assert_eq!(abi.program, "token.aleo");
+        assert!(abi.structs.is_empty());
+        assert_eq!(abi.records.len(), 1);
+        assert_eq!(abi.records[0].fields, vec![
+            parameter(Some("owner"), "address", Some("private")),
+            parameter(Some("amount"), "u64", Some("private"))
+        ]);
+        assert_eq!(abi.mappings.len(), 1);
+        assert_eq!((abi.mappings[0].key.as_str(), abi.mappings[0].value.as_str()), ("address", "u64"));
+
+        let transition = |name: &str| abi.transitions.iter().find(|transition| transition.name == name).unwrap();
+        let mint_public = transition("mint_public");
+        assert_eq!(mint_public.inputs, vec![
+            parameter(Some("receiver"), "address", Some("public")),
+            parameter(Some("amount"), "u64", Some("public"))
+        ]);
+        assert!(mint_public.outputs.is_empty());
+        assert_eq!(
+            mint_public.finalize,
+            Some(vec![parameter(Some("receiver"), "address", None), parameter(Some("amount"), "u64", None)])
+        );
+        let transfer_private = transition("transfer_private");
+        assert_eq!(transfer_private.inputs, vec![
+            parameter(Some("sender"), "token", None),
+            parameter(Some("receiver"), "address", Some("private")),
+            parameter(Some("amount"), "u64", Some("private"))
+        ]);
+        assert_eq!(transfer_private.outputs, vec![parameter(None, "token", None), parameter(None, "token", None)]);
+        assert_eq!(transfer_private.finalize, None);
+    }
+
+    #[test]
+    #[serial]
+    fn bindgen_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("token");
+        if project_directory.exists() {
+            std::fs::remove_dir_all(project_directory.clone()).unwrap();
+        }
+
+        // Create the token example
+        let example = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Example { command: crate::cli::commands::Example::Token },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+        let bindgen = |lang: Language, program: Option<&str>| CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Bindgen {
+                command: crate::cli::commands::Bindgen {
+                    lang,
+                    program: program.map(PathBuf::from),
+                    output: None,
+                    compiler_options: Default::default(),
+                },
+            },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        create_session_if_not_set_then(|_| {
+            run_with_args(example).expect("Failed to execute `leo example`");
+            run_with_args(bindgen(Language::Rust, None)).expect("Failed to execute `leo bindgen --lang rust`");
+            run_with_args(bindgen(Language::Typescript, None))
+                .expect("Failed to execute `leo bindgen --lang typescript`");
+        });
+
+        let bindings = project_directory.join("build").join("bindings");
+        let rust = std::fs::read_to_string(bindings.join("token.rs")).unwrap();
+        assert!(
+            rust.contains(
+                "pub struct Token {\n    pub owner: String,\n    pub amount: u64,\n    pub _nonce: String,\n}"
+            )
+        );
+        assert!(rust.contains(
+            "format!(\"{{ owner: {}, amount: {}, _nonce: {}.public }}\", self.owner.to_entry(\"private\"), \
+             self.amount.to_entry(\"private\"), self._nonce)"
+        ));
+        assert!(rust.contains("pub mod transfer_private {"));
+        assert!(rust.contains("pub fn inputs(sender: Token, receiver: String, amount: u64) -> Vec<String> {"));
+        assert!(rust.contains("pub fn outputs(outputs: &[String]) -> Result<(Token, Token), String> {"));
+
+        let typescript = std::fs::read_to_string(bindings.join("token.ts")).unwrap();
+        assert!(
+            typescript.contains("export interface Token {\n  owner: string;\n  amount: bigint;\n  _nonce: string;\n}")
+        );
+        assert!(typescript.contains("export const transferPrivate = {"));
+        assert!(typescript.contains("inputs(sender: Token, receiver: string, amount: bigint): string[] {"));
+        assert!(typescript.contains("return [toAleoToken(sender), receiver, `${amount}u64`];"));
+        assert!(typescript.contains("outputs(outputs: string[]): [Token, Token] {"));
+
+        // Bindings to a built program are generated by disassembling it, in which its inputs are not named.
+        create_session_if_not_set_then(|_| {
+            run_with_args(bindgen(Language::Rust, Some("build/main.aleo"))).expect("Failed to execute `leo bindgen`");
+        });
+        let rust = std::fs::read_to_string(bindings.join("token.rs")).unwrap();
+        assert!(rust.contains("pub fn inputs(a1: Token, a2: String, a3: u64) -> Vec<String> {"));
+        assert!(rust.contains("self.owner.to_entry(\"private\")"));
+    }
+
+    #[test]
+    #[serial]
+    fn modules_build_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("shapes");
+
+        // Create file structure
+        test_helpers::sample_module_package(&temp_dir, "const UNIT: u32 = 10u32;");
+
+        // Build program
+        let build = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Build { command: crate::cli::commands::Build { options: Default::default() } },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        create_session_if_not_set_then(|_| {
+            run_with_args(build).expect("Failed to execute `leo build`");
+        });
+
+        // The items of the modules are compiled into the main program.
+        let bytecode = std::fs::read_to_string(project_directory.join("build").join("main.aleo")).unwrap();
+        assert!(bytecode.contains("struct Point:"));
+        assert!(bytecode.contains("10u32"));
+    }
+
+    #[test]
+    #[serial]
+    fn cyclic_modules_test() {
+        // Set current directory to temporary directory
+        let temp_dir = temp_dir();
+        let project_directory = temp_dir.join("shapes");
+
+        // Create file structure
+        test_helpers::sample_module_package(&temp_dir, "import geometry;\nconst UNIT: u32 = 10u32;");
+
+        // Build program
+        let build = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Build { command: crate::cli::commands::Build { options: Default::default() } },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        create_session_if_not_set_then(|_| {
+            let error = run_with_args(build).expect_err("Expected `leo build` to fail");
+            assert!(error.to_string().contains("geometry -> units -> geometry"));
+        });
+    }
+
+    #[test]
+    fn language_server_analysis_test() {
+        let source = r#"program test.aleo {
+    transition main(a: u32) -> u32 {
+        let b: u32 = a + 1u32;
+        let c: u8 = b;
+        return b;
+    }
+}
+"#;
+        let analysis = Analysis::new(source, Path::new("/leo/test/src/main.leo"));
+
+        // The assignment of a `u32` to a `u8` is reported at the expression.
+        assert_eq!(analysis.diagnostics.len(), 1);
+        assert_eq!(analysis.diagnostics[0].range, Range::new(Position::new(3, 20), Position::new(3, 21)));
+
+        // The type of `b` is shown when hovering over its use.
+        let (range, type_) = analysis.type_at(Position::new(4, 15)).expect("Expected a type for `b`");
+        assert_eq!(range, Range::new(Position::new(4, 15), Position::new(4, 16)));
+        assert_eq!(type_, "u32");
+
+        // The use of `b` refers to its definition, and the use of `a` refers to the input.
+        assert_eq!(
+            analysis.definition_at(Position::new(4, 15)),
+            Some(Range::new(Position::new(2, 12), Position::new(2, 13)))
+        );
+        assert_eq!(
+            analysis.definition_at(Position::new(2, 21)),
+            Some(Range::new(Position::new(1, 20), Position::new(1, 21)))
+        );
+    }
+
+    #[test]
+    fn format_test() {
+        let source = r#"// The program.
+import credits.aleo;
+program test.aleo {
+    struct Point { x: u32, y: u32 }
+
+    mapping counts: address=>u32;
+
+    // Adds the coordinates.
+    transition main(a: u32,b: u32) -> u32 {
+        let p: Point = Point{x:a,y:b}; // A point.
+        if (p.x > p.y) { return p.x + p.y * 2u32; } else if p.x == 0u32 { return (p.x + p.y) * 2u32; }
+        /* Otherwise. */
+        for i:u32 in 0u32..4u32 { assert_neq(i, a); }
+        for x:u32 in [a,b] { for j:u8 in (8u8..=0u8).step(2u8) { assert_neq(x, 5u32); } }
+        return a.add(b);
+    }
+}
+"#;
+        let expected = r#"// The program.
+import credits.aleo;
+program test.aleo {
+    struct Point {
+        x: u32,
+        y: u32,
+    }
+
+    mapping counts: address => u32;
+
+    // Adds the coordinates.
+    transition main(a: u32, b: u32) -> u32 {
+        let p: Point = Point { x: a, y: b }; // A point.
+        if p.x > p.y {
+            return p.x + p.y * 2u32;
+        } else if p.x == 0u32 {
+            return (p.x + p.y) * 2u32;
+        }
+        /* Otherwise. */
+        for i: u32 in 0u32..4u32 {
+            assert_neq(i, a);
+        }
+        for x: u32 in [a, b] {
+            for j: u8 in (8u8..=0u8).step(2u8) {
+                assert_neq(x, 5u32);
+            }
+        }
+        return a.add(b);
+    }
+}
+"#;
+        create_session_if_not_set_then(|_| {
+            let handler = Handler::default();
+            let formatted = formatter::format(source, Path::new("main.leo"), &handler).expect("Failed to format");
+            assert_eq!(formatted, expected);
+
+            // Formatting is idempotent.
+            let reformatted = formatter::format(&formatted, Path::new("main.leo"), &handler).expect("Failed to format");
+            assert_eq!(reformatted, expected);
+        });
+    }
 }
 
 #[cfg(test)]
 mod test_helpers {
     use crate::cli::{cli::Commands, run_with_args, Add, New, CLI};
     use leo_span::symbol::create_session_if_not_set_then;
-    use std::path::Path;
+    use std::path::{Path, PathBuf};
 
     pub(crate) fn sample_nested_package(temp_dir: &Path) {
         let name = "nested";
@@ -254,6 +916,7 @@ mod test_helpers {
             command: Commands::New { command: New { name: name.to_string() } },
             path: Some(project_directory.clone()),
             home: None,
+            message_format: Default::default(),
         };
 
         create_session_if_not_set_then(|_| {
@@ -320,10 +983,16 @@ function external_nested_function:
                     name: "nested_example_layer_0".to_string(),
                     local: None,
                     network: "testnet3".to_string(),
+                    git: None,
+                    rev: None,
+                    tag: None,
+                    branch: None,
+                    subdirectory: None,
                 },
             },
             path: Some(project_directory.clone()),
             home: None,
+            message_format: Default::default(),
         };
 
         create_session_if_not_set_then(|_| {
@@ -354,6 +1023,7 @@ function external_nested_function:
             command: Commands::New { command: New { name: "grandparent".to_string() } },
             path: Some(grandparent_directory.clone()),
             home: None,
+            message_format: Default::default(),
         };
 
         let create_parent_project = CLI {
@@ -362,6 +1032,7 @@ function external_nested_function:
             command: Commands::New { command: New { name: "parent".to_string() } },
             path: Some(parent_directory.clone()),
             home: None,
+            message_format: Default::default(),
         };
 
         let create_child_project = CLI {
@@ -370,6 +1041,7 @@ function external_nested_function:
             command: Commands::New { command: New { name: "child".to_string() } },
             path: Some(child_directory.clone()),
             home: None,
+            message_format: Default::default(),
         };
 
         // Add source files `grandparent/src/main.leo`, `grandparent/parent/src/main.leo`, and `grandparent/parent/child/src/main.leo`
@@ -413,10 +1085,16 @@ program child.aleo {
                     name: "parent".to_string(),
                     local: Some(parent_directory.clone()),
                     network: "testnet3".to_string(),
+                    git: None,
+                    rev: None,
+                    tag: None,
+                    branch: None,
+                    subdirectory: None,
                 },
             },
             path: Some(grandparent_directory.clone()),
             home: None,
+            message_format: Default::default(),
         };
 
         let add_grandparent_dependency_2 = CLI {
@@ -427,10 +1105,16 @@ program child.aleo {
                     name: "child".to_string(),
                     local: Some(child_directory.clone()),
                     network: "testnet3".to_string(),
+                    git: None,
+                    rev: None,
+                    tag: None,
+                    branch: None,
+                    subdirectory: None,
                 },
             },
             path: Some(grandparent_directory.clone()),
             home: None,
+            message_format: Default::default(),
         };
 
         let add_parent_dependency = CLI {
@@ -441,10 +1125,16 @@ program child.aleo {
                     name: "child".to_string(),
                     local: Some(child_directory.clone()),
                     network: "testnet3".to_string(),
+                    git: None,
+                    rev: None,
+                    tag: None,
+                    branch: None,
+                    subdirectory: None,
                 },
             },
             path: Some(parent_directory.clone()),
             home: None,
+            message_format: Default::default(),
         };
 
         // Execute all commands
@@ -465,4 +1155,263 @@ program child.aleo {
             run_with_args(add_parent_dependency).unwrap();
         });
     }
+
+    pub(crate) fn sample_test_package(temp_dir: &Path, assertion: &str) {
+        let name = "counter";
+
+        // Remove it if it already exists
+        let project_directory = temp_dir.join(name);
+        if project_directory.exists() {
+            std::fs::remove_dir_all(project_directory.clone()).unwrap();
+        }
+
+        // Create new Leo project
+        let new = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::New { command: New { name: name.to_string() } },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        // The tests in `src/main.leo` are part of the program, and `tests/counter_test.leo` imports it.
+        let program = format!(
+            "
+program counter.aleo {{
+    mapping counts: u8 => u32;
+
+    inline add_numbers(a: u32, b: u32) -> u32 {{
+        return a + b;
+    }}
+
+    transition compute(a: u32, b: u32) -> u32 {{
+        return add_numbers(a, b);
+    }}
+
+    transition increment() {{
+        return then finalize();
+    }}
+    finalize increment() {{
+        let count: u32 = Mapping::get_or_use(counts, 0u8, 0u32);
+        Mapping::set(counts, 0u8, count + 1u32);
+        assert_eq(count, 0u32);
+    }}
+
+    @test
+    transition test_add() {{
+        let sum: u32 = add_numbers(1u32, 2u32);
+        {assertion}
+    }}
+
+    @test
+    @should_fail
+    transition test_overflow() {{
+        return then finalize();
+    }}
+    finalize test_overflow() {{
+        let count: u32 = Mapping::get_or_use(counts, 1u8, 4294967295u32);
+        Mapping::set(counts, 1u8, count + 1u32);
+    }}
+
+    @test
+    transition test_increment() {{
+        return then finalize();
+    }}
+    finalize test_increment() {{
+        let count: u32 = Mapping::get_or_use(counts, 0u8, 0u32);
+        Mapping::set(counts, 0u8, count + 1u32);
+        assert_eq(count, 0u32);
+    }}
+
+    @test
+    transition test_increment_again() {{
+        return then finalize();
+    }}
+    finalize test_increment_again() {{
+        let count: u32 = Mapping::get_or_use(counts, 0u8, 0u32);
+        Mapping::set(counts, 0u8, count + 1u32);
+        assert_eq(count, 0u32);
+    }}
+}}
+"
+        );
+        let test_program = "
+import counter.aleo;
+program counter_test.aleo {
+    @test
+    transition test_external_add() {
+        let sum: u32 = counter.aleo/compute(2u32, 3u32);
+        assert_eq(sum, 5u32);
+    }
+}
+";
+
+        // Execute all commands
+        create_session_if_not_set_then(|_| {
+            // Create project
+            run_with_args(new).unwrap();
+
+            // Write files
+            std::fs::write(project_directory.join("src").join("main.leo"), program).unwrap();
+            std::fs::create_dir_all(project_directory.join("tests")).unwrap();
+            std::fs::write(project_directory.join("tests").join("counter_test.leo"), test_program).unwrap();
+        });
+    }
+
+    pub(crate) fn sample_module_package(temp_dir: &Path, units_module: &str) {
+        let name = "shapes";
+
+        // Remove it if it already exists
+        let project_directory = temp_dir.join(name);
+        if project_directory.exists() {
+            std::fs::remove_dir_all(project_directory.clone()).unwrap();
+        }
+
+        // Create new Leo project
+        let new = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::New { command: New { name: name.to_string() } },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        // `src/main.leo` imports the `geometry` module, which imports the `units` module.
+        let program = "
+import geometry;
+program shapes.aleo {
+    transition area(a: u32, b: u32) -> u32 {
+        let p: Point = Point { x: a, y: b };
+        return scale(p.x * p.y);
+    }
+}
+";
+        let geometry_module = "
+import units;
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+inline scale(value: u32) -> u32 {
+    return value * UNIT;
+}
+";
+
+        // Execute all commands
+        create_session_if_not_set_then(|_| {
+            // Create project
+            run_with_args(new).unwrap();
+
+            // Write files
+            let source_directory = project_directory.join("src");
+            std::fs::write(source_directory.join("main.leo"), program).unwrap();
+            std::fs::write(source_directory.join("geometry.leo"), geometry_module).unwrap();
+            std::fs::write(source_directory.join("units.leo"), units_module).unwrap();
+        });
+    }
+
+    pub(crate) fn sample_git_package(temp_dir: &Path) {
+        let repository_directory = temp_dir.join("git_library");
+        let library_directory = repository_directory.join("programs").join("git_library");
+        let bare_directory = temp_dir.join("git_library.git");
+        let project_directory = temp_dir.join("git_main");
+
+        // Remove them if they already exist
+        for directory in [&repository_directory, &bare_directory, &project_directory, &temp_dir.join(".aleo_git")] {
+            if directory.exists() {
+                std::fs::remove_dir_all(directory).unwrap();
+            }
+        }
+
+        // Create the library in a subdirectory of a repository, and the project that depends on it
+        let create_library_project = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::New { command: New { name: "git_library".to_string() } },
+            path: Some(library_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        let create_main_project = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::New { command: New { name: "git_main".to_string() } },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        let library_program = "
+program git_library.aleo {
+    transition twice(a: u32) -> u32 {
+        return a + a;
+    }
+}
+";
+        let main_program = "
+import git_library.aleo;
+program git_main.aleo {
+    transition quadruple(a: u32) -> u32 {
+        let b: u32 = git_library.aleo/twice(a);
+        return git_library.aleo/twice(b);
+    }
+}
+";
+
+        // Commit the library, and publish it to a bare repository
+        let git = |directory: &Path, args: &[&str]| {
+            let output = std::process::Command::new("git")
+                .args(["-c", "user.name=leo", "-c", "user.email=leo@aleo.org"])
+                .args(args)
+                .current_dir(directory)
+                .output()
+                .expect("Failed to run `git`");
+            assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+            String::from_utf8_lossy(&output.stdout).trim().to_string()
+        };
+
+        std::fs::create_dir_all(repository_directory.join("programs")).unwrap();
+        create_session_if_not_set_then(|_| {
+            run_with_args(create_library_project).unwrap();
+            run_with_args(create_main_project).unwrap();
+        });
+        std::fs::write(library_directory.join("src").join("main.leo"), library_program).unwrap();
+        std::fs::write(project_directory.join("src").join("main.leo"), main_program).unwrap();
+
+        git(&repository_directory, &["init", "--quiet"]);
+        git(&repository_directory, &["add", "."]);
+        git(&repository_directory, &["commit", "--quiet", "-m", "Add git_library"]);
+        let rev = git(&repository_directory, &["rev-parse", "HEAD"]);
+        git(temp_dir, &["clone", "--quiet", "--bare", "git_library", "git_library.git"]);
+
+        // Add the dependency at the published commit
+        let add = CLI {
+            debug: false,
+            quiet: false,
+            command: Commands::Add {
+                command: Add {
+                    name: "git_library".to_string(),
+                    local: None,
+                    network: "testnet3".to_string(),
+                    git: Some(format!("file://{}", bare_directory.display())),
+                    rev: Some(rev),
+                    tag: None,
+                    branch: None,
+                    subdirectory: Some(PathBuf::from("programs/git_library")),
+                },
+            },
+            path: Some(project_directory.clone()),
+            home: None,
+            message_format: Default::default(),
+        };
+
+        create_session_if_not_set_then(|_| {
+            run_with_args(add).expect("Failed to execute `leo add`");
+        });
+    }
 }
@@ -15,7 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::*;
-use retriever::{Dependency, Location, Manifest, Network};
+use retriever::{Dependency, GitSource, Location, Manifest, Network};
 use std::path::PathBuf;
 
 /// Clean outputs folder command
@@ -30,6 +30,21 @@ pub struct Add {
 
     #[clap(short = 'n', long, help = "Optional name of the network to use", default_value = "testnet3")]
     pub(crate) network: String,
+
+    #[clap(short = 'g', long, help = "Optional url of the Git repository of the dependency", conflicts_with = "local")]
+    pub(crate) git: Option<String>,
+
+    #[clap(long, help = "The commit of the Git dependency to use", requires = "git", conflicts_with_all = ["tag", "branch"])]
+    pub(crate) rev: Option<String>,
+
+    #[clap(long, help = "The tag of the Git dependency to use", requires = "git", conflicts_with = "branch")]
+    pub(crate) tag: Option<String>,
+
+    #[clap(long, help = "The branch of the Git dependency to use", requires = "git")]
+    pub(crate) branch: Option<String>,
+
+    #[clap(long, help = "Optional path to the dependency within its Git repository", requires = "git")]
+    pub(crate) subdirectory: Option<PathBuf>,
 }
 
 impl Command for Add {
@@ -76,9 +91,12 @@ impl Command for Add {
         };
 
         // Add new dependency to manifest
-        dependencies.push(match self.local {
-            Some(local_path) => Dependency::new(name, Location::Local, None, Some(local_path)),
-            None => Dependency::new(name, Location::Network, Some(Network::from(&self.network)), None),
+        dependencies.push(match (self.local, self.git) {
+            (Some(local_path), _) => Dependency::new(name, Location::Local, None, Some(local_path)),
+            (None, Some(url)) => {
+                Dependency::new_git(name, GitSource::new(url, self.rev, self.tag, self.branch), self.subdirectory)
+            }
+            (None, None) => Dependency::new(name, Location::Network, Some(Network::from(&self.network)), None),
         });
 
         // Update manifest
@@ -88,7 +106,8 @@ impl Command for Add {
             manifest.description(),
             manifest.license(),
             Some(dependencies),
-        );
+        )
+        .with_lints(manifest.lints().clone());
         let new_manifest_data = serde_json::to_string_pretty(&new_manifest)
             .map_err(|err| PackageError::failed_to_serialize_manifest_file(path.to_str().unwrap(), err))?;
         std::fs::write(path.join("program.json"), new_manifest_data).map_err(PackageError::failed_to_write_manifest)?;
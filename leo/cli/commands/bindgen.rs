@@ -0,0 +1,108 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::cli::helpers::bindings;
+
+use disassembler::disassemble;
+use leo_ast::Abi;
+
+use snarkvm::prelude::Program;
+
+use std::str::FromStr;
+
+/// The languages that bindings can be generated in.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    Typescript,
+}
+
+/// Generate typed client bindings to a program
+#[derive(Parser, Debug)]
+pub struct Bindgen {
+    #[arg(long, value_enum, help = "The language of the bindings.")]
+    pub(crate) lang: Language,
+
+    #[clap(
+        name = "PROGRAM",
+        help = "An Aleo program to generate bindings to, e.g. `build/imports/token.aleo`. Defaults to the program of the current package, which is built first."
+    )]
+    pub(crate) program: Option<PathBuf>,
+
+    #[arg(short, long, help = "The file to write the bindings to. Defaults to `build/bindings/<program>.<rs|ts>`.")]
+    pub(crate) output: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Bindgen {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        if self.program.is_none() {
+            (Build { options: self.compiler_options.clone() }).execute(context)?;
+        }
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+
+        // Describe the program, either by disassembling it, or from the ABI written when the package was built.
+        let abi = match &self.program {
+            Some(path) => {
+                let path = package_path.join(path);
+                let source = std::fs::read_to_string(&path)
+                    .map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+                let program =
+                    Program::<CurrentNetwork>::from_str(&source).map_err(CliError::failed_to_generate_bindings)?;
+                Abi::from(&disassemble(program))
+            }
+            None => {
+                let program_id = *context.open_manifest()?.program_id();
+                let path = BuildDirectory::open(&package_path)?.join(format!("{}.abi.json", program_id.name()));
+                let abi = std::fs::read_to_string(&path)
+                    .map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+                serde_json::from_str(&abi).map_err(CliError::failed_to_generate_bindings)?
+            }
+        };
+
+        let (bindings, extension) = match self.lang {
+            Language::Rust => (bindings::rust(&abi), "rs"),
+            Language::Typescript => (bindings::typescript(&abi), "ts"),
+        };
+        let program_name = abi.program.split('.').next().unwrap_or(&abi.program);
+        let output = match &self.output {
+            Some(output) => package_path.join(output),
+            None => package_path.join("build").join("bindings").join(format!("{program_name}.{extension}")),
+        };
+        if let Some(directory) = output.parent() {
+            std::fs::create_dir_all(directory).map_err(CliError::failed_to_write_file)?;
+        }
+        std::fs::write(&output, bindings).map_err(CliError::failed_to_write_file)?;
+
+        tracing::info!("✅ Generated bindings to '{}' in '{}'", abi.program, output.display());
+        Ok(())
+    }
+}
@@ -16,12 +16,12 @@
 
 use super::*;
 
-use leo_ast::Stub;
-use leo_compiler::{Compiler, CompilerOptions, OutputOptions};
+use leo_ast::{Abi, Stub};
+use leo_compiler::{Compiler, CompilerOptions, LintConfig, OutputOptions};
 use leo_errors::UtilError;
 use leo_package::{build::BuildDirectory, outputs::OutputsDirectory, source::SourceDirectory};
 use leo_span::Symbol;
-use retriever::Retriever;
+use retriever::{HttpBackend, LockMode, Manifest, MirrorBackend, Registry, Retriever, VendoredBackend};
 
 use snarkvm::{
     package::Package,
@@ -39,7 +39,12 @@ type CurrentNetwork = Testnet3;
 impl From<BuildOptions> for CompilerOptions {
     fn from(options: BuildOptions) -> Self {
         let mut out_options = Self {
-            build: leo_compiler::BuildOptions { dce_enabled: options.enable_dce },
+            build: leo_compiler::BuildOptions {
+                dce_enabled: options.enable_dce,
+                tests_enabled: false,
+                check_privacy: options.check_privacy,
+                lints: None,
+            },
             output: OutputOptions {
                 symbol_table_spans_enabled: options.enable_symbol_table_spans,
                 initial_symbol_table: options.enable_initial_symbol_table_snapshot,
@@ -49,6 +54,7 @@ impl From<BuildOptions> for CompilerOptions {
                 initial_ast: options.enable_initial_ast_snapshot,
                 unrolled_ast: options.enable_unrolled_ast_snapshot,
                 ssa_ast: options.enable_ssa_ast_snapshot,
+                const_folded_ast: options.enable_const_folded_ast_snapshot,
                 flattened_ast: options.enable_flattened_ast_snapshot,
                 destructured_ast: options.enable_destructured_ast_snapshot,
                 inlined_ast: options.enable_inlined_ast_snapshot,
@@ -59,6 +65,7 @@ impl From<BuildOptions> for CompilerOptions {
             out_options.output.initial_ast = true;
             out_options.output.unrolled_ast = true;
             out_options.output.ssa_ast = true;
+            out_options.output.const_folded_ast = true;
             out_options.output.flattened_ast = true;
             out_options.output.destructured_ast = true;
             out_options.output.inlined_ast = true;
@@ -101,12 +108,17 @@ impl Command for Build {
         let program_id = manifest.program_id();
 
         // Initialize error handler
-        let handler = Handler::default();
+        let handler = context.handler();
 
         // Retrieve all local dependencies in post order
         let main_sym = Symbol::intern(&program_id.name().to_string());
-        let mut retriever = Retriever::new(main_sym, &package_path, &home_path)
-            .map_err(|err| UtilError::failed_to_retrieve_dependencies(err, Default::default()))?;
+        let mut retriever = Retriever::new(
+            main_sym,
+            &package_path,
+            self.options.registry(&package_path, &home_path),
+            self.options.lock_mode(),
+        )
+        .map_err(|err| UtilError::failed_to_retrieve_dependencies(err, Default::default()))?;
         let mut local_dependencies =
             retriever.retrieve().map_err(|err| UtilError::failed_to_retrieve_dependencies(err, Default::default()))?;
 
@@ -127,22 +139,27 @@ impl Command for Build {
             // Fetch paths to all .leo files in the source directory.
             let local_source_files = SourceDirectory::files(&local_path)?;
 
-            // Check the source files.
-            SourceDirectory::check_files(&local_source_files)?;
-
-            // Compile all .leo files into .aleo files.
-            for file_path in local_source_files {
-                compile_leo_file(
-                    file_path,
-                    &ProgramID::<Testnet3>::try_from(format!("{}.aleo", dependency))
-                        .map_err(|_| UtilError::snarkvm_error_building_program_id(Default::default()))?,
-                    &local_outputs_directory,
-                    &local_build_directory,
-                    &handler,
-                    self.options.clone(),
-                    stubs.clone(),
-                )?;
-            }
+            // Check the source files, and find the main file.
+            let main_file_path = SourceDirectory::check_files(&local_source_files)?;
+
+            // Read the lints configured in the package's `program.json`, if linting is enabled.
+            let lints = match self.options.lint {
+                true => Some(read_lint_config(&local_path)?),
+                false => None,
+            };
+
+            // Compile the main file into an .aleo file, along with the modules it imports.
+            compile_leo_file(
+                main_file_path,
+                &ProgramID::<Testnet3>::try_from(format!("{}.aleo", dependency))
+                    .map_err(|_| UtilError::snarkvm_error_building_program_id(Default::default()))?,
+                &local_outputs_directory,
+                &local_build_directory,
+                &handler,
+                self.options.clone(),
+                lints,
+                stubs,
+            )?;
 
             // Writes `leo.lock` as well as caches objects (when target is an intermediate dependency)
             retriever.process_local(dependency)?;
@@ -173,6 +190,39 @@ impl Command for Build {
     }
 }
 
+impl BuildOptions {
+    /// Returns the registry to retrieve network dependencies from.
+    /// Programs vendored in `imports/` take precedence over the `~/.aleo/registry` cache, which takes precedence over
+    /// the mirror. The network is only queried last, and never in offline mode.
+    fn registry(&self, package_path: &Path, home_path: &Path) -> Registry {
+        let cache_path = home_path.join("registry");
+        let mut registry = Registry::new(&cache_path)
+            .with_backend(VendoredBackend::new(package_path))
+            .with_backend(MirrorBackend::new(&cache_path));
+        if let Some(mirror) = &self.mirror {
+            registry = registry.with_backend(MirrorBackend::new(mirror));
+        }
+        if !self.offline {
+            let http = self
+                .endpoint
+                .iter()
+                .cloned()
+                .fold(HttpBackend::default(), |http, (network, endpoint)| http.with_endpoint(network, endpoint));
+            registry = registry.with_backend(http);
+        }
+        registry
+    }
+
+    /// Returns how the existing `leo.lock` file is treated.
+    fn lock_mode(&self) -> LockMode {
+        match (self.update_lock_file, self.locked) {
+            (true, _) => LockMode::Update,
+            (false, true) => LockMode::Locked,
+            (false, false) => LockMode::Verify,
+        }
+    }
+}
+
 /// Compiles a Leo file in the `src/` directory.
 #[allow(clippy::too_many_arguments)]
 fn compile_leo_file(
@@ -182,6 +232,7 @@ fn compile_leo_file(
     build: &Path,
     handler: &Handler,
     options: BuildOptions,
+    lints: Option<LintConfig>,
     stubs: IndexMap<Symbol, Stub>,
 ) -> Result<()> {
     // Construct the Leo file name with extension `foo.leo`.
@@ -195,6 +246,10 @@ fn compile_leo_file(
     let mut aleo_file_path = build.to_path_buf();
     aleo_file_path.push(format!("main.{}", program_id.network()));
 
+    // Enable the lints configured for the package.
+    let mut options: CompilerOptions = options.into();
+    options.build.lints = lints;
+
     // Create a new instance of the Leo compiler.
     let mut compiler = Compiler::new(
         program_name,
@@ -202,7 +257,7 @@ fn compile_leo_file(
         handler,
         file_path.clone(),
         outputs.to_path_buf(),
-        Some(options.into()),
+        Some(options),
         stubs,
     );
 
@@ -215,6 +270,34 @@ fn compile_leo_file(
         .write_all(instructions.as_bytes())
         .map_err(CliError::failed_to_load_instructions)?;
 
+    // Write the source map, which relates the instructions to the Leo statements they were generated from.
+    let source_map = serde_json::to_string_pretty(&compiler.instruction_spans.to_source_map())
+        .map_err(CliError::failed_to_write_source_map)?;
+    std::fs::write(build.join(format!("main.{}.map", program_id.network())), source_map)
+        .map_err(CliError::failed_to_write_source_map)?;
+
+    // Write the ABI, which describes the interface of the program.
+    let abi = serde_json::to_string_pretty(&Abi::from(&compiler.ast.ast)).map_err(CliError::failed_to_write_abi)?;
+    std::fs::write(build.join(format!("{}.abi.json", program_id.name())), abi)
+        .map_err(CliError::failed_to_write_abi)?;
+
     tracing::info!("✅ Compiled '{}' into Aleo instructions", file_name);
     Ok(())
 }
+
+/// Reads the lints enabled or disabled under `lints` in the `program.json` of the package at `path`.
+fn read_lint_config(path: &Path) -> Result<LintConfig> {
+    let manifest_path = path.join("program.json");
+    let manifest_data = std::fs::read_to_string(&manifest_path)
+        .map_err(|err| PackageError::failed_to_read_file(manifest_path.display(), err))?;
+    let manifest: Manifest = serde_json::from_str(&manifest_data)
+        .map_err(|err| PackageError::failed_to_deserialize_manifest_file(manifest_path.display(), err))?;
+
+    let mut config = LintConfig::default();
+    for (name, enabled) in manifest.lints() {
+        let lint = leo_compiler::Lint::from_name(name)
+            .ok_or_else(|| CliError::unknown_lint(name, leo_compiler::Lint::names()))?;
+        config.set(lint, *enabled);
+    }
+    Ok(config)
+}
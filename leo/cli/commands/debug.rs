@@ -0,0 +1,143 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::cli::helpers::debugger::Debugger;
+
+use disassembler::disassemble;
+use leo_compiler::{Compiler, CompilerOptions};
+use leo_interpreter::Interpreter;
+use leo_package::source::SourceDirectory;
+use leo_span::Symbol;
+
+use snarkvm::prelude::{Address, PrivateKey, Program};
+
+use indexmap::IndexMap;
+use std::{
+    io::{BufRead, Write},
+    path::Path,
+    str::FromStr,
+};
+
+/// Debug a transition of the current package with the Leo interpreter
+#[derive(Parser, Debug)]
+pub struct Debug {
+    #[clap(name = "NAME", help = "The name of the transition to debug.")]
+    pub(crate) name: String,
+
+    #[clap(name = "INPUTS", help = "The inputs to the transition.")]
+    pub(crate) inputs: Vec<String>,
+
+    #[arg(
+        short = 'b',
+        long = "break",
+        value_name = "LINE",
+        help = "Pause before the statements on a line of the program. Pauses before the first statement if omitted."
+    )]
+    pub(crate) breakpoints: Vec<usize>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for Debug {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let stdin = std::io::stdin();
+        self.debug(context, stdin.lock(), std::io::stdout())
+    }
+}
+
+impl Debug {
+    /// Interprets the transition of the built package, reading the debugger's commands from `input`.
+    pub(crate) fn debug(&self, context: Context, input: impl BufRead, output: impl Write) -> Result<()> {
+        let package_path = context.dir()?;
+        let build_directory = BuildDirectory::open(&package_path)?;
+        let outputs_directory = OutputsDirectory::create(&package_path)?;
+        let handler = context.handler();
+
+        // Type check the main program against the stubs of its imports, which have just been built.
+        let main_program = load_program(&build_directory.join("main.aleo"))?;
+        let mut stubs = IndexMap::new();
+        for import_id in main_program.imports().keys() {
+            let path = build_directory.join("imports").join(import_id.to_string());
+            if path.exists() {
+                let stub = disassemble(load_program(&path)?);
+                stubs.insert(stub.stub_id.name.name, stub);
+            }
+        }
+        let main_file_path = SourceDirectory::check_files(&SourceDirectory::files(&package_path)?)?;
+        let mut compiler = Compiler::new(
+            main_program.id().name().to_string(),
+            main_program.id().network().to_string(),
+            &handler,
+            main_file_path,
+            outputs_directory,
+            Some(CompilerOptions::from(self.compiler_options.clone())),
+            stubs,
+        );
+        compiler.check()?;
+
+        let mut debugger = Debugger::new(input, output, self.breakpoints.iter().copied());
+        let result = Interpreter::new(&compiler.ast.ast).and_then(|interpreter| {
+            let mut interpreter = interpreter.with_caller(caller(&package_path)).with_hook(&mut debugger);
+            let output = interpreter.run(Symbol::intern(&self.name), &self.inputs)?;
+            Ok((output, interpreter.mappings().clone()))
+        });
+        let (value, mappings) = match result {
+            Ok(result) => result,
+            Err(_) if debugger.quit() => return Ok(()),
+            Err(error) => return Err(error),
+        };
+
+        let output = debugger.output();
+        writeln!(output, "Output: {value}").map_err(CliError::cli_io_error)?;
+        for (mapping, entries) in mappings.iter() {
+            for (key, value) in entries.iter() {
+                writeln!(output, "{mapping}[{key}] = {value}").map_err(CliError::cli_io_error)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads and parses an Aleo program.
+fn load_program(path: &Path) -> Result<Program<CurrentNetwork>> {
+    let source = std::fs::read_to_string(path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+    Ok(Program::from_str(&source).map_err(CliError::failed_to_debug)?)
+}
+
+/// Returns the address of the private key in the package's `.env` file, which is the caller for `leo run`.
+/// The zero address is used if there is no such key.
+fn caller(package_path: &Path) -> Address<CurrentNetwork> {
+    dotenvy::from_path_iter(package_path.join(".env"))
+        .ok()
+        .and_then(|variables| variables.flatten().find(|(name, _)| name == "PRIVATE_KEY"))
+        .and_then(|(_, private_key)| PrivateKey::<CurrentNetwork>::from_str(&private_key).ok())
+        .and_then(|private_key| Address::try_from(private_key).ok())
+        .unwrap_or_else(Address::zero)
+}
@@ -16,6 +16,8 @@
 
 use super::*;
 
+use crate::cli::helpers::{evaluator::halting, source_map::annotate_failure};
+
 use snarkvm::{cli::Execute as SnarkVMExecute, prelude::Parser as SnarkVMParser};
 
 /// Build, Prove and Run Leo program with inputs
@@ -58,7 +60,7 @@ impl Command for Execute {
         let mut inputs = self.inputs;
 
         // Compose the `execute` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
+        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name.clone()];
 
         // Add the inputs to the arguments.
         match self.file {
@@ -86,6 +88,9 @@ impl Command for Execute {
             None => arguments.append(&mut inputs),
         }
 
+        // Keep the inputs, which follow the function name, to locate the failing statement if the program fails.
+        let inputs = arguments[2..].to_vec();
+
         // Add the compiler options to the arguments.
         if self.compiler_options.offline {
             arguments.push(String::from("--offline"));
@@ -109,7 +114,9 @@ impl Command for Execute {
         // Call the `execute` command.
         println!();
         let command = SnarkVMExecute::try_parse_from(&arguments).map_err(CliError::failed_to_parse_execute)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_execute)?;
+        let res = halting(|| command.parse()).map_err(|error| {
+            CliError::failed_to_execute_execute(annotate_failure(&build_directory, &self.name, &inputs, error))
+        })?;
 
         // Log the output of the `execute` command.
         tracing::info!("{}", res);
@@ -0,0 +1,84 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::cli::formatter;
+
+use leo_package::{source::SourceDirectory, tests::TestsDirectory};
+
+use std::path::PathBuf;
+
+/// Format the Leo files of the current package
+#[derive(Parser, Debug)]
+pub struct Fmt {
+    #[clap(name = "FILES", help = "The files to format. Defaults to the files in `src/` and `tests/`.")]
+    pub(crate) files: Vec<PathBuf>,
+
+    #[clap(long, help = "Check that the files are formatted, without modifying them.")]
+    pub(crate) check: bool,
+}
+
+impl Command for Fmt {
+    type Input = ();
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let files = match self.files.is_empty() {
+            true => {
+                let package_path = context.dir()?;
+                let mut files = SourceDirectory::files(&package_path)?;
+                files.extend(TestsDirectory::files(&package_path)?);
+                files
+            }
+            false => self.files,
+        };
+
+        let handler = context.handler();
+        let mut unformatted = 0;
+        for path in files {
+            let source =
+                std::fs::read_to_string(&path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+            let formatted = formatter::format(&source, &path, &handler)?;
+            if formatted == source {
+                continue;
+            }
+            match self.check {
+                true => {
+                    tracing::info!("❌ '{}' is not formatted", path.display());
+                    unformatted += 1;
+                }
+                false => {
+                    std::fs::write(&path, formatted).map_err(CliError::failed_to_write_file)?;
+                    tracing::info!("✅ Formatted '{}'", path.display());
+                }
+            }
+        }
+
+        match unformatted {
+            0 => Ok(()),
+            _ => Err(CliError::unformatted_files(unformatted).into()),
+        }
+    }
+}
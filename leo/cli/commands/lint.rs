@@ -0,0 +1,41 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+use super::*;
+
+/// Check the current package against the lints configured in `program.json`
+#[derive(Parser, Debug)]
+pub struct Lint {
+    #[clap(flatten)]
+    pub(crate) options: BuildOptions,
+}
+
+impl Command for Lint {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        // Lint warnings are reported while building each program of the package.
+        (Build { options: BuildOptions { lint: true, ..self.options.clone() } }).execute(context)
+    }
+
+    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+        Ok(())
+    }
+}
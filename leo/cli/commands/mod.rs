@@ -20,18 +20,33 @@ pub use add::Add;
 pub mod account;
 pub use account::Account;
 
+pub mod bindgen;
+pub use bindgen::Bindgen;
+
 pub mod build;
 pub use build::Build;
 
 pub mod clean;
 pub use clean::Clean;
 
+pub mod debug;
+pub use debug::Debug;
+
 pub mod example;
 pub use example::Example;
 
 pub mod execute;
 pub use execute::Execute;
 
+pub mod fmt;
+pub use fmt::Fmt;
+
+pub mod lint;
+pub use lint::Lint;
+
+pub mod lsp;
+pub use lsp::Lsp;
+
 // pub mod deploy;
 // pub use deploy::Deploy;
 
@@ -44,9 +59,18 @@ pub use new::New;
 pub mod run;
 pub use run::Run;
 
+pub mod state;
+pub use state::State;
+
+pub mod test;
+pub use test::Test;
+
 pub mod update;
 pub use update::Update;
 
+pub mod update_deps;
+pub use update_deps::UpdateDeps;
+
 use super::*;
 use crate::cli::helpers::context::*;
 use leo_errors::{emitter::Handler, CliError, PackageError, Result};
@@ -54,6 +78,8 @@ use leo_package::{build::*, outputs::OutputsDirectory, package::*};
 
 use clap::Parser;
 use colored::Colorize;
+use retriever::Network;
+use std::path::PathBuf;
 use tracing::span::Span;
 
 /// Base trait for the Leo CLI, see methods and their documentation for details.
@@ -122,8 +148,23 @@ pub trait Command {
 /// require Build command output as their input.
 #[derive(Parser, Clone, Debug, Default)]
 pub struct BuildOptions {
-    #[clap(long, help = "Enables offline mode.")]
+    #[clap(
+        long,
+        help = "Enables offline mode. Network dependencies must be vendored in `imports/`, cached, or mirrored."
+    )]
     pub offline: bool,
+    #[clap(long, help = "A directory of `{network}/{program}` files to retrieve network dependencies from.")]
+    pub mirror: Option<PathBuf>,
+    #[clap(long, value_parser = parse_endpoint, help = "Retrieve network dependencies from an endpoint, as `{network}={url}`.")]
+    pub endpoint: Vec<(Network, String)>,
+    #[clap(long, help = "Refuse to build if `leo.lock` is out of date.")]
+    pub locked: bool,
+    #[clap(long, help = "Warn when private data flows into public outputs, finalize arguments, or mappings.")]
+    pub check_privacy: bool,
+    #[clap(skip)]
+    pub update_lock_file: bool,
+    #[clap(skip)]
+    pub lint: bool,
     #[clap(long, help = "Enable spans in AST snapshots.")]
     pub enable_symbol_table_spans: bool,
     #[clap(long, help = "Enables dead code elimination in the compiler.")]
@@ -144,6 +185,8 @@ pub struct BuildOptions {
     pub enable_unrolled_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the SSA AST.")]
     pub enable_ssa_ast_snapshot: bool,
+    #[clap(long, help = "Writes AST snapshot of the constant folded AST.")]
+    pub enable_const_folded_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the flattened AST.")]
     pub enable_flattened_ast_snapshot: bool,
     #[clap(long, help = "Writes AST snapshot of the destructured AST.")]
@@ -153,3 +196,14 @@ pub struct BuildOptions {
     #[clap(long, help = "Writes AST snapshot of the dead code eliminated (DCE) AST.")]
     pub enable_dce_ast_snapshot: bool,
 }
+
+/// Parses an `--endpoint` argument of the form `{network}={url}`.
+fn parse_endpoint(argument: &str) -> std::result::Result<(Network, String), String> {
+    let (network, url) = argument.split_once('=').ok_or("expected an argument of the form `{network}={url}`")?;
+    let network = match network {
+        "testnet3" => Network::Testnet3,
+        "mainnet" => Network::Mainnet,
+        _ => return Err(format!("unknown network `{network}`")),
+    };
+    Ok((network, url.to_string()))
+}
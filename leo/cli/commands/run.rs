@@ -16,7 +16,19 @@
 
 use super::*;
 
-use snarkvm::{cli::Run as SnarkVMRun, prelude::Parser as SnarkVMParser};
+use crate::cli::helpers::{
+    evaluator::{halting, Evaluator},
+    source_map::annotate_failure,
+    state_directory::StateDirectory,
+};
+
+use snarkvm::{
+    cli::{dotenv_private_key, Run as SnarkVMRun},
+    package::Package,
+    prelude::{Identifier, Parser as SnarkVMParser, Program, Value},
+};
+
+use std::{path::Path, str::FromStr};
 
 /// Build, Prove and Run Leo program with inputs
 #[derive(Parser, Debug)]
@@ -30,6 +42,13 @@ pub struct Run {
     #[arg(short, long, help = "The inputs to the program, from a file. Overrides the INPUTS argument.")]
     pub(crate) file: Option<String>,
 
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Run the finalize block against the mappings stored in DIR, which persist across runs. See `leo state`."
+    )]
+    pub(crate) state: Option<PathBuf>,
+
     #[clap(flatten)]
     pub(crate) compiler_options: BuildOptions,
 }
@@ -50,7 +69,7 @@ impl Command for Run {
         let mut inputs = self.inputs;
 
         // Compose the `run` command.
-        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name];
+        let mut arguments = vec![SNARKVM_COMMAND.to_string(), self.name.clone()];
 
         // Add the inputs to the arguments.
         match self.file {
@@ -78,9 +97,13 @@ impl Command for Run {
             None => arguments.append(&mut inputs),
         }
 
+        // Keep the inputs, which follow the function name, to locate the failing statement if the program fails.
+        let inputs = arguments[2..].to_vec();
+
         // Open the Leo build/ directory
         let path = context.dir()?;
         let build_directory = BuildDirectory::open(&path)?;
+        let state = self.state.as_ref().map(|state| StateDirectory::new(path.join(state)));
 
         // Change the cwd to the Leo build/ directory to compile aleo files.
         std::env::set_current_dir(&build_directory)
@@ -92,11 +115,67 @@ impl Command for Run {
         // Call the `run` command.
         println!();
         let command = SnarkVMRun::try_parse_from(&arguments).map_err(CliError::failed_to_parse_run)?;
-        let res = command.parse().map_err(CliError::failed_to_execute_run)?;
+        let res = halting(|| command.parse()).map_err(|error| {
+            CliError::failed_to_execute_run(annotate_failure(&build_directory, &self.name, &inputs, error))
+        })?;
 
         // Log the output of the `run` command.
         tracing::info!("{}", res);
 
+        // Run the finalize block against the stored mappings, which are only updated if it succeeds.
+        if let Some(state) = state {
+            finalize_against_state(&build_directory, &state, &self.name, &inputs)?;
+            tracing::info!("✅ Stored the mappings in '{}'", state.path().display());
+        }
+
         Ok(())
     }
 }
+
+/// Evaluates `function` of the program built in `build_directory` on `inputs`, and runs its finalize block against the
+/// mappings stored in `state`. The mappings of the program and its imports are stored again if the finalize block succeeds.
+fn finalize_against_state(
+    build_directory: &Path,
+    state: &StateDirectory,
+    function: &str,
+    inputs: &[String],
+) -> Result<()> {
+    let package = Package::<CurrentNetwork>::open(build_directory).map_err(CliError::failed_to_finalize)?;
+    let process = package.get_process().map_err(CliError::failed_to_finalize)?;
+    let private_key = dotenv_private_key().map_err(CliError::failed_to_finalize)?;
+    let rng = &mut rand::thread_rng();
+    let evaluator = Evaluator::new(process, rng).map_err(CliError::failed_to_finalize)?.with_private_key(private_key);
+
+    // Collect the program and the programs it imports, directly or indirectly.
+    let mut programs: Vec<Program<CurrentNetwork>> = vec![package.program().clone()];
+    let mut index = 0;
+    while index < programs.len() {
+        let import_ids = programs[index].imports().keys().copied().collect::<Vec<_>>();
+        for import_id in import_ids {
+            if !programs.iter().any(|program| program.id() == &import_id) {
+                let import = evaluator.process().get_program(import_id).map_err(CliError::failed_to_finalize)?;
+                programs.push(import.clone());
+            }
+        }
+        index += 1;
+    }
+
+    // Load their stored mappings.
+    for program in programs.iter() {
+        evaluator.initialize_mappings(program).map_err(CliError::failed_to_finalize)?;
+        state.load(program, evaluator.store())?;
+    }
+
+    let function_name = Identifier::from_str(function).map_err(CliError::failed_to_finalize)?;
+    let inputs = inputs
+        .iter()
+        .map(|input| Value::from_str(input))
+        .collect::<snarkvm::prelude::Result<Vec<_>>>()
+        .map_err(CliError::failed_to_finalize)?;
+    evaluator.run(package.program_id(), &function_name, &inputs, rng).map_err(CliError::failed_to_finalize)?;
+
+    for program in programs.iter() {
+        state.save(program, evaluator.store())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::cli::helpers::state_directory::StateDirectory;
+
+use snarkvm::prelude::{Plaintext, ProgramID};
+
+use std::{io::Write, str::FromStr};
+
+/// Commands to inspect the mappings stored by `leo run --state`.
+#[derive(Parser, Debug)]
+pub enum State {
+    /// Show the entries of a stored mapping, or the value of one of its keys.
+    Show {
+        /// The name of the mapping, prefixed with its program if it is declared by an import, e.g. `token.aleo/balances`.
+        mapping: String,
+        /// The key whose value is shown. All entries of the mapping are shown if omitted.
+        key: Option<String>,
+        /// The directory in which the mappings are stored.
+        #[clap(long, value_name = "DIR", default_value = "state")]
+        state: PathBuf,
+    },
+}
+
+impl Command for State {
+    type Input = ();
+    type Output = ();
+
+    fn prelude(&self, _: Context) -> Result<Self::Input> {
+        Ok(())
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        self.show(context, std::io::stdout())
+    }
+}
+
+impl State {
+    /// Writes the entries of the stored mapping, or the value of its key, to `output`.
+    pub(crate) fn show(&self, context: Context, mut output: impl Write) -> Result<()> {
+        let State::Show { mapping, key, state } = self;
+        let state = StateDirectory::new(context.dir()?.join(state));
+        let not_stored = || CliError::mapping_not_in_state(mapping, state.path().display());
+
+        let (program_id, mapping_name) = match mapping.split_once('/') {
+            Some((program_id, mapping_name)) => {
+                (ProgramID::<CurrentNetwork>::from_str(program_id).map_err(|_| not_stored())?, mapping_name)
+            }
+            None => (*context.open_manifest()?.program_id(), mapping.as_str()),
+        };
+        let entries =
+            state.read(&program_id)?.and_then(|mut mappings| mappings.remove(mapping_name)).ok_or_else(not_stored)?;
+
+        match key {
+            None => {
+                for (key, value) in entries.iter() {
+                    writeln!(output, "{mapping_name}[{key}] = {value}").map_err(CliError::cli_io_error)?;
+                }
+            }
+            Some(key) => {
+                // The keys are compared as plaintexts, so that the key need not be written exactly as it is stored.
+                let plaintext = Plaintext::<CurrentNetwork>::from_str(key).ok();
+                let value = entries
+                    .iter()
+                    .find(|(stored, _)| plaintext.is_some() && Plaintext::from_str(stored).ok() == plaintext)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| CliError::key_not_in_mapping(key, mapping))?;
+                writeln!(output, "{value}").map_err(CliError::cli_io_error)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::cli::helpers::evaluator::{failing_instruction, Evaluator};
+
+use disassembler::disassemble;
+use leo_ast::Stub;
+use leo_compiler::{Compiler, CompilerOptions, InstructionSpans};
+use leo_package::{outputs::OutputsDirectory, source::SourceDirectory, tests::TestsDirectory};
+use leo_span::{sym, Symbol};
+
+use snarkvm::prelude::{Identifier, Process, Program, ProgramID};
+
+use indexmap::IndexMap;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Compile and run the tests of the current package
+#[derive(Parser, Debug)]
+pub struct Test {
+    #[clap(name = "FILTER", help = "Only run the tests whose names contain the filter.")]
+    pub(crate) filter: Option<String>,
+
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+/// A compiled program and the tests it contains.
+struct TestProgram {
+    /// The compiled program, including its `@test` transitions.
+    program: Program<CurrentNetwork>,
+    /// The tests in the program.
+    tests: Vec<TestCase>,
+    /// The spans of the Leo statements that the instructions of the program were generated from.
+    instruction_spans: InstructionSpans,
+}
+
+/// A transition annotated with `@test`.
+struct TestCase {
+    /// The name of the transition.
+    name: Symbol,
+    /// Whether the transition is annotated with `@should_fail`.
+    should_fail: bool,
+    /// The span of the transition's name.
+    span: leo_span::Span,
+}
+
+impl Command for Test {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        (Build { options: self.compiler_options.clone() }).execute(context)
+    }
+
+    fn apply(self, context: Context, _: Self::Input) -> Result<Self::Output> {
+        let package_path = context.dir()?;
+        let build_directory = BuildDirectory::open(&package_path)?;
+        let outputs_directory = OutputsDirectory::create(&package_path)?;
+        let handler = context.handler();
+
+        // Load the main program and its imports, which have just been built.
+        let main_program = load_program(&build_directory.join("main.aleo"))?;
+        let credits_id =
+            ProgramID::<CurrentNetwork>::from_str("credits.aleo").map_err(CliError::failed_to_run_tests)?;
+        let mut imports = Vec::new();
+        let mut stubs = IndexMap::new();
+        for import_id in main_program.imports().keys() {
+            let path = build_directory.join("imports").join(import_id.to_string());
+            // Note that `credits.aleo` is always part of the process.
+            if import_id == &credits_id && !path.exists() {
+                continue;
+            }
+            let import = load_program(&path)?;
+            insert_stub(&mut stubs, &import);
+            if import_id != &credits_id {
+                imports.push(import);
+            }
+        }
+
+        // Compile the tests in `src/`, which are part of the main program.
+        let mut options: CompilerOptions = self.compiler_options.into();
+        options.build.tests_enabled = true;
+        let network = main_program.id().network().to_string();
+        let mut test_programs = Vec::new();
+        let main_file_path = SourceDirectory::check_files(&SourceDirectory::files(&package_path)?)?;
+        test_programs.push(compile_tests(
+            main_file_path,
+            main_program.id().name().to_string(),
+            &network,
+            &handler,
+            &outputs_directory,
+            &options,
+            stubs.clone(),
+        )?);
+
+        // Compile the tests in `tests/`, which are separate programs that may import the main program.
+        insert_stub(&mut stubs, &main_program);
+        for file_path in TestsDirectory::files(&package_path)? {
+            let program_name = file_path
+                .file_stem()
+                .and_then(|name| name.to_str())
+                .ok_or_else(PackageError::failed_to_get_file_name)?
+                .to_string();
+            test_programs.push(compile_tests(
+                file_path,
+                program_name,
+                &network,
+                &handler,
+                &outputs_directory,
+                &options,
+                stubs.clone(),
+            )?);
+        }
+
+        // Run the tests.
+        let process = Process::<CurrentNetwork>::load().map_err(CliError::failed_to_run_tests)?;
+        let rng = &mut rand::thread_rng();
+        let (mut passed, mut failed) = (0, 0);
+        for test_program in test_programs.iter() {
+            let program_id = test_program.program.id();
+            for test in test_program.tests.iter() {
+                let test_name = format!("{program_id}/{}", test.name);
+                if let Some(filter) = &self.filter {
+                    if !test_name.contains(filter.as_str()) {
+                        continue;
+                    }
+                }
+
+                // Each test is run by a new evaluator, so that its mappings are isolated from the other tests.
+                let mut evaluator = Evaluator::new(process.clone(), rng).map_err(CliError::failed_to_run_tests)?;
+                for import in imports.iter() {
+                    evaluator.add_program(import).map_err(CliError::failed_to_run_tests)?;
+                }
+                if program_id != main_program.id() {
+                    evaluator.add_program(&main_program).map_err(CliError::failed_to_run_tests)?;
+                }
+                evaluator.add_program(&test_program.program).map_err(CliError::failed_to_run_tests)?;
+
+                let function_name =
+                    Identifier::from_str(&test.name.to_string()).map_err(CliError::failed_to_run_tests)?;
+                let result = evaluator.run(program_id, &function_name, &[], rng);
+
+                let error = match (result, test.should_fail) {
+                    (Ok(_), false) | (Err(_), true) => None,
+                    (Ok(_), true) => Some(CliError::test_did_not_fail(&test_name, test.span)),
+                    (Err(error), false) => {
+                        let error = error.to_string();
+                        // Point at the failing assertion, if it can be found.
+                        let span = failing_instruction(&error)
+                            .and_then(|instruction| test_program.instruction_spans.find(test.name, instruction))
+                            .unwrap_or(test.span);
+                        Some(CliError::test_failed(&test_name, error, span))
+                    }
+                };
+                match error {
+                    None => {
+                        passed += 1;
+                        tracing::info!("✅ Passed '{test_name}'");
+                    }
+                    Some(error) => {
+                        failed += 1;
+                        tracing::info!("❌ Failed '{test_name}'");
+                        handler.emit_err(error);
+                    }
+                }
+            }
+        }
+
+        tracing::info!("{passed} passed; {failed} failed");
+        match failed {
+            0 => Ok(()),
+            _ => Err(CliError::tests_failed(failed, passed + failed).into()),
+        }
+    }
+}
+
+/// Reads and parses an Aleo program.
+fn load_program(path: &Path) -> Result<Program<CurrentNetwork>> {
+    let source = std::fs::read_to_string(path).map_err(|err| PackageError::failed_to_read_file(path.display(), err))?;
+    Ok(Program::from_str(&source).map_err(CliError::failed_to_run_tests)?)
+}
+
+/// Adds the stub of the given program to the stubs used for compilation.
+fn insert_stub(stubs: &mut IndexMap<Symbol, Stub>, program: &Program<CurrentNetwork>) {
+    let stub = disassemble(program.clone());
+    stubs.insert(stub.stub_id.name.name, stub);
+}
+
+/// Compiles a Leo file, keeping its `@test` transitions.
+fn compile_tests(
+    file_path: PathBuf,
+    program_name: String,
+    network: &str,
+    handler: &Handler,
+    outputs: &Path,
+    options: &CompilerOptions,
+    stubs: IndexMap<Symbol, Stub>,
+) -> Result<TestProgram> {
+    let mut compiler = Compiler::new(
+        program_name,
+        network.to_string(),
+        handler,
+        file_path,
+        outputs.to_path_buf(),
+        Some(options.clone()),
+        stubs,
+    );
+    let bytecode = compiler.compile()?;
+
+    let tests = compiler
+        .ast
+        .ast
+        .program_scopes
+        .values()
+        .flat_map(|program_scope| program_scope.functions.iter())
+        .filter(|(_, function)| function.is_test())
+        .map(|(name, function)| TestCase {
+            name: *name,
+            should_fail: function.has_annotation(sym::should_fail),
+            span: function.identifier.span,
+        })
+        .collect();
+
+    Ok(TestProgram {
+        program: Program::from_str(&bytecode).map_err(CliError::failed_to_run_tests)?,
+        tests,
+        instruction_spans: compiler.instruction_spans,
+    })
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// Update the checksums of the dependencies in `leo.lock`.
+/// The current package is built as usual, except that changed checksums are accepted instead of rejected.
+#[derive(Parser, Debug)]
+pub struct UpdateDeps {
+    #[clap(flatten)]
+    pub(crate) compiler_options: BuildOptions,
+}
+
+impl Command for UpdateDeps {
+    type Input = <Build as Command>::Output;
+    type Output = ();
+
+    fn log_span(&self) -> Span {
+        tracing::span!(tracing::Level::INFO, "Leo")
+    }
+
+    fn prelude(&self, context: Context) -> Result<Self::Input> {
+        let options = BuildOptions { locked: false, update_lock_file: true, ..self.compiler_options.clone() };
+        (Build { options }).execute(context)
+    }
+
+    fn apply(self, _: Context, _: Self::Input) -> Result<Self::Output> {
+        tracing::info!("✅ Updated `leo.lock`");
+        Ok(())
+    }
+}
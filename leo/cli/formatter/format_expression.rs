@@ -0,0 +1,402 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::{
+    AccessExpression,
+    AssociatedFunction,
+    BinaryExpression,
+    BinaryOperation,
+    Expression,
+    GroupLiteral,
+    Identifier,
+    Literal,
+    Node,
+    StructExpression,
+    UnaryExpression,
+    UnaryOperation,
+};
+
+/// The indentation and column at which an expression starts, or `None` if it must be printed on one line.
+type Position = Option<(usize, usize)>;
+
+/// The precedence of postfix expressions, like calls and accesses, and of primary expressions.
+const POSTFIX: u8 = 15;
+
+/// The precedence of prefix expressions, like `!x`, and of negative literals.
+const PREFIX: u8 = 14;
+
+/// The precedence of casts.
+const CAST: u8 = 13;
+
+impl Formatter<'_> {
+    /// Returns an expression starting after `used` columns of the current line.
+    pub(super) fn format_expression(&self, input: &Expression, used: usize) -> String {
+        self.expression(input, self.position(used))
+    }
+
+    /// Returns the condition of a conditional statement or the bound of a loop, which are followed by a block.
+    /// Note that these expressions must be parenthesized if they contain a struct expression outside of a delimiter.
+    pub(super) fn format_condition(&self, input: &Expression, used: usize) -> String {
+        match Self::contains_struct(input) {
+            true => format!("({})", self.expression(input, self.position(used + 1))),
+            false => self.format_expression(input, used),
+        }
+    }
+
+    /// Returns a delimited list of expressions starting after `used` columns of the current line.
+    pub(super) fn format_list(
+        &self,
+        open: &str,
+        elements: &[&Expression],
+        close: &str,
+        used: usize,
+        trailing_comma: bool,
+    ) -> String {
+        self.list(open, elements, close, self.position(used), trailing_comma)
+    }
+
+    fn position(&self, used: usize) -> Position {
+        let indent = self.indent * INDENT_WIDTH;
+        Some((indent, indent + used))
+    }
+
+    fn expression(&self, input: &Expression, position: Position) -> String {
+        // Expressions are only split across lines if they do not fit on the current line.
+        if let Some((_, column)) = position {
+            let flat = self.expression(input, None);
+            if column + flat.chars().count() <= MAX_WIDTH {
+                return flat;
+            }
+        }
+        match input {
+            Expression::Access(AccessExpression::Array(access)) => {
+                let array = self.operand(&access.array, POSTFIX, position);
+                let index = self.expression(&access.index, Self::advance(position, &format!("{array}[")));
+                format!("{array}[{index}]")
+            }
+            Expression::Access(AccessExpression::AssociatedConstant(constant)) => {
+                format!("{}::{}", self.format_type(&constant.ty), constant.name)
+            }
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => {
+                self.associated_function(function, position)
+            }
+            Expression::Access(AccessExpression::Member(access)) => {
+                format!("{}.{}", self.operand(&access.inner, POSTFIX, position), access.name)
+            }
+            Expression::Access(AccessExpression::Tuple(access)) => {
+                format!("{}.{}", self.operand(&access.tuple, POSTFIX, position), access.index)
+            }
+            Expression::Array(array) => self.list("[", &array.elements.iter().collect::<Vec<_>>(), "]", position, true),
+            Expression::Binary(binary) => self.binary(binary, position),
+            Expression::Call(call) => {
+                let mut function = match &call.external {
+                    Some(program) => format!("{program}.aleo/{}", call.function),
+                    None => call.function.to_string(),
+                };
+                // Note that the const arguments are short, so they are kept on one line.
+                if !call.const_arguments.is_empty() {
+                    let const_arguments = call.const_arguments.iter().map(|argument| self.expression(argument, None));
+                    function += &format!("::[{}]", const_arguments.collect::<Vec<_>>().join(", "));
+                }
+                self.list(&format!("{function}("), &call.arguments.iter().collect::<Vec<_>>(), ")", position, true)
+            }
+            Expression::Cast(cast) => {
+                format!("{} as {}", self.operand(&cast.expression, PREFIX, position), self.format_type(&cast.type_))
+            }
+            Expression::Struct(struct_) => self.struct_expression(struct_, position),
+            Expression::Err(_) => unreachable!("The formatter only formats programs that parse without errors."),
+            Expression::Identifier(identifier) => identifier.to_string(),
+            Expression::Literal(literal) => Self::literal(literal),
+            Expression::Ternary(ternary) => {
+                let condition =
+                    self.operand(&ternary.condition, Self::binary_precedence(BinaryOperation::Or), position);
+                let position = Self::advance(position, &format!("{condition} ? "));
+                let if_true = self.operand(&ternary.if_true, 1, position);
+                let position = Self::advance(position, &format!("{if_true} : "));
+                let if_false = self.operand(&ternary.if_false, 1, position);
+                format!("{condition} ? {if_true} : {if_false}")
+            }
+            Expression::Tuple(tuple) => self.list("(", &tuple.elements.iter().collect::<Vec<_>>(), ")", position, true),
+            Expression::Unary(unary) => self.unary(unary, position),
+            Expression::Unit(_) => "()".to_string(),
+        }
+    }
+
+    /// Returns an operand of a larger expression, which is parenthesized if its precedence is lower than `precedence`.
+    /// The parentheses written in the source are kept.
+    fn operand(&self, input: &Expression, precedence: u8, position: Position) -> String {
+        match self.precedence(input) < precedence || self.is_parenthesized(input) {
+            true => format!("({})", self.expression(input, Self::advance(position, "("))),
+            false => self.expression(input, position),
+        }
+    }
+
+    /// Returns a delimited list of expressions, with one element per line if it does not fit on the current line.
+    fn list(
+        &self,
+        open: &str,
+        elements: &[&Expression],
+        close: &str,
+        position: Position,
+        trailing_comma: bool,
+    ) -> String {
+        let flat = format!(
+            "{open}{}{close}",
+            elements.iter().map(|element| self.expression(element, None)).collect::<Vec<_>>().join(", ")
+        );
+        match position {
+            Some((indent, column)) if !elements.is_empty() && column + flat.chars().count() > MAX_WIDTH => {
+                let elements = elements
+                    .iter()
+                    .map(|element| {
+                        let indent = indent + INDENT_WIDTH;
+                        format!("{}{}", " ".repeat(indent), self.expression(element, Some((indent, indent))))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",\n");
+                // Note that the parser does not accept a trailing comma in every list, e.g. in the arguments of an assertion.
+                let comma = if trailing_comma { "," } else { "" };
+                format!("{open}\n{elements}{comma}\n{}{close}", " ".repeat(indent))
+            }
+            _ => flat,
+        }
+    }
+
+    fn associated_function(&self, input: &AssociatedFunction, position: Position) -> String {
+        let arguments = input.arguments.iter().collect::<Vec<_>>();
+        // Mapping operations and signature verifications may be written as method calls, e.g. `balances.get(owner)`.
+        match arguments.split_first() {
+            Some((receiver, arguments)) if receiver.span().lo == input.span.lo && !input.span.is_dummy() => {
+                let receiver = self.operand(receiver, POSTFIX, position);
+                let open = format!("{receiver}.{}(", input.name);
+                self.list(&open, arguments, ")", Self::advance(position, &open), true)
+            }
+            _ => {
+                let open = format!("{}::{}(", self.format_type(&input.ty), input.name);
+                self.list(&open, &arguments, ")", position, true)
+            }
+        }
+    }
+
+    fn binary(&self, input: &BinaryExpression, position: Position) -> String {
+        if Self::is_method_call(input) {
+            let receiver = self.operand(&input.left, POSTFIX, position);
+            let open = format!("{receiver}.{}(", Self::binary_method(input.op));
+            return self.list(&open, &[&input.right], ")", Self::advance(position, &open), true);
+        }
+        // Comparisons are not associative, while the other operations are left-associative.
+        let precedence = Self::binary_precedence(input.op);
+        let left_precedence = match input.op {
+            BinaryOperation::Eq
+            | BinaryOperation::Neq
+            | BinaryOperation::Lt
+            | BinaryOperation::Lte
+            | BinaryOperation::Gt
+            | BinaryOperation::Gte => precedence + 1,
+            _ => precedence,
+        };
+        let left = self.operand(&input.left, left_precedence, position);
+        let right =
+            self.operand(&input.right, precedence + 1, Self::advance(position, &format!("{left} {} ", input.op)));
+        format!("{left} {} {right}", input.op)
+    }
+
+    fn unary(&self, input: &UnaryExpression, position: Position) -> String {
+        match (input.op, Self::is_prefix(input)) {
+            (UnaryOperation::Negate, true) => {
+                format!("-{}", self.operand(&input.receiver, PREFIX, Self::advance(position, "-")))
+            }
+            (UnaryOperation::Not, true) => {
+                format!("!{}", self.operand(&input.receiver, PREFIX, Self::advance(position, "!")))
+            }
+            (op, _) => format!("{}.{}()", self.operand(&input.receiver, POSTFIX, position), Self::unary_method(op)),
+        }
+    }
+
+    fn struct_expression(&self, input: &StructExpression, position: Position) -> String {
+        let members =
+            input.members.iter().map(|member| (member.identifier, member.expression.as_ref())).collect::<Vec<_>>();
+        let member = |(identifier, expression): &(Identifier, Option<&Expression>), position| match expression {
+            Some(expression) => format!(
+                "{identifier}: {}",
+                self.expression(expression, Self::advance(position, &format!("{identifier}: ")))
+            ),
+            None => identifier.to_string(),
+        };
+        if members.is_empty() {
+            return format!("{} {{}}", input.name);
+        }
+        let flat =
+            format!("{} {{ {} }}", input.name, members.iter().map(|m| member(m, None)).collect::<Vec<_>>().join(", "));
+        match position {
+            Some((indent, column)) if column + flat.chars().count() > MAX_WIDTH => {
+                let indentation = " ".repeat(indent);
+                let members = members
+                    .iter()
+                    .map(|m| {
+                        let indent = indent + INDENT_WIDTH;
+                        format!("{}{},\n", " ".repeat(indent), member(m, Some((indent, indent))))
+                    })
+                    .collect::<String>();
+                format!("{} {{\n{members}{indentation}}}", input.name)
+            }
+            _ => flat,
+        }
+    }
+
+    fn literal(input: &Literal) -> String {
+        match input {
+            // Note that the `Display` implementation only prints the x-coordinate of an affine group literal.
+            Literal::Group(group) => match &**group {
+                GroupLiteral::Tuple(tuple) => format!("({}, {})group", tuple.x, tuple.y),
+                GroupLiteral::Single(..) => input.to_string(),
+            },
+            literal => literal.to_string(),
+        }
+    }
+
+    /// Returns the position after the given text, which starts at the given position.
+    fn advance(position: Position, text: &str) -> Position {
+        position.map(|(indent, column)| match text.rsplit_once('\n') {
+            Some((_, last_line)) => (indent, last_line.chars().count()),
+            None => (indent, column + text.chars().count()),
+        })
+    }
+
+    /// Returns the precedence of an expression, where a higher precedence binds more tightly.
+    fn precedence(&self, input: &Expression) -> u8 {
+        match input {
+            Expression::Ternary(_) => 1,
+            Expression::Binary(binary) if !Self::is_method_call(binary) => Self::binary_precedence(binary.op),
+            Expression::Cast(_) => CAST,
+            Expression::Unary(unary) if Self::is_prefix(unary) => PREFIX,
+            // A negative literal is parsed as a negation followed by a literal.
+            Expression::Literal(literal) if Self::literal(literal).starts_with('-') => PREFIX,
+            _ => POSTFIX,
+        }
+    }
+
+    /// Returns the precedence of a binary operation that has an operator.
+    fn binary_precedence(op: BinaryOperation) -> u8 {
+        match op {
+            BinaryOperation::Or => 2,
+            BinaryOperation::And => 3,
+            BinaryOperation::Eq | BinaryOperation::Neq => 4,
+            BinaryOperation::Lt | BinaryOperation::Lte | BinaryOperation::Gt | BinaryOperation::Gte => 5,
+            BinaryOperation::Xor => 6,
+            BinaryOperation::BitwiseOr => 7,
+            BinaryOperation::BitwiseAnd => 8,
+            BinaryOperation::Shl | BinaryOperation::Shr => 9,
+            BinaryOperation::Add | BinaryOperation::Sub => 10,
+            BinaryOperation::Mul | BinaryOperation::Div | BinaryOperation::Rem => 11,
+            BinaryOperation::Pow => 12,
+            _ => POSTFIX,
+        }
+    }
+
+    /// Returns whether a binary operation is written as a method call, e.g. `a.add_wrapped(b)`.
+    /// Note that a method call ends with a parenthesis after its argument, while an operation ends with its right operand.
+    fn is_method_call(input: &BinaryExpression) -> bool {
+        Self::binary_precedence(input.op) == POSTFIX || input.span.hi > input.right.span().hi
+    }
+
+    /// Returns whether a unary operation is written with a prefix operator, e.g. `!a`, rather than as a method call.
+    fn is_prefix(input: &UnaryExpression) -> bool {
+        matches!(input.op, UnaryOperation::Negate | UnaryOperation::Not) && input.span.lo < input.receiver.span().lo
+    }
+
+    /// Returns the name of the method for a binary operation.
+    fn binary_method(op: BinaryOperation) -> &'static str {
+        match op {
+            BinaryOperation::Add => "add",
+            BinaryOperation::AddWrapped => "add_wrapped",
+            BinaryOperation::And | BinaryOperation::BitwiseAnd => "and",
+            BinaryOperation::Div => "div",
+            BinaryOperation::DivWrapped => "div_wrapped",
+            BinaryOperation::Eq => "eq",
+            BinaryOperation::Gte => "gte",
+            BinaryOperation::Gt => "gt",
+            BinaryOperation::Lte => "lte",
+            BinaryOperation::Lt => "lt",
+            BinaryOperation::Mod => "mod",
+            BinaryOperation::Mul => "mul",
+            BinaryOperation::MulWrapped => "mul_wrapped",
+            BinaryOperation::Nand => "nand",
+            BinaryOperation::Neq => "neq",
+            BinaryOperation::Nor => "nor",
+            BinaryOperation::Or | BinaryOperation::BitwiseOr => "or",
+            BinaryOperation::Pow => "pow",
+            BinaryOperation::PowWrapped => "pow_wrapped",
+            BinaryOperation::Rem => "rem",
+            BinaryOperation::RemWrapped => "rem_wrapped",
+            BinaryOperation::Shl => "shl",
+            BinaryOperation::ShlWrapped => "shl_wrapped",
+            BinaryOperation::Shr => "shr",
+            BinaryOperation::ShrWrapped => "shr_wrapped",
+            BinaryOperation::Sub => "sub",
+            BinaryOperation::SubWrapped => "sub_wrapped",
+            BinaryOperation::Xor => "xor",
+        }
+    }
+
+    /// Returns the name of the method for a unary operation.
+    fn unary_method(op: UnaryOperation) -> &'static str {
+        match op {
+            UnaryOperation::Abs => "abs",
+            UnaryOperation::AbsWrapped => "abs_wrapped",
+            UnaryOperation::Double => "double",
+            UnaryOperation::Inverse => "inv",
+            UnaryOperation::Negate => "neg",
+            UnaryOperation::Not => "not",
+            UnaryOperation::Square => "square",
+            UnaryOperation::SquareRoot => "square_root",
+            UnaryOperation::ToXCoordinate => "to_x_coordinate",
+            UnaryOperation::ToYCoordinate => "to_y_coordinate",
+        }
+    }
+
+    /// Returns whether the expression is directly enclosed by parentheses in the source.
+    fn is_parenthesized(&self, input: &Expression) -> bool {
+        let span = input.span();
+        !span.is_dummy()
+            && self.source_before(span.lo).trim_end().ends_with('(')
+            && self.source_after(span.hi).trim_start().starts_with(')')
+    }
+
+    /// Returns whether the expression contains a struct expression that is not enclosed by a delimiter.
+    fn contains_struct(input: &Expression) -> bool {
+        match input {
+            Expression::Struct(_) => true,
+            Expression::Access(AccessExpression::Array(access)) => Self::contains_struct(&access.array),
+            Expression::Access(AccessExpression::Member(access)) => Self::contains_struct(&access.inner),
+            Expression::Access(AccessExpression::Tuple(access)) => Self::contains_struct(&access.tuple),
+            Expression::Access(AccessExpression::AssociatedFunction(function)) => {
+                function.span.lo == function.arguments.first().map_or(BytePos(0), |argument| argument.span().lo)
+                    && Self::contains_struct(&function.arguments[0])
+            }
+            Expression::Binary(binary) => {
+                Self::contains_struct(&binary.left)
+                    || (!Self::is_method_call(binary) && Self::contains_struct(&binary.right))
+            }
+            Expression::Cast(cast) => Self::contains_struct(&cast.expression),
+            Expression::Ternary(ternary) => Self::contains_struct(&ternary.condition),
+            Expression::Unary(unary) => Self::contains_struct(&unary.receiver),
+            _ => false,
+        }
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::{
+    ConstDeclaration,
+    Enum,
+    Finalize,
+    Function,
+    Input,
+    Mapping,
+    Mode,
+    Module,
+    Output,
+    Program,
+    ProgramScope,
+    Struct,
+    Type,
+    Variant,
+};
+
+/// An item declared in a program scope or a module, or an import.
+/// Note that the AST groups items by kind, so they are sorted back into the order of the source.
+enum Item<'a> {
+    Import(String, Span),
+    Const(&'a ConstDeclaration),
+    Struct(&'a Struct),
+    Enum(&'a Enum),
+    Mapping(&'a Mapping),
+    Function(&'a Function),
+}
+
+impl Item<'_> {
+    /// Returns the position of the start of the item, including its annotations.
+    fn lo(&self) -> BytePos {
+        match self {
+            Item::Import(_, span) => span.lo,
+            Item::Const(declaration) => declaration.span.lo,
+            Item::Struct(struct_) => struct_.span.lo,
+            Item::Enum(enum_) => enum_.span.lo,
+            Item::Mapping(mapping) => mapping.span.lo,
+            Item::Function(function) => {
+                function.annotations.first().map_or(function.span.lo, |annotation| annotation.span.lo)
+            }
+        }
+    }
+}
+
+impl Formatter<'_> {
+    pub(super) fn format_program(&mut self, input: &Program) {
+        let mut imports = input
+            .imports
+            .iter()
+            .map(|(name, (_, span))| Item::Import(format!("import {name}.aleo;"), *span))
+            .chain(input.modules.iter().map(|(name, span)| Item::Import(format!("import {name};"), *span)))
+            .collect::<Vec<_>>();
+        imports.sort_by_key(Item::lo);
+        let mut imports = imports.into_iter().peekable();
+        for scope in input.program_scopes.values() {
+            while let Some(import) = imports.next_if(|import| import.lo() < scope.span.lo) {
+                self.format_item(import);
+            }
+            self.format_program_scope(scope);
+        }
+        imports.for_each(|import| self.format_item(import));
+
+        // Print the comments at the end of the file.
+        self.comments_before(BytePos(u32::MAX));
+    }
+
+    pub(super) fn format_module(&mut self, input: &Module) {
+        let mut items = input
+            .modules
+            .iter()
+            .map(|(name, span)| Item::Import(format!("import {name};"), *span))
+            .chain(input.consts.iter().map(|(_, declaration)| Item::Const(declaration)))
+            .chain(input.structs.iter().map(|(_, struct_)| Item::Struct(struct_)))
+            .chain(input.functions.iter().map(|(_, function)| Item::Function(function)))
+            .collect::<Vec<_>>();
+        items.sort_by_key(Item::lo);
+        items.into_iter().for_each(|item| self.format_item(item));
+
+        // Print the comments at the end of the file.
+        self.comments_before(BytePos(u32::MAX));
+    }
+
+    fn format_item(&mut self, item: Item) {
+        match item {
+            Item::Import(import, span) => self.element(span.lo, span.hi, span.hi, |f| f.line(&import)),
+            Item::Const(declaration) => self.format_const_item(declaration),
+            Item::Struct(struct_) => self.format_struct(struct_),
+            Item::Enum(enum_) => self.format_enum(enum_),
+            Item::Mapping(mapping) => self.format_mapping(mapping),
+            Item::Function(function) => self.format_function(function),
+        }
+    }
+
+    fn format_program_scope(&mut self, input: &ProgramScope) {
+        let mut items = input
+            .consts
+            .iter()
+            .map(|(_, declaration)| Item::Const(declaration))
+            .chain(input.structs.iter().map(|(_, struct_)| Item::Struct(struct_)))
+            .chain(input.enums.iter().map(|(_, enum_)| Item::Enum(enum_)))
+            .chain(input.mappings.iter().map(|(_, mapping)| Item::Mapping(mapping)))
+            .chain(input.functions.iter().map(|(_, function)| Item::Function(function)))
+            .collect::<Vec<_>>();
+        items.sort_by_key(Item::lo);
+
+        self.element(input.span.lo, input.span.lo, input.span.hi, |f| {
+            f.open_block(&format!("program {} {{", input.program_id), input.span.lo);
+            items.into_iter().for_each(|item| f.format_item(item));
+            f.close_block("}", input.span.hi);
+        });
+    }
+
+    fn format_const_item(&mut self, input: &ConstDeclaration) {
+        self.element(input.span.lo, input.span.hi, input.span.hi, |f| f.format_const(input));
+    }
+
+    fn format_struct(&mut self, input: &Struct) {
+        let keyword = if input.is_record { "record" } else { "struct" };
+        let header = format!("{keyword} {} {{", input.identifier);
+        self.element(input.span.lo, input.span.lo, input.span.hi, |f| {
+            if input.members.is_empty() && !f.has_comments_before(input.span.hi) {
+                return f.line(&format!("{header}}}"));
+            }
+            f.open_block(&header, input.span.lo);
+            for member in input.members.iter() {
+                f.element(member.span.lo, member.span.hi, member.span.hi, |f| {
+                    f.line(&format!(
+                        "{}{}: {},",
+                        Self::format_mode(member.mode),
+                        member.identifier,
+                        f.format_type(&member.type_)
+                    ))
+                });
+            }
+            f.close_block("}", input.span.hi);
+        });
+    }
+
+    fn format_enum(&mut self, input: &Enum) {
+        let header = format!("enum {} {{", input.identifier);
+        self.element(input.span.lo, input.span.lo, input.span.hi, |f| {
+            if input.variants.is_empty() && !f.has_comments_before(input.span.hi) {
+                return f.line(&format!("{header}}}"));
+            }
+            f.open_block(&header, input.span.lo);
+            for variant in input.variants.iter() {
+                f.element(variant.span.lo, variant.span.hi, variant.span.hi, |f| match &variant.payload {
+                    Some(payload) => f.line(&format!("{}({}),", variant.identifier, f.format_type(payload))),
+                    None => f.line(&format!("{},", variant.identifier)),
+                });
+            }
+            f.close_block("}", input.span.hi);
+        });
+    }
+
+    fn format_mapping(&mut self, input: &Mapping) {
+        self.element(input.span.lo, input.span.hi, input.span.hi, |f| {
+            f.line(&format!(
+                "mapping {}: {} => {};",
+                input.identifier,
+                f.format_type(&input.key_type),
+                f.format_type(&input.value_type)
+            ))
+        });
+    }
+
+    fn format_function(&mut self, input: &Function) {
+        let variant = match input.variant {
+            Variant::Inline => "inline",
+            Variant::Standard => "function",
+            Variant::Transition => "transition",
+        };
+        let lo = Item::Function(input).lo();
+        self.element(lo, input.block.span.lo, input.span.hi, |f| {
+            for annotation in input.annotations.iter() {
+                f.line(&annotation.to_string());
+            }
+            let name = match input.is_generic() {
+                true => format!(
+                    "{variant} {}::[{}]",
+                    input.identifier,
+                    input
+                        .const_parameters
+                        .iter()
+                        .map(|parameter| format!("{}: {}", parameter.identifier, f.format_type(&parameter.type_)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                false => format!("{variant} {}", input.identifier),
+            };
+            let header = f.format_signature(&name, &input.input, &input.output);
+            f.format_body(header, &input.block);
+        });
+        if let Some(finalize) = &input.finalize {
+            self.format_finalize(finalize);
+        }
+    }
+
+    fn format_finalize(&mut self, input: &Finalize) {
+        self.element(input.span.lo, input.block.span.lo, input.span.hi, |f| {
+            let header = f.format_signature(&format!("finalize {}", input.identifier), &input.input, &input.output);
+            f.format_body(header, &input.block);
+        });
+    }
+
+    /// Returns the signature of a function or finalize block, e.g. `transition foo(a: u8) -> u8`.
+    /// If it is too long, its inputs are put on separate lines.
+    fn format_signature(&self, name: &str, inputs: &[Input], outputs: &[Output]) -> String {
+        let inputs = inputs.iter().map(|input| self.format_input(input)).collect::<Vec<_>>();
+        let outputs = outputs.iter().map(|output| self.format_output(output)).collect::<Vec<_>>();
+        let outputs = match outputs.len() {
+            0 => String::new(),
+            1 => format!(" -> {}", outputs[0]),
+            _ => format!(" -> ({})", outputs.join(", ")),
+        };
+        let signature = format!("{name}({}){outputs}", inputs.join(", "));
+        // Note that the opening brace of the body follows the signature.
+        match self.fits(2, &signature) || inputs.is_empty() {
+            true => signature,
+            false => {
+                let indentation = self.indentation();
+                let inputs = inputs.iter().map(|input| format!("{indentation}    {input},\n")).collect::<String>();
+                format!("{name}(\n{inputs}{indentation}){outputs}")
+            }
+        }
+    }
+
+    /// Prints the body of a function or finalize block after its signature.
+    /// Note that the body of a function without one is a semicolon.
+    fn format_body(&mut self, signature: String, block: &leo_ast::Block) {
+        if self.source_between(block.span.lo, block.span.hi) == ";" {
+            return self.line(&format!("{signature};"));
+        }
+        self.format_block(&signature, block);
+    }
+
+    fn format_input(&self, input: &Input) -> String {
+        match input {
+            Input::Internal(input) => {
+                format!("{}{}: {}", Self::format_mode(input.mode), input.identifier, self.format_type(&input.type_))
+            }
+            Input::External(input) => {
+                format!("{}: {}.aleo/{}.record", input.identifier, input.program_name, input.record)
+            }
+        }
+    }
+
+    fn format_output(&self, output: &Output) -> String {
+        match output {
+            Output::Internal(output) => {
+                format!("{}{}", Self::format_mode(output.mode), self.format_type(&output.type_))
+            }
+            Output::External(output) => format!("{}.aleo/{}.record", output.program_name, output.record),
+        }
+    }
+
+    /// Returns the mode of an input, output, or member, followed by a space if there is one.
+    fn format_mode(mode: Mode) -> String {
+        match mode {
+            Mode::None => String::new(),
+            mode => format!("{mode} "),
+        }
+    }
+
+    pub(super) fn format_type(&self, type_: &Type) -> String {
+        match type_ {
+            Type::Address => "address".to_string(),
+            Type::Array(array_type) => {
+                format!("[{}; {}]", self.format_type(array_type.element_type()), array_type.declared_length())
+            }
+            Type::Boolean => "bool".to_string(),
+            Type::Field => "field".to_string(),
+            Type::Group => "group".to_string(),
+            Type::Identifier(identifier) => match self.external_program(identifier.span) {
+                Some(program) => format!("{program}.aleo/{identifier}"),
+                None => identifier.to_string(),
+            },
+            Type::Integer(integer_type) => integer_type.to_string(),
+            Type::Mapping(mapping_type) => {
+                format!("mapping {} => {}", self.format_type(&mapping_type.key), self.format_type(&mapping_type.value))
+            }
+            Type::Scalar => "scalar".to_string(),
+            Type::Signature => "signature".to_string(),
+            Type::String => "string".to_string(),
+            Type::Tuple(tuple_type) => {
+                format!(
+                    "({})",
+                    tuple_type.elements().iter().map(|type_| self.format_type(type_)).collect::<Vec<_>>().join(", ")
+                )
+            }
+            Type::Unit => "()".to_string(),
+            Type::Err => "error".to_string(),
+        }
+    }
+
+    /// Returns the name of the program of a record type, e.g. `credits` in `credits.aleo/credits`.
+    /// Note that the parser only keeps the name of the record, so the program is found in the source.
+    fn external_program(&self, span: Span) -> Option<&str> {
+        let before = self.source_before(span.lo).trim_end().strip_suffix('/')?.trim_end();
+        let before = before.strip_suffix("aleo")?.trim_end().strip_suffix('.')?.trim_end();
+        let start = before.rfind(|c: char| !c.is_ascii_alphanumeric() && c != '_').map_or(0, |index| index + 1);
+        Some(&before[start..]).filter(|program| !program.is_empty())
+    }
+
+    /// Returns whether there are comments left before the given position.
+    pub(super) fn has_comments_before(&self, pos: BytePos) -> bool {
+        matches!(self.comments.last(), Some((_, span)) if span.lo < pos)
+    }
+}
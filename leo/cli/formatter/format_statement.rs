@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::{
+    AssertStatement,
+    AssertVariant,
+    AssignStatement,
+    Block,
+    ConditionalStatement,
+    ConstDeclaration,
+    DefinitionStatement,
+    Expression,
+    ExpressionStatement,
+    IterationRange,
+    IterationStatement,
+    MatchStatement,
+    Node,
+    ReturnStatement,
+    Statement,
+};
+
+impl Formatter<'_> {
+    fn format_statement(&mut self, input: &Statement) {
+        let span = input.span();
+        match input {
+            Statement::Block(block) => {
+                self.element(span.lo, block.span.lo, span.hi, |f| f.format_block("", block));
+            }
+            Statement::Conditional(conditional) => {
+                self.element(span.lo, conditional.then.span.lo, span.hi, |f| f.format_conditional(conditional));
+            }
+            Statement::Iteration(iteration) => {
+                self.element(span.lo, iteration.block.span.lo, span.hi, |f| f.format_iteration(iteration));
+            }
+            Statement::Match(match_) => {
+                self.element(span.lo, match_.expression.span().hi, span.hi, |f| f.format_match(match_));
+            }
+            Statement::Assert(assert) => {
+                // Note that the span of an assertion only covers its keyword, so it is extended to the semicolon.
+                let header_end = match &assert.variant {
+                    AssertVariant::Assert(expression) => expression.span().hi,
+                    AssertVariant::AssertEq(_, right) | AssertVariant::AssertNeq(_, right) => right.span().hi,
+                };
+                let hi = header_end + BytePos::from_usize(self.source_after(header_end).find(';').map_or(0, |i| i + 1));
+                self.element(span.lo, header_end, hi, |f| f.format_assert(assert));
+            }
+            Statement::Assign(assign) => self.element(span.lo, span.hi, span.hi, |f| f.format_assign(assign)),
+            Statement::Const(declaration) => self.element(span.lo, span.hi, span.hi, |f| f.format_const(declaration)),
+            Statement::Definition(definition) => {
+                self.element(span.lo, span.hi, span.hi, |f| f.format_definition(definition))
+            }
+            Statement::Expression(statement) => {
+                self.element(span.lo, span.hi, span.hi, |f| f.format_expression_statement(statement))
+            }
+            Statement::Return(return_) => self.element(span.lo, span.hi, span.hi, |f| f.format_return(return_)),
+            Statement::Console(_) => {
+                unreachable!("Parsing guarantees that `ConsoleStatement`s are not present in the AST.")
+            }
+        }
+    }
+
+    /// Prints a block, preceded by the given header, e.g. `while x`.
+    pub(super) fn format_block(&mut self, header: &str, input: &Block) {
+        let header = match header.is_empty() {
+            true => "{".to_string(),
+            false => format!("{header} {{"),
+        };
+        if input.statements.is_empty() && !self.has_comments_before(input.span.hi) {
+            return self.line(&format!("{header}}}"));
+        }
+        self.open_block(&header, input.span.lo);
+        input.statements.iter().for_each(|statement| self.format_statement(statement));
+        self.close_block("}", input.span.hi);
+    }
+
+    fn format_conditional(&mut self, input: &ConditionalStatement) {
+        let mut header = "if ".to_string();
+        let mut conditional = input;
+        loop {
+            let condition = self.format_condition(&conditional.condition, header.len());
+            self.open_block(&format!("{header}{condition} {{"), conditional.then.span.lo);
+            conditional.then.statements.iter().for_each(|statement| self.format_statement(statement));
+            match conditional.otherwise.as_deref() {
+                Some(Statement::Conditional(otherwise)) => {
+                    self.comments_before(conditional.then.span.hi - BytePos(1));
+                    self.indent -= 1;
+                    header = "} else if ".to_string();
+                    conditional = otherwise;
+                }
+                Some(Statement::Block(otherwise)) => {
+                    self.comments_before(conditional.then.span.hi - BytePos(1));
+                    self.indent -= 1;
+                    self.open_block("} else {", otherwise.span.lo);
+                    otherwise.statements.iter().for_each(|statement| self.format_statement(statement));
+                    return self.close_block("}", otherwise.span.hi);
+                }
+                // Note that the parser reports an error if the `else` is followed by any other statement.
+                _ => return self.close_block("}", conditional.then.span.hi),
+            }
+        }
+    }
+
+    fn format_iteration(&mut self, input: &IterationStatement) {
+        let header = format!("for {}: {} in ", input.variable, self.format_type(&input.type_));
+        let range = match &input.range {
+            IterationRange::Range { start, stop, inclusive, step: None, .. } => {
+                let dots = if *inclusive { "..=" } else { ".." };
+                let start = self.format_expression(start, header.len());
+                let stop = self.format_condition(stop, header.len() + start.len() + dots.len());
+                format!("{start}{dots}{stop}")
+            }
+            IterationRange::Range { start, stop, inclusive, step: Some(step), .. } => {
+                let dots = if *inclusive { "..=" } else { ".." };
+                let start = self.format_expression(start, header.len() + 1);
+                let stop = self.format_expression(stop, header.len() + start.len() + dots.len() + 1);
+                let step = self.format_expression(step, header.len() + start.len() + dots.len() + stop.len() + 8);
+                format!("({start}{dots}{stop}).step({step})")
+            }
+            IterationRange::Array { array } => self.format_condition(array, header.len()),
+        };
+        self.format_block(&format!("{header}{range}"), &input.block);
+    }
+
+    fn format_match(&mut self, input: &MatchStatement) {
+        let expression = self.format_condition(&input.expression, 6);
+        self.open_block(&format!("match {expression} {{"), input.expression.span().hi);
+        for arm in input.arms.iter() {
+            self.element(arm.span.lo, arm.block.span.lo, arm.span.hi, |f| {
+                f.format_block(&format!("{} =>", arm.pattern), &arm.block)
+            });
+        }
+        self.close_block("}", input.span.hi);
+    }
+
+    fn format_assert(&mut self, input: &AssertStatement) {
+        let assertion = match &input.variant {
+            AssertVariant::Assert(expression) => self.format_list("assert(", &[expression], ")", 0, false),
+            AssertVariant::AssertEq(left, right) => self.format_list("assert_eq(", &[left, right], ")", 0, false),
+            AssertVariant::AssertNeq(left, right) => self.format_list("assert_neq(", &[left, right], ")", 0, false),
+        };
+        self.line(&format!("{assertion};"));
+    }
+
+    fn format_assign(&mut self, input: &AssignStatement) {
+        let place = self.format_expression(&input.place, 0);
+        // The parser expands an assignment like `x += 1` into `x = x + 1`, using the span of the statement for the addition.
+        let (operator, value) = match &input.value {
+            Expression::Binary(binary) if binary.span.lo == input.place.span().lo => {
+                (format!("{}=", binary.op), &*binary.right)
+            }
+            value => ("=".to_string(), value),
+        };
+        let value = self.format_expression(value, place.len() + operator.len() + 2);
+        self.line(&format!("{place} {operator} {value};"));
+    }
+
+    pub(super) fn format_const(&mut self, input: &ConstDeclaration) {
+        let declaration = format!("const {}: {} = ", input.place, self.format_type(&input.type_));
+        let value = self.format_expression(&input.value, declaration.len());
+        self.line(&format!("{declaration}{value};"));
+    }
+
+    fn format_definition(&mut self, input: &DefinitionStatement) {
+        let place = self.format_expression(&input.place, 4);
+        let declaration = format!("let {place}: {} = ", self.format_type(&input.type_));
+        let value = self.format_expression(&input.value, declaration.len());
+        self.line(&format!("{declaration}{value};"));
+    }
+
+    fn format_expression_statement(&mut self, input: &ExpressionStatement) {
+        let expression = self.format_expression(&input.expression, 0);
+        self.line(&format!("{expression};"));
+    }
+
+    fn format_return(&mut self, input: &ReturnStatement) {
+        let mut statement = "return".to_string();
+        if !matches!(input.expression, Expression::Unit(_)) {
+            statement = format!("{statement} {}", self.format_expression(&input.expression, statement.len() + 1));
+        }
+        if let Some(arguments) = &input.finalize_arguments {
+            let arguments = arguments.iter().collect::<Vec<_>>();
+            let finalize = self.format_list(" then finalize(", &arguments, ")", statement.len(), true);
+            statement.push_str(&finalize);
+        }
+        self.line(&format!("{statement};"));
+    }
+}
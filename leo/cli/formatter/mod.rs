@@ -0,0 +1,211 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The formatter for Leo files, which prints the AST of a file in a canonical style.
+//!
+//! The AST does not keep the comments in a file, so they are collected separately and printed with the items,
+//! members, and statements that they precede or follow on the same line.
+//! Comments inside expressions, or in the signature of a function, are moved above the item or statement that contains them.
+//! The blank lines between items and statements are kept, although consecutive blank lines are merged into one.
+
+mod format_expression;
+
+mod format_program;
+
+mod format_statement;
+
+use leo_errors::{emitter::Handler, Result};
+use leo_package::source::SourceDirectory;
+use leo_span::{
+    source_map::FileName,
+    span::{BytePos, Pos},
+    symbol::with_session_globals,
+    Span,
+};
+
+use std::path::Path;
+
+/// The maximum width of a line, beyond which lists of arguments, elements, members, and inputs are split across lines.
+const MAX_WIDTH: usize = 100;
+
+/// The number of spaces by which each block is indented.
+const INDENT_WIDTH: usize = 4;
+
+/// Returns the source of the Leo file at the given path, formatted in the canonical style.
+/// Note that the file must parse, and that this requires a session.
+pub fn format(source: &str, path: &Path, handler: &Handler) -> Result<String> {
+    let source_file = with_session_globals(|s| s.source_map.new_source(source, FileName::Real(path.to_path_buf())));
+    let comments = leo_parser::parse_comments(&source_file.src, source_file.start_pos)?;
+    let mut formatter = Formatter::new(&source_file.src, source_file.start_pos, comments);
+
+    // Note that the parser recovers from some errors, which are reported to the handler.
+    let node_builder = Default::default();
+    match SourceDirectory::is_module(path) {
+        true => {
+            let module = leo_parser::parse_module(handler, &node_builder, &source_file.src, source_file.start_pos)?;
+            handler.last_err().map_err(|error| *error)?;
+            formatter.format_module(&module);
+        }
+        false => {
+            let ast = leo_parser::parse_ast(handler, &node_builder, &source_file.src, source_file.start_pos)?;
+            handler.last_err().map_err(|error| *error)?;
+            formatter.format_program(ast.as_repr());
+        }
+    }
+    Ok(formatter.output)
+}
+
+/// Prints an AST in the canonical style, along with the comments of its source.
+struct Formatter<'a> {
+    /// The source that the AST was parsed from.
+    source: &'a str,
+    /// The position of the start of the source.
+    start_pos: BytePos,
+    /// The comments in the source, with their spans, in reverse order.
+    comments: Vec<(String, Span)>,
+    /// The formatted source.
+    output: String,
+    /// The current level of indentation.
+    indent: usize,
+    /// The position in the source of the end of the last item, statement, or comment printed.
+    last_pos: BytePos,
+    /// Whether nothing has been printed since the start of the current block.
+    at_block_start: bool,
+}
+
+impl<'a> Formatter<'a> {
+    fn new(source: &'a str, start_pos: BytePos, comments: Vec<(String, Span)>) -> Self {
+        // Line comments include their line break, which is left out so that it can be counted as a blank line.
+        let mut comments = comments
+            .into_iter()
+            .map(|(comment, span)| match comment.strip_suffix('\n') {
+                Some(stripped) => (stripped.trim_end().to_string(), Span::new(span.lo, span.hi - BytePos(1))),
+                None => (comment.trim_end().to_string(), span),
+            })
+            .collect::<Vec<_>>();
+        comments.reverse();
+        Self {
+            source,
+            start_pos,
+            comments,
+            output: String::new(),
+            indent: 0,
+            last_pos: start_pos,
+            at_block_start: true,
+        }
+    }
+
+    /// Returns the text of the source between the given positions.
+    fn source_between(&self, lo: BytePos, hi: BytePos) -> &'a str {
+        let lo = lo.0.saturating_sub(self.start_pos.0) as usize;
+        let hi = hi.0.saturating_sub(self.start_pos.0) as usize;
+        self.source.get(lo..hi.max(lo)).unwrap_or_default()
+    }
+
+    /// Returns the text of the source before the given position.
+    fn source_before(&self, pos: BytePos) -> &'a str {
+        self.source_between(self.start_pos, pos)
+    }
+
+    /// Returns the text of the source after the given position.
+    fn source_after(&self, pos: BytePos) -> &'a str {
+        self.source_between(pos, self.start_pos + BytePos::from_usize(self.source.len()))
+    }
+
+    /// Returns the indentation of the current line.
+    fn indentation(&self) -> String {
+        " ".repeat(self.indent * INDENT_WIDTH)
+    }
+
+    /// Returns whether the given text fits on the current line after `used` columns.
+    fn fits(&self, used: usize, text: &str) -> bool {
+        !text.contains('\n') && self.indent * INDENT_WIDTH + used + text.chars().count() <= MAX_WIDTH
+    }
+
+    /// Prints a line at the current indentation.
+    fn line(&mut self, text: &str) {
+        self.output.push_str(&self.indentation());
+        self.output.push_str(text);
+        self.output.push('\n');
+        self.at_block_start = false;
+    }
+
+    /// Prints the line opening a block, e.g. `if x {`, and indents the lines that follow.
+    fn open_block(&mut self, text: &str, pos: BytePos) {
+        self.line(text);
+        self.indent += 1;
+        self.last_pos = pos;
+        self.at_block_start = true;
+    }
+
+    /// Prints the line closing a block ending at the given position, after the comments left in the block.
+    fn close_block(&mut self, text: &str, hi: BytePos) {
+        self.comments_before(hi - BytePos(1));
+        self.indent -= 1;
+        self.line(text);
+        self.last_pos = hi;
+        self.trailing_comment();
+    }
+
+    /// Prints an item or statement spanning `lo` to `hi`, using `print`.
+    /// The comments before `header_end` are printed above it, and a comment following it on the same line is kept there.
+    fn element(&mut self, lo: BytePos, header_end: BytePos, hi: BytePos, print: impl FnOnce(&mut Self)) {
+        self.comments_before(header_end);
+        self.blank_line_before(lo);
+        print(self);
+        self.last_pos = self.last_pos.max(hi);
+        self.trailing_comment();
+    }
+
+    /// Prints a blank line if there is one in the source between the last element printed and the given position.
+    fn blank_line_before(&mut self, pos: BytePos) {
+        if !self.at_block_start && self.source_between(self.last_pos, pos).matches('\n').count() > 1 {
+            self.output.push('\n');
+        }
+    }
+
+    /// Prints the comments that start before the given position, each on its own lines.
+    fn comments_before(&mut self, pos: BytePos) {
+        while let Some((comment, span)) = self.comments.pop() {
+            if span.lo >= pos {
+                self.comments.push((comment, span));
+                break;
+            }
+            self.blank_line_before(span.lo);
+            self.line(&comment);
+            self.last_pos = span.hi;
+        }
+    }
+
+    /// Appends the next comment to the last line printed, if it follows the last element printed on the same line.
+    fn trailing_comment(&mut self) {
+        match self.comments.last() {
+            Some((comment, span))
+                if span.lo >= self.last_pos
+                    && !comment.contains('\n')
+                    && !self.source_between(self.last_pos, span.lo).contains('\n') =>
+            {
+                self.output.pop();
+                self.output.push(' ');
+                self.output.push_str(comment);
+                self.output.push('\n');
+                self.last_pos = span.hi;
+                self.comments.pop();
+            }
+            _ => {}
+        }
+    }
+}
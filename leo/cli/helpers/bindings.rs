@@ -0,0 +1,749 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{Abi, AbiParameter, AbiStruct, AbiTransition};
+
+/// The type of a value in the generated bindings, parsed from its type in an ABI.
+enum BindingType {
+    Boolean,
+    /// An integer type, e.g. `u64`.
+    Integer(String),
+    /// An `address`, `field`, `group`, `scalar`, or `signature`, which is written as the Aleo literal it is.
+    Literal,
+    Array(Box<BindingType>, usize),
+    /// A struct or record declared by the program.
+    Struct(String),
+    /// A record declared by another program, which is written as the Aleo value it is.
+    External,
+}
+
+impl BindingType {
+    /// Parses a type written in Leo syntax, as it is in an ABI.
+    fn parse(type_: &str) -> Self {
+        let array = type_.strip_prefix('[').and_then(|array| array.strip_suffix(']'));
+        if let Some((element, length)) = array.and_then(|array| array.rsplit_once(';')) {
+            if let Ok(length) = length.trim().parse() {
+                return BindingType::Array(Box::new(BindingType::parse(element.trim())), length);
+            }
+        }
+        match type_ {
+            "boolean" => BindingType::Boolean,
+            "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128" => {
+                BindingType::Integer(type_.to_string())
+            }
+            "address" | "field" | "group" | "scalar" | "signature" => BindingType::Literal,
+            _ if type_.contains('/') => BindingType::External,
+            _ => BindingType::Struct(type_.to_string()),
+        }
+    }
+
+    /// Returns the Rust type of the values of this type.
+    fn rust(&self) -> String {
+        match self {
+            BindingType::Boolean => "bool".to_string(),
+            BindingType::Integer(integer) => integer.clone(),
+            BindingType::Literal | BindingType::External => "String".to_string(),
+            BindingType::Array(element, length) => format!("[{}; {length}]", element.rust()),
+            BindingType::Struct(name) => pascal_case(name),
+        }
+    }
+
+    /// Returns the TypeScript type of the values of this type.
+    /// Integers of up to 32 bits are numbers, and larger integers are bigints.
+    fn typescript(&self) -> String {
+        match self {
+            BindingType::Boolean => "boolean".to_string(),
+            BindingType::Integer(integer) if integer.ends_with("64") || integer.ends_with("128") => {
+                "bigint".to_string()
+            }
+            BindingType::Integer(_) => "number".to_string(),
+            BindingType::Literal | BindingType::External => "string".to_string(),
+            BindingType::Array(element, _) => format!("{}[]", element.typescript()),
+            BindingType::Struct(name) => pascal_case(name),
+        }
+    }
+
+    /// Returns a TypeScript expression that writes `value`, of this type, as an Aleo plaintext.
+    fn typescript_to_aleo(&self, value: &str) -> String {
+        match self {
+            BindingType::Boolean => format!("String({value})"),
+            BindingType::Integer(integer) => format!("`${{{value}}}{integer}`"),
+            BindingType::Literal | BindingType::External => value.to_string(),
+            BindingType::Array(element, _) => {
+                format!("`[${{{value}.map((element) => {}).join(\", \")}}]`", element.typescript_to_aleo("element"))
+            }
+            BindingType::Struct(name) => format!("toAleo{}({value})", pascal_case(name)),
+        }
+    }
+
+    /// Returns a TypeScript expression that reads a value of this type from `value`, a parsed Aleo plaintext.
+    fn typescript_from_aleo(&self, value: &str) -> String {
+        match self {
+            BindingType::Boolean => format!("readBoolean({value})"),
+            BindingType::Integer(integer) => match self.typescript().as_str() {
+                "bigint" => format!("BigInt(readInteger({value}, \"{integer}\"))"),
+                _ => format!("Number(readInteger({value}, \"{integer}\"))"),
+            },
+            BindingType::Literal | BindingType::External => format!("readLiteral({value})"),
+            BindingType::Array(element, _) => {
+                format!("readElements({value}).map((element) => {})", element.typescript_from_aleo("element"))
+            }
+            BindingType::Struct(name) => format!("fromAleo{}({value})", pascal_case(name)),
+        }
+    }
+}
+
+/// Generates Rust bindings to the program described by an ABI.
+/// The bindings have no dependencies, and are meant to be included as a module, e.g. `mod token;`.
+pub fn rust(abi: &Abi) -> String {
+    let mut code = format!("//! Bindings to `{}`, generated by `leo bindgen`.\n\n", abi.program);
+    code.push_str(RUST_RUNTIME);
+    for struct_ in abi.structs.iter().chain(abi.records.iter()) {
+        code.push_str(&rust_struct(abi, struct_));
+    }
+    for transition in abi.transitions.iter() {
+        code.push_str(&rust_transition(abi, transition));
+    }
+    code
+}
+
+/// Generates TypeScript bindings to the program described by an ABI.
+pub fn typescript(abi: &Abi) -> String {
+    let mut code = format!("// Bindings to `{}`, generated by `leo bindgen`.\n\n", abi.program);
+    code.push_str(TYPESCRIPT_RUNTIME);
+    for struct_ in abi.structs.iter().chain(abi.records.iter()) {
+        code.push_str(&typescript_struct(abi, struct_));
+    }
+    for transition in abi.transitions.iter() {
+        code.push_str(&typescript_transition(abi, transition));
+    }
+    code
+}
+
+/// Generates a Rust struct for a struct or record, which is written to and read from Aleo plaintexts.
+fn rust_struct(abi: &Abi, struct_: &AbiStruct) -> String {
+    let name = pascal_case(&struct_.name);
+    let is_record = abi.records.contains(struct_);
+    let kind = if is_record { "record" } else { "struct" };
+
+    let mut fields = struct_
+        .fields
+        .iter()
+        .map(|field| format!("    pub {}: {},\n", parameter_name(field), BindingType::parse(&field.type_).rust()))
+        .collect::<String>();
+    // Each member is written by a `name: {}` pair in the format string, and an argument that writes its value.
+    let mut members = struct_.fields.iter().map(|field| format!("{}: {{}}", parameter_name(field))).collect::<Vec<_>>();
+    let mut arguments = struct_
+        .fields
+        .iter()
+        .map(|field| match &field.mode {
+            // Each literal in an entry of a record has the visibility of the entry.
+            Some(mode) if is_record => format!("self.{}.to_entry(\"{mode}\")", parameter_name(field)),
+            _ => format!("self.{}.to_aleo()", parameter_name(field)),
+        })
+        .collect::<Vec<_>>();
+    let mut reads = struct_
+        .fields
+        .iter()
+        .map(|field| format!("            {0}: FromAleo::from_aleo(value.member(\"{0}\")?)?,\n", parameter_name(field)))
+        .collect::<String>();
+    if is_record {
+        fields.push_str("    pub _nonce: String,\n");
+        members.push("_nonce: {}.public".to_string());
+        arguments.push("self._nonce".to_string());
+        reads.push_str("            _nonce: FromAleo::from_aleo(value.member(\"_nonce\")?)?,\n");
+    }
+
+    format!(
+        "
+/// The {kind} `{}` of `{}`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct {name} {{
+{fields}}}
+
+impl ToAleo for {name} {{
+    fn to_aleo(&self) -> String {{
+        format!(\"{{{{ {} }}}}\", {})
+    }}
+}}
+
+impl FromAleo for {name} {{
+    fn from_aleo(value: &AleoValue) -> Result<Self, String> {{
+        Ok(Self {{
+{reads}        }})
+    }}
+}}
+",
+        struct_.name,
+        abi.program,
+        members.join(", "),
+        arguments.join(", "),
+    )
+}
+
+/// Generates a Rust module for a transition, with functions that write its inputs and read its outputs.
+fn rust_transition(abi: &Abi, transition: &AbiTransition) -> String {
+    let parameters = transition
+        .inputs
+        .iter()
+        .map(|input| format!("{}: {}", parameter_name(input), BindingType::parse(&input.type_).rust()))
+        .collect::<Vec<_>>();
+    let inputs =
+        transition.inputs.iter().map(|input| format!("{}.to_aleo()", parameter_name(input))).collect::<Vec<_>>();
+
+    let output_types = transition.outputs.iter().map(|output| BindingType::parse(&output.type_)).collect::<Vec<_>>();
+    let outputs = output_types
+        .iter()
+        .enumerate()
+        .map(|(index, type_)| match type_ {
+            BindingType::External => format!("outputs[{index}].clone()"),
+            _ => format!("parse_output(&outputs[{index}])?"),
+        })
+        .collect::<Vec<_>>();
+    let (output_type, output) = match output_types.len() {
+        1 => (output_types[0].rust(), outputs[0].clone()),
+        _ => (
+            format!("({})", output_types.iter().map(|type_| type_.rust()).collect::<Vec<_>>().join(", ")),
+            format!("({})", outputs.join(", ")),
+        ),
+    };
+
+    format!(
+        "
+/// The transition `{program}/{name}`.
+pub mod {name} {{
+    use super::*;
+
+    /// The name of the transition.
+    pub const NAME: &str = \"{name}\";
+
+    /// Writes the inputs of the transition as Aleo values, e.g. for `leo run`.
+    pub fn inputs({}) -> Vec<String> {{
+        vec![{}]
+    }}
+
+    /// Reads the outputs of the transition from the Aleo values it returned.
+    pub fn outputs(outputs: &[String]) -> Result<{output_type}, String> {{
+        expect_outputs(outputs, {})?;
+        Ok({output})
+    }}
+}}
+",
+        parameters.join(", "),
+        inputs.join(", "),
+        outputs.len(),
+        program = abi.program,
+        name = transition.name,
+    )
+}
+
+/// Generates a TypeScript interface for a struct or record, and functions that write it to and read it from Aleo
+/// plaintexts.
+fn typescript_struct(abi: &Abi, struct_: &AbiStruct) -> String {
+    let name = pascal_case(&struct_.name);
+    let is_record = abi.records.contains(struct_);
+    let kind = if is_record { "record" } else { "struct" };
+
+    let mut fields = struct_
+        .fields
+        .iter()
+        .map(|field| format!("  {}: {};\n", parameter_name(field), BindingType::parse(&field.type_).typescript()))
+        .collect::<String>();
+    let mut members = struct_
+        .fields
+        .iter()
+        .map(|field| {
+            let value =
+                BindingType::parse(&field.type_).typescript_to_aleo(&format!("value.{}", parameter_name(field)));
+            match &field.mode {
+                // Each literal in an entry of a record has the visibility of the entry.
+                Some(mode) if is_record => {
+                    format!("{}: ${{toEntry(parseAleo({value}), \"{mode}\")}}", parameter_name(field))
+                }
+                _ => format!("{}: ${{{value}}}", parameter_name(field)),
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut reads = struct_
+        .fields
+        .iter()
+        .map(|field| {
+            let member = format!("readMember(value, \"{}\")", parameter_name(field));
+            format!(
+                "    {}: {},\n",
+                parameter_name(field),
+                BindingType::parse(&field.type_).typescript_from_aleo(&member)
+            )
+        })
+        .collect::<String>();
+    if is_record {
+        fields.push_str("  _nonce: string;\n");
+        members.push("_nonce: ${value._nonce}.public".to_string());
+        reads.push_str("    _nonce: readLiteral(readMember(value, \"_nonce\")),\n");
+    }
+
+    format!(
+        "
+/** The {kind} `{}` of `{}`. */
+export interface {name} {{
+{fields}}}
+
+export function toAleo{name}(value: {name}): string {{
+  return `{{ {} }}`;
+}}
+
+export function fromAleo{name}(value: AleoValue): {name} {{
+  return {{
+{reads}  }};
+}}
+",
+        struct_.name,
+        abi.program,
+        members.join(", "),
+    )
+}
+
+/// Generates a TypeScript object for a transition, with functions that write its inputs and read its outputs.
+fn typescript_transition(abi: &Abi, transition: &AbiTransition) -> String {
+    let parameters = transition
+        .inputs
+        .iter()
+        .map(|input| format!("{}: {}", parameter_name(input), BindingType::parse(&input.type_).typescript()))
+        .collect::<Vec<_>>();
+    let inputs = transition
+        .inputs
+        .iter()
+        .map(|input| BindingType::parse(&input.type_).typescript_to_aleo(&parameter_name(input)))
+        .collect::<Vec<_>>();
+
+    let output_types = transition.outputs.iter().map(|output| BindingType::parse(&output.type_)).collect::<Vec<_>>();
+    let outputs = output_types
+        .iter()
+        .enumerate()
+        .map(|(index, type_)| match type_ {
+            BindingType::External => format!("outputs[{index}]"),
+            _ => type_.typescript_from_aleo(&format!("parseAleo(outputs[{index}])")),
+        })
+        .collect::<Vec<_>>();
+    let (output_type, output) = match output_types.len() {
+        0 => ("void".to_string(), String::new()),
+        1 => (output_types[0].typescript(), format!("\n    return {};", outputs[0])),
+        _ => (
+            format!("[{}]", output_types.iter().map(|type_| type_.typescript()).collect::<Vec<_>>().join(", ")),
+            format!("\n    return [{}];", outputs.join(", ")),
+        ),
+    };
+
+    format!(
+        "
+/** The transition `{program}/{name}`. */
+export const {} = {{
+  name: \"{name}\",
+
+  /** Writes the inputs of the transition as Aleo values, e.g. for `leo run`. */
+  inputs({}): string[] {{
+    return [{}];
+  }},
+
+  /** Reads the outputs of the transition from the Aleo values it returned. */
+  outputs(outputs: string[]): {output_type} {{
+    expectOutputs(outputs, {});{output}
+  }},
+}};
+",
+        camel_case(&transition.name),
+        parameters.join(", "),
+        inputs.join(", "),
+        outputs.len(),
+        program = abi.program,
+        name = transition.name,
+    )
+}
+
+/// Returns the name of a parameter or field, which outputs do not have.
+fn parameter_name(parameter: &AbiParameter) -> String {
+    parameter.name.clone().unwrap_or_default()
+}
+
+/// Converts a name in snake case, as Leo names usually are, into Pascal case, e.g. `token_info` into `TokenInfo`.
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|first| first.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Converts a name in snake case into camel case, e.g. `transfer_private` into `transferPrivate`.
+fn camel_case(name: &str) -> String {
+    let pascal_case = pascal_case(name);
+    let mut chars = pascal_case.chars();
+    chars.next().map(|first| first.to_lowercase().chain(chars).collect()).unwrap_or_default()
+}
+
+/// The Rust code that the generated bindings build on, which parses and writes Aleo plaintexts.
+const RUST_RUNTIME: &str = r##"#![allow(dead_code)]
+
+use std::fmt;
+
+/// An Aleo plaintext, parsed from a value such as `{ owner: aleo1....private, amount: 10u64.private }`.
+/// The visibilities of its literals are dropped.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AleoValue {
+    Literal(String),
+    Struct(Vec<(String, AleoValue)>),
+    Array(Vec<AleoValue>),
+}
+
+impl AleoValue {
+    /// Parses an Aleo plaintext.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut rest = input;
+        let value = Self::parse_value(&mut rest)?;
+        match rest.trim().is_empty() {
+            true => Ok(value),
+            false => Err(format!("Unexpected `{}` after the value `{value}`", rest.trim())),
+        }
+    }
+
+    fn parse_value(input: &mut &str) -> Result<Self, String> {
+        *input = input.trim_start();
+        if let Some(rest) = input.strip_prefix('{') {
+            *input = rest;
+            let mut members = Vec::new();
+            loop {
+                *input = input.trim_start();
+                if let Some(rest) = input.strip_prefix('}') {
+                    *input = rest;
+                    return Ok(AleoValue::Struct(members));
+                }
+                let (name, rest) = input.split_once(':').ok_or_else(|| format!("Expected a member at `{input}`"))?;
+                *input = rest;
+                members.push((name.trim().to_string(), Self::parse_value(input)?));
+                *input = input.trim_start();
+                if let Some(rest) = input.strip_prefix(',') {
+                    *input = rest;
+                }
+            }
+        }
+        if let Some(rest) = input.strip_prefix('[') {
+            *input = rest;
+            let mut elements = Vec::new();
+            loop {
+                *input = input.trim_start();
+                if let Some(rest) = input.strip_prefix(']') {
+                    *input = rest;
+                    return Ok(AleoValue::Array(elements));
+                }
+                elements.push(Self::parse_value(input)?);
+                *input = input.trim_start();
+                if let Some(rest) = input.strip_prefix(',') {
+                    *input = rest;
+                }
+            }
+        }
+        let end = input.find(|c: char| c == ',' || c == '}' || c == ']' || c.is_whitespace()).unwrap_or(input.len());
+        let (literal, rest) = input.split_at(end);
+        if literal.is_empty() {
+            return Err(format!("Expected a value at `{input}`"));
+        }
+        *input = rest;
+        let literal = [".private", ".public", ".constant"]
+            .iter()
+            .find_map(|visibility| literal.strip_suffix(visibility))
+            .unwrap_or(literal);
+        Ok(AleoValue::Literal(literal.to_string()))
+    }
+
+    /// Returns the member of a struct or record with the given name.
+    pub fn member(&self, name: &str) -> Result<&AleoValue, String> {
+        match self {
+            AleoValue::Struct(members) => members
+                .iter()
+                .find(|(member, _)| member == name)
+                .map(|(_, value)| value)
+                .ok_or_else(|| format!("Expected a member `{name}` in `{self}`")),
+            _ => Err(format!("Expected a struct, found `{self}`")),
+        }
+    }
+
+    /// Returns the literal that the value is.
+    pub fn literal(&self) -> Result<&str, String> {
+        match self {
+            AleoValue::Literal(literal) => Ok(literal),
+            _ => Err(format!("Expected a literal, found `{self}`")),
+        }
+    }
+
+    /// Writes the value as an entry of a record, in which each literal has the given visibility.
+    pub fn to_entry(&self, visibility: &str) -> String {
+        match self {
+            AleoValue::Literal(literal) => format!("{literal}.{visibility}"),
+            AleoValue::Struct(members) => {
+                let members = members.iter().map(|(name, value)| format!("{name}: {}", value.to_entry(visibility)));
+                format!("{{ {} }}", members.collect::<Vec<_>>().join(", "))
+            }
+            AleoValue::Array(elements) => {
+                let elements = elements.iter().map(|element| element.to_entry(visibility));
+                format!("[{}]", elements.collect::<Vec<_>>().join(", "))
+            }
+        }
+    }
+}
+
+impl fmt::Display for AleoValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AleoValue::Literal(literal) => write!(f, "{literal}"),
+            AleoValue::Struct(members) => {
+                let members = members.iter().map(|(name, value)| format!("{name}: {value}"));
+                write!(f, "{{ {} }}", members.collect::<Vec<_>>().join(", "))
+            }
+            AleoValue::Array(elements) => {
+                let elements = elements.iter().map(|element| element.to_string());
+                write!(f, "[{}]", elements.collect::<Vec<_>>().join(", "))
+            }
+        }
+    }
+}
+
+/// A value that can be written as an Aleo plaintext.
+pub trait ToAleo {
+    fn to_aleo(&self) -> String;
+
+    /// Writes the value as an entry of a record, in which each literal has the given visibility.
+    fn to_entry(&self, visibility: &str) -> String {
+        match AleoValue::parse(&self.to_aleo()) {
+            Ok(value) => value.to_entry(visibility),
+            Err(_) => self.to_aleo(),
+        }
+    }
+}
+
+/// A value that can be read from an Aleo plaintext.
+pub trait FromAleo: Sized {
+    fn from_aleo(value: &AleoValue) -> Result<Self, String>;
+}
+
+/// Reads an output of a transition.
+pub fn parse_output<T: FromAleo>(output: &str) -> Result<T, String> {
+    T::from_aleo(&AleoValue::parse(output)?)
+}
+
+/// Checks that a transition returned the expected number of outputs.
+fn expect_outputs(outputs: &[String], expected: usize) -> Result<(), String> {
+    match outputs.len() == expected {
+        true => Ok(()),
+        false => Err(format!("Expected {expected} outputs, found {}", outputs.len())),
+    }
+}
+
+macro_rules! integer {
+    ($($integer:ident),*) => {$(
+        impl ToAleo for $integer {
+            fn to_aleo(&self) -> String {
+                format!("{self}{}", stringify!($integer))
+            }
+        }
+
+        impl FromAleo for $integer {
+            fn from_aleo(value: &AleoValue) -> Result<Self, String> {
+                let literal = value.literal()?;
+                literal
+                    .strip_suffix(stringify!($integer))
+                    .and_then(|digits| digits.parse().ok())
+                    .ok_or_else(|| format!("Expected a `{}`, found `{literal}`", stringify!($integer)))
+            }
+        }
+    )*};
+}
+
+integer!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl ToAleo for bool {
+    fn to_aleo(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl FromAleo for bool {
+    fn from_aleo(value: &AleoValue) -> Result<Self, String> {
+        match value.literal()? {
+            "true" => Ok(true),
+            "false" => Ok(false),
+            literal => Err(format!("Expected a `boolean`, found `{literal}`")),
+        }
+    }
+}
+
+/// Addresses, fields, groups, scalars, and signatures are written as the Aleo literals they are, e.g. `1field`.
+impl ToAleo for String {
+    fn to_aleo(&self) -> String {
+        self.clone()
+    }
+}
+
+impl FromAleo for String {
+    fn from_aleo(value: &AleoValue) -> Result<Self, String> {
+        value.literal().map(|literal| literal.to_string())
+    }
+}
+
+impl<T: ToAleo, const N: usize> ToAleo for [T; N] {
+    fn to_aleo(&self) -> String {
+        format!("[{}]", self.iter().map(|element| element.to_aleo()).collect::<Vec<_>>().join(", "))
+    }
+}
+
+impl<T: FromAleo, const N: usize> FromAleo for [T; N] {
+    fn from_aleo(value: &AleoValue) -> Result<Self, String> {
+        match value {
+            AleoValue::Array(elements) => elements
+                .iter()
+                .map(T::from_aleo)
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()
+                .map_err(|_| format!("Expected an array of {N} elements, found `{value}`")),
+            _ => Err(format!("Expected an array, found `{value}`")),
+        }
+    }
+}
+"##;
+
+/// The TypeScript code that the generated bindings build on, which parses and writes Aleo plaintexts.
+const TYPESCRIPT_RUNTIME: &str = r##"/**
+ * An Aleo plaintext, parsed from a value such as `{ owner: aleo1....private, amount: 10u64.private }`.
+ * The visibilities of its literals are dropped.
+ */
+export type AleoValue = string | AleoValue[] | { [member: string]: AleoValue };
+
+/** Parses an Aleo plaintext. */
+export function parseAleo(input: string): AleoValue {
+  let rest = input;
+  const value = (): AleoValue => {
+    rest = rest.trimStart();
+    if (rest.startsWith("{")) {
+      rest = rest.slice(1);
+      const members: { [member: string]: AleoValue } = {};
+      for (;;) {
+        rest = rest.trimStart();
+        if (rest.startsWith("}")) {
+          rest = rest.slice(1);
+          return members;
+        }
+        const colon = rest.indexOf(":");
+        if (colon < 0) {
+          throw new Error(`Expected a member at \`${rest}\``);
+        }
+        const name = rest.slice(0, colon).trim();
+        rest = rest.slice(colon + 1);
+        members[name] = value();
+        rest = rest.trimStart();
+        if (rest.startsWith(",")) {
+          rest = rest.slice(1);
+        }
+      }
+    }
+    if (rest.startsWith("[")) {
+      rest = rest.slice(1);
+      const elements: AleoValue[] = [];
+      for (;;) {
+        rest = rest.trimStart();
+        if (rest.startsWith("]")) {
+          rest = rest.slice(1);
+          return elements;
+        }
+        elements.push(value());
+        rest = rest.trimStart();
+        if (rest.startsWith(",")) {
+          rest = rest.slice(1);
+        }
+      }
+    }
+    const match = /^[^\s,\]}]+/.exec(rest);
+    if (match === null) {
+      throw new Error(`Expected a value at \`${rest}\``);
+    }
+    rest = rest.slice(match[0].length);
+    return match[0].replace(/\.(private|public|constant)$/, "");
+  };
+  const result = value();
+  if (rest.trim() !== "") {
+    throw new Error(`Unexpected \`${rest.trim()}\` after the value`);
+  }
+  return result;
+}
+
+/** Writes a value as an entry of a record, in which each literal has the given visibility. */
+export function toEntry(value: AleoValue, visibility: string): string {
+  if (typeof value === "string") {
+    return `${value}.${visibility}`;
+  }
+  if (Array.isArray(value)) {
+    return `[${value.map((element) => toEntry(element, visibility)).join(", ")}]`;
+  }
+  return `{ ${Object.entries(value)
+    .map(([name, member]) => `${name}: ${toEntry(member, visibility)}`)
+    .join(", ")} }`;
+}
+
+function readLiteral(value: AleoValue): string {
+  if (typeof value !== "string") {
+    throw new Error(`Expected a literal, found ${JSON.stringify(value)}`);
+  }
+  return value;
+}
+
+function readInteger(value: AleoValue, type: string): string {
+  const digits = readLiteral(value);
+  if (!digits.endsWith(type)) {
+    throw new Error(`Expected a \`${type}\`, found \`${digits}\``);
+  }
+  return digits.slice(0, -type.length);
+}
+
+function readBoolean(value: AleoValue): boolean {
+  switch (readLiteral(value)) {
+    case "true":
+      return true;
+    case "false":
+      return false;
+    default:
+      throw new Error(`Expected a \`boolean\`, found \`${readLiteral(value)}\``);
+  }
+}
+
+function readElements(value: AleoValue): AleoValue[] {
+  if (!Array.isArray(value)) {
+    throw new Error(`Expected an array, found ${JSON.stringify(value)}`);
+  }
+  return value;
+}
+
+function readMember(value: AleoValue, name: string): AleoValue {
+  if (typeof value === "string" || Array.isArray(value) || !(name in value)) {
+    throw new Error(`Expected a member \`${name}\` in ${JSON.stringify(value)}`);
+  }
+  return value[name];
+}
+
+function expectOutputs(outputs: string[], expected: number): void {
+  if (outputs.length !== expected) {
+    throw new Error(`Expected ${expected} outputs, found ${outputs.length}`);
+  }
+}
+"##;
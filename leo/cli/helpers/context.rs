@@ -16,7 +16,7 @@
 
 use super::*;
 use aleo_std;
-use leo_errors::{CliError, PackageError, Result};
+use leo_errors::{emitter::Handler, CliError, PackageError, Result};
 use leo_package::build::{BuildDirectory, BUILD_DIRECTORY_NAME};
 
 use snarkvm::file::Manifest;
@@ -37,11 +37,31 @@ pub struct Context {
     pub path: Option<PathBuf>,
     /// Path to use for the Aleo registry, None when default
     pub home: Option<PathBuf>,
+    /// The format in which errors and warnings are written
+    pub message_format: MessageFormat,
+}
+
+/// The format in which errors and warnings are written
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MessageFormat {
+    /// Colored text, meant to be read by people
+    #[default]
+    Human,
+    /// A JSON object per line, meant to be read by tools
+    Json,
 }
 
 impl Context {
-    pub fn new(path: Option<PathBuf>, home: Option<PathBuf>) -> Result<Context> {
-        Ok(Context { path, home })
+    pub fn new(path: Option<PathBuf>, home: Option<PathBuf>, message_format: MessageFormat) -> Result<Context> {
+        Ok(Context { path, home, message_format })
+    }
+
+    /// Returns a handler that reports errors and warnings in the requested format.
+    pub fn handler(&self) -> Handler {
+        match self.message_format {
+            MessageFormat::Human => Handler::default(),
+            MessageFormat::Json => Handler::json(),
+        }
     }
 
     /// Returns the path of the parent directory to the Leo package.
@@ -0,0 +1,216 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::Node;
+use leo_errors::{CliError, LeoError, Result};
+use leo_interpreter::{Hook, Step, Value};
+use leo_span::{symbol::with_session_globals, Span, Symbol};
+
+use indexmap::{IndexMap, IndexSet};
+use std::io::{BufRead, Write};
+
+/// The help text of the debugger's commands.
+const HELP: &str = "\
+Commands:
+  n, next          Run to the next statement of this function, stepping over calls
+  s, step          Run to the next statement, stepping into calls
+  f, finish        Run until this function returns
+  c, continue      Run to the next breakpoint
+  b, break <line>  Pause before the statements on the given line
+  d, delete <line> Remove the breakpoint on the given line
+  p, print <path>  Print a variable, or a member or element of one, e.g. `p point.x` or `p items[0]`
+  l, locals        Print the local variables
+  q, quit          Stop debugging
+  h, help          Print this help
+An empty line repeats the previous command.";
+
+/// When the debugger pauses next, other than at breakpoints.
+#[derive(Clone, Copy)]
+enum Mode {
+    /// Before the next statement.
+    Step,
+    /// Before the next statement of the function at the given depth, or of a function it returns to.
+    Next(usize),
+    /// Before the next statement of a function that the function at the given depth returns to.
+    Finish(usize),
+    /// Only at breakpoints.
+    Continue,
+}
+
+/// A line-based debugger, which pauses the interpreter at breakpoints and reads commands from `input`.
+pub struct Debugger<R, W> {
+    /// The commands.
+    input: R,
+    /// Where the paused statements and inspected values are written.
+    output: W,
+    /// The lines to pause at.
+    breakpoints: IndexSet<usize>,
+    /// When to pause next.
+    mode: Mode,
+    /// The previous command, which an empty line repeats.
+    previous: String,
+    /// Whether the user quit, which aborts the evaluation.
+    quit: bool,
+}
+
+impl<R: BufRead, W: Write> Debugger<R, W> {
+    /// Initializes a debugger with the given breakpoints.
+    /// Without breakpoints, the debugger pauses before the first statement.
+    pub fn new(input: R, output: W, breakpoints: impl IntoIterator<Item = usize>) -> Self {
+        let breakpoints: IndexSet<usize> = breakpoints.into_iter().collect();
+        let mode = if breakpoints.is_empty() { Mode::Step } else { Mode::Continue };
+        Self { input, output, breakpoints, mode, previous: String::new(), quit: false }
+    }
+
+    /// Returns whether the user quit the debugger.
+    pub fn quit(&self) -> bool {
+        self.quit
+    }
+
+    /// Returns the writer that the debugger writes to.
+    pub fn output(&mut self) -> &mut W {
+        &mut self.output
+    }
+
+    /// Reads and runs commands until one of them resumes the evaluation.
+    fn prompt(&mut self, step: &Step) -> Result<()> {
+        loop {
+            write!(self.output, "(leo) ").map_err(CliError::cli_io_error)?;
+            self.output.flush().map_err(CliError::cli_io_error)?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line).map_err(CliError::cli_io_error)? == 0 {
+                // Without more commands, the evaluation runs to completion.
+                writeln!(self.output).map_err(CliError::cli_io_error)?;
+                self.breakpoints.clear();
+                self.mode = Mode::Continue;
+                return Ok(());
+            }
+            let line = match line.trim() {
+                "" => self.previous.clone(),
+                line => line.to_string(),
+            };
+            self.previous = line.clone();
+
+            let (command, argument) = match line.split_once(char::is_whitespace) {
+                Some((command, argument)) => (command, argument.trim()),
+                None => (line.as_str(), ""),
+            };
+            let message = match command {
+                "n" | "next" => {
+                    self.mode = Mode::Next(step.depth);
+                    return Ok(());
+                }
+                "s" | "step" => {
+                    self.mode = Mode::Step;
+                    return Ok(());
+                }
+                "f" | "finish" => {
+                    self.mode = Mode::Finish(step.depth);
+                    return Ok(());
+                }
+                "c" | "continue" => {
+                    self.mode = Mode::Continue;
+                    return Ok(());
+                }
+                "q" | "quit" => {
+                    self.quit = true;
+                    return Err(LeoError::LastErrorCode(0));
+                }
+                "b" | "break" => match argument.parse::<usize>() {
+                    Ok(line) => {
+                        self.breakpoints.insert(line);
+                        format!("Breakpoint set on line {line}")
+                    }
+                    Err(_) => "Expected a line number, e.g. `b 12`".to_string(),
+                },
+                "d" | "delete" => match argument.parse::<usize>() {
+                    Ok(line) if self.breakpoints.remove(&line) => format!("Breakpoint removed from line {line}"),
+                    Ok(line) => format!("There is no breakpoint on line {line}"),
+                    Err(_) => "Expected a line number, e.g. `d 12`".to_string(),
+                },
+                "p" | "print" => match inspect(step.variables, argument) {
+                    Some(value) => format!("{argument} = {value}"),
+                    None => format!("`{argument}` is not in scope"),
+                },
+                "l" | "locals" => match step.variables.is_empty() {
+                    true => "There are no local variables".to_string(),
+                    false => step
+                        .variables
+                        .iter()
+                        .map(|(name, value)| format!("{name} = {value}"))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                },
+                "h" | "help" => HELP.to_string(),
+                _ => format!("Unknown command `{command}`. Type `h` for help."),
+            };
+            writeln!(self.output, "{message}").map_err(CliError::cli_io_error)?;
+        }
+    }
+}
+
+impl<R: BufRead, W: Write> Hook for Debugger<R, W> {
+    fn before_statement(&mut self, step: &Step) -> Result<()> {
+        let span = step.statement.span();
+        let (file, line, source) = with_session_globals(|session| {
+            let location = session.source_map.span_to_location(span)?;
+            let source = session.source_map.line_contents_of_span(Span::new(span.lo, span.lo))?;
+            Some((location.source_file.name.to_string(), location.line_start, source))
+        })
+        .unwrap_or_default();
+
+        let pause = self.breakpoints.contains(&line)
+            || match self.mode {
+                Mode::Step => true,
+                Mode::Next(depth) => step.depth <= depth,
+                Mode::Finish(depth) => step.depth < depth,
+                Mode::Continue => false,
+            };
+        if !pause {
+            return Ok(());
+        }
+
+        writeln!(self.output, "Paused at {file}:{line} in `{}`", step.function).map_err(CliError::cli_io_error)?;
+        writeln!(self.output, "{line:>4} | {}", source.trim_end()).map_err(CliError::cli_io_error)?;
+        self.prompt(step)
+    }
+}
+
+/// Returns the value at a path such as `point.x`, `items[0]` or `pair.1` among the variables in scope.
+fn inspect<'a>(variables: &'a IndexMap<Symbol, Value>, path: &str) -> Option<&'a Value> {
+    let end = path.find(['.', '[']).unwrap_or(path.len());
+    let mut value = variables.get(&Symbol::intern(path[..end].trim()))?;
+    let mut rest = &path[end..];
+    while !rest.is_empty() {
+        if let Some(index) = rest.strip_prefix('[') {
+            let close = index.find(']')?;
+            // The index may be written with its type, e.g. `items[0u32]`.
+            let digits = index[..close].trim().split(|c: char| !c.is_ascii_digit()).next()?;
+            value = value.element(digits.parse().ok()?)?;
+            rest = &index[close + 1..];
+        } else {
+            let member = rest.strip_prefix('.')?;
+            let end = member.find(['.', '[']).unwrap_or(member.len());
+            value = match member[..end].parse::<usize>() {
+                Ok(index) => value.element(index)?,
+                Err(_) => value.member(Symbol::intern(&member[..end]))?,
+            };
+            rest = &member[end..];
+        }
+    }
+    Some(value)
+}
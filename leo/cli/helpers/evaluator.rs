@@ -0,0 +1,328 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use snarkvm::{
+    circuit::AleoV0,
+    console::network::prelude::{bail, ensure, Result},
+    ledger::store::{helpers::memory::FinalizeMemory, FinalizeStore},
+    prelude::{
+        Authorization,
+        Field,
+        Future,
+        Identifier,
+        Network,
+        PrivateKey,
+        Process,
+        Program,
+        ProgramID,
+        Request,
+        Response,
+        Value,
+        Zero,
+    },
+    synthesizer::{
+        process::{CallStack, FinalizeRegisters, Registers, Stack, StackProgramTypes},
+        program::{
+            Branch,
+            Command,
+            FinalizeGlobalState,
+            FinalizeStoreTrait,
+            Instruction,
+            Operand,
+            RegistersLoad,
+            RegistersSigner,
+            RegistersStore,
+            StackProgram,
+        },
+    },
+};
+
+use rand::{CryptoRng, Rng};
+use std::{
+    collections::HashMap,
+    panic::{catch_unwind, set_hook, take_hook, AssertUnwindSafe},
+    str::FromStr,
+};
+
+/// Evaluates the functions of Aleo programs without producing proofs.
+/// The finalize blocks of evaluated transitions are run against an in-memory mapping store,
+/// which is owned by the evaluator and therefore isolated from any other evaluator.
+pub struct Evaluator {
+    /// The process containing the programs that can be evaluated.
+    process: Process<CurrentNetwork>,
+    /// The mapping store that finalize blocks read from and write to.
+    store: FinalizeStore<CurrentNetwork, FinalizeMemory<CurrentNetwork>>,
+    /// The private key used to sign the requests.
+    private_key: PrivateKey<CurrentNetwork>,
+}
+
+impl Evaluator {
+    /// Initializes a new evaluator for the given process, in which only the mappings of `credits.aleo` exist.
+    pub fn new<R: Rng + CryptoRng>(process: Process<CurrentNetwork>, rng: &mut R) -> Result<Self> {
+        let evaluator = Self { process, store: FinalizeStore::open(None)?, private_key: PrivateKey::new(rng)? };
+        let credits = evaluator.process.get_program(ProgramID::<CurrentNetwork>::from_str("credits.aleo")?)?.clone();
+        evaluator.initialize_mappings(&credits)?;
+        Ok(evaluator)
+    }
+
+    /// Adds the given program to the process, and initializes its mappings.
+    /// Note that the imports of the program must be added first.
+    pub fn add_program(&mut self, program: &Program<CurrentNetwork>) -> Result<()> {
+        self.process.add_program(program)?;
+        self.initialize_mappings(program)
+    }
+
+    /// Sets the private key used to sign the requests, which determines `self.caller` and `self.signer`.
+    pub fn with_private_key(mut self, private_key: PrivateKey<CurrentNetwork>) -> Self {
+        self.private_key = private_key;
+        self
+    }
+
+    /// Returns the process used by the evaluator.
+    pub fn process(&self) -> &Process<CurrentNetwork> {
+        &self.process
+    }
+
+    /// Returns the mapping store used by the evaluator.
+    pub fn store(&self) -> &FinalizeStore<CurrentNetwork, FinalizeMemory<CurrentNetwork>> {
+        &self.store
+    }
+
+    /// Evaluates the given function on the given inputs, and then runs its finalize block, if it has one.
+    /// If the finalize block fails, none of its updates to the mappings are kept.
+    pub fn run<R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<CurrentNetwork>,
+        function_name: &Identifier<CurrentNetwork>,
+        inputs: &[Value<CurrentNetwork>],
+        rng: &mut R,
+    ) -> Result<Response<CurrentNetwork>> {
+        let response = self.evaluate(program_id, function_name, inputs, rng)?;
+
+        // If the last output is a future, finalize it.
+        if let Some(Value::Future(future)) = response.outputs().last() {
+            let state = FinalizeGlobalState::new_genesis::<CurrentNetwork>()?;
+            self.store.start_atomic();
+            match self.finalize(state, future) {
+                Ok(()) => self.store.finish_atomic()?,
+                Err(error) => {
+                    self.store.abort_atomic();
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// Evaluates the given function on the given inputs.
+    pub fn evaluate<R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<CurrentNetwork>,
+        function_name: &Identifier<CurrentNetwork>,
+        inputs: &[Value<CurrentNetwork>],
+        rng: &mut R,
+    ) -> Result<Response<CurrentNetwork>> {
+        halting(|| {
+            let authorization = self.process.authorize::<AleoV0, R>(
+                &self.private_key,
+                program_id,
+                function_name,
+                inputs.iter(),
+                rng,
+            )?;
+            self.process.evaluate::<AleoV0>(authorization)
+        })
+        .map_err(|error| {
+            // Authorizing a function synthesizes its circuit, which halts on a failing assertion without naming it.
+            // Evaluating the function without its external calls names the assertion, if that is what failed.
+            match self.evaluate_without_calls(program_id, function_name, inputs, rng) {
+                Err(console_error)
+                    if failing_instruction(&console_error.to_string())
+                        .is_some_and(|instruction| instruction.starts_with("assert")) =>
+                {
+                    console_error
+                }
+                _ => error,
+            }
+        })
+    }
+
+    /// Evaluates the given function on the given inputs, without authorizing the external calls it makes.
+    /// The evaluation therefore fails if an external call is reached.
+    fn evaluate_without_calls<R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<CurrentNetwork>,
+        function_name: &Identifier<CurrentNetwork>,
+        inputs: &[Value<CurrentNetwork>],
+        rng: &mut R,
+    ) -> Result<Response<CurrentNetwork>> {
+        halting(|| {
+            let input_types = self.process.get_stack(program_id)?.get_function(function_name)?.input_types();
+            let request =
+                Request::sign(&self.private_key, *program_id, *function_name, inputs.iter(), &input_types, rng)?;
+            self.process.evaluate::<AleoV0>(Authorization::new(request))
+        })
+    }
+
+    /// Evaluates the given function on the given inputs one instruction at a time, returning the first one that fails.
+    /// Unlike `evaluate`, this names the failing instruction even if it halts, e.g. on an overflow.
+    /// Note that the search stops at the first `call` instruction, since the requests of external calls are not signed.
+    pub fn failing_function_instruction<R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<CurrentNetwork>,
+        function_name: &Identifier<CurrentNetwork>,
+        inputs: &[Value<CurrentNetwork>],
+        rng: &mut R,
+    ) -> Result<Option<String>> {
+        let stack = self.process.get_stack(program_id)?.as_ref();
+        let function = stack.get_function(function_name)?;
+        let request =
+            Request::sign(&self.private_key, *program_id, *function_name, inputs.iter(), &function.input_types(), rng)?;
+
+        // Initialize the registers as snarkVM does, with the signer as the caller of the function.
+        let mut registers = Registers::<CurrentNetwork, AleoV0>::new(
+            CallStack::Evaluate(Authorization::new(request.clone())),
+            stack.get_register_types(function_name)?.clone(),
+        );
+        registers.set_signer(*request.signer());
+        registers.set_caller(*request.signer());
+        registers.set_tvk(*request.tvk());
+        ensure!(function.inputs().len() == request.inputs().len(), "Incorrect number of inputs");
+        for (input, value) in function.inputs().iter().zip(request.inputs()) {
+            registers.store(stack, input.register(), value.clone())?;
+        }
+
+        for instruction in function.instructions() {
+            if let Instruction::Call(_) = instruction {
+                break;
+            }
+            if halting(|| instruction.evaluate(stack, &mut registers)).is_err() {
+                return Ok(Some(instruction.to_string()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Initializes the mappings declared in the given program, which must be part of the process.
+    /// Note that this is done by `add_program`, so it is only needed for the programs that the process starts with.
+    pub fn initialize_mappings(&self, program: &Program<CurrentNetwork>) -> Result<()> {
+        for mapping_name in program.mappings().keys() {
+            if !self.store.contains_mapping_confirmed(program.id(), mapping_name)? {
+                self.store.initialize_mapping(*program.id(), *mapping_name)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the finalize block that the given future was produced for.
+    fn finalize(&self, state: FinalizeGlobalState, future: &Future<CurrentNetwork>) -> Result<()> {
+        let stack = self.process.get_stack(future.program_id())?.as_ref();
+        let function_name = future.function_name();
+        let finalize = match stack.get_function_ref(function_name)?.finalize_logic() {
+            Some(finalize) => finalize,
+            None => bail!("The function '{}/{function_name}' does not have a finalize block", future.program_id()),
+        };
+
+        // Initialize the registers with the arguments of the future.
+        let mut registers = FinalizeRegisters::new(
+            state,
+            <CurrentNetwork as Network>::TransitionID::from(Field::zero()),
+            *function_name,
+            stack.get_finalize_types(function_name)?.clone(),
+        );
+        ensure!(finalize.inputs().len() == future.arguments().len(), "Incorrect number of arguments to finalize");
+        for (input, argument) in finalize.inputs().iter().zip(future.arguments().iter()) {
+            registers.store(stack, input.register(), Value::from(argument))?;
+        }
+
+        // Evaluate the commands, following any branches.
+        let mut counter = 0;
+        while counter < finalize.commands().len() {
+            let command = &finalize.commands()[counter];
+            let result = halting(|| match command {
+                Command::BranchEq(branch) => branch_to(counter, branch, finalize.positions(), stack, &registers),
+                Command::BranchNeq(branch) => branch_to(counter, branch, finalize.positions(), stack, &registers),
+                Command::Await(await_) => {
+                    match registers.load(stack, &Operand::Register(await_.register().clone()))? {
+                        Value::Future(future) => self.finalize(state, &future)?,
+                        _ => bail!("The input to 'await' is not a future"),
+                    }
+                    Ok(counter + 1)
+                }
+                _ => command.finalize(stack, &self.store, &mut registers).map(|_| counter + 1),
+            });
+            counter = match result {
+                Ok(counter) => counter,
+                Err(error) => bail!("Failed to evaluate command ({command}): {error}"),
+            };
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the index of the command to evaluate after the given branch.
+/// The `branch.eq` variant jumps if its operands are equal, and the `branch.neq` variant jumps if they are not.
+fn branch_to<const VARIANT: u8>(
+    counter: usize,
+    branch: &Branch<CurrentNetwork, VARIANT>,
+    positions: &HashMap<Identifier<CurrentNetwork>, usize>,
+    stack: &Stack<CurrentNetwork>,
+    registers: &FinalizeRegisters<CurrentNetwork>,
+) -> Result<usize> {
+    let first = registers.load(stack, branch.first())?;
+    let second = registers.load(stack, branch.second())?;
+    if (first == second) != (VARIANT == 0) {
+        return Ok(counter + 1);
+    }
+    match positions.get(branch.position()) {
+        Some(index) if *index > counter => Ok(*index),
+        Some(_) => bail!("Cannot branch to an earlier position '{}' in the program", branch.position()),
+        None => bail!("The position '{}' does not exist", branch.position()),
+    }
+}
+
+/// Returns the innermost instruction or command named in a snarkVM evaluation error.
+pub fn failing_instruction(error: &str) -> Option<&str> {
+    let start = ["instruction (", "command ("]
+        .iter()
+        .filter_map(|prefix| error.rfind(prefix).map(|index| index + prefix.len()))
+        .max()?;
+    let end = error[start..].find(')')?;
+    Some(&error[start..start + end])
+}
+
+/// Runs the given closure, converting a halt into an error.
+/// Note that snarkVM halts by panicking, so the panic hook is silenced while the closure runs.
+pub fn halting<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let hook = take_hook();
+    set_hook(Box::new(|_| {}));
+    let result = catch_unwind(AssertUnwindSafe(f));
+    set_hook(hook);
+    match result {
+        Ok(result) => result,
+        Err(payload) => match payload.downcast_ref::<String>() {
+            Some(message) => bail!("{message}"),
+            None => match payload.downcast_ref::<&str>() {
+                Some(message) => bail!("{message}"),
+                None => bail!("The program halted"),
+            },
+        },
+    }
+}
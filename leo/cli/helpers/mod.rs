@@ -16,6 +16,11 @@
 
 pub use super::*;
 
+pub mod bindings;
 pub mod context;
+pub mod debugger;
+pub mod evaluator;
 pub mod logger;
+pub mod source_map;
+pub mod state_directory;
 pub mod updater;
@@ -0,0 +1,62 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use crate::cli::helpers::evaluator::{failing_instruction, Evaluator};
+
+use leo_compiler::InstructionSourceMap;
+
+use snarkvm::{
+    package::Package,
+    prelude::{Identifier, Value},
+};
+
+use std::{fmt::Display, path::Path, str::FromStr};
+
+/// Annotates an error from running `function` of the program built in `build_directory` with the Leo statement that
+/// the failing instruction was generated from, as recorded in `main.aleo.map`.
+/// If the error does not name the failing instruction, the function is evaluated again to find it.
+pub fn annotate_failure(build_directory: &Path, function: &str, inputs: &[String], error: impl Display) -> String {
+    let error = error.to_string();
+    let location = std::fs::read_to_string(build_directory.join("main.aleo.map"))
+        .ok()
+        .and_then(|source_map| serde_json::from_str::<InstructionSourceMap>(&source_map).ok())
+        .and_then(|source_map| {
+            let instruction = match failing_instruction(&error) {
+                Some(instruction) => instruction.to_string(),
+                None => find_failing_instruction(build_directory, function, inputs)?,
+            };
+            source_map.find(function, &instruction).cloned()
+        });
+    match location {
+        Some(location) => format!(
+            "{error}\n    --> {}:{}:{}\n     |\n{:>4} | {}\n     |\n     = failed on `{}`",
+            location.file, location.line, location.column, location.line, location.source, location.instruction
+        ),
+        None => error,
+    }
+}
+
+/// Evaluates `function` of the program built in `build_directory` on `inputs`, returning the instruction that fails.
+fn find_failing_instruction(build_directory: &Path, function: &str, inputs: &[String]) -> Option<String> {
+    let package = Package::<CurrentNetwork>::open(build_directory).ok()?;
+    let rng = &mut rand::thread_rng();
+    let evaluator = Evaluator::new(package.get_process().ok()?, rng).ok()?;
+    let function_name = Identifier::from_str(function).ok()?;
+    let inputs = inputs.iter().map(|input| Value::from_str(input)).collect::<Result<Vec<_>, _>>().ok()?;
+    evaluator.failing_function_instruction(package.program_id(), &function_name, &inputs, rng).ok()?
+}
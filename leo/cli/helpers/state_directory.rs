@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_errors::{CliError, Result};
+use snarkvm::{
+    ledger::store::{helpers::memory::FinalizeMemory, FinalizeStore},
+    prelude::{Identifier, Plaintext, Program, ProgramID, Value},
+};
+
+use indexmap::IndexMap;
+use std::{path::PathBuf, str::FromStr};
+
+/// The entries of the mappings of a program by mapping name, with their keys and values written as Aleo plaintexts.
+pub type Mappings = IndexMap<String, IndexMap<String, String>>;
+
+/// A directory in which `leo run --state` stores the mappings of the programs it runs, so that they persist across runs.
+/// The mappings of each program are stored as JSON in `<program id>.json`, e.g. `token.aleo.json`.
+pub struct StateDirectory {
+    /// The path of the directory, which is created once mappings are first stored.
+    path: PathBuf,
+}
+
+impl StateDirectory {
+    /// Returns the state directory at the given path.
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Returns the path of the directory.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Reads the stored mappings of the given program, if it has been run against this state.
+    pub fn read(&self, program_id: &ProgramID<CurrentNetwork>) -> Result<Option<Mappings>> {
+        let path = self.file(program_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents =
+            std::fs::read_to_string(&path).map_err(|error| CliError::failed_to_read_state(path.display(), error))?;
+        let mappings =
+            serde_json::from_str(&contents).map_err(|error| CliError::failed_to_read_state(path.display(), error))?;
+        Ok(Some(mappings))
+    }
+
+    /// Loads the stored mappings of the given program into a mapping store, in which they have been initialized.
+    /// Stored mappings that the program no longer declares are ignored.
+    pub fn load(
+        &self,
+        program: &Program<CurrentNetwork>,
+        store: &FinalizeStore<CurrentNetwork, FinalizeMemory<CurrentNetwork>>,
+    ) -> Result<()> {
+        let path = self.file(program.id());
+        for (mapping, entries) in self.read(program.id())?.unwrap_or_default() {
+            let result = Identifier::from_str(&mapping).and_then(|mapping| {
+                if !program.mappings().contains_key(&mapping) {
+                    return Ok(());
+                }
+                let entries = entries
+                    .iter()
+                    .map(|(key, value)| Ok((Plaintext::from_str(key)?, Value::from_str(value)?)))
+                    .collect::<snarkvm::prelude::Result<Vec<_>>>()?;
+                store.replace_mapping(*program.id(), mapping, entries).map(|_| ())
+            });
+            result.map_err(|error| CliError::failed_to_read_state(path.display(), error))?;
+        }
+        Ok(())
+    }
+
+    /// Stores the mappings of the given program from a mapping store, replacing those stored before.
+    pub fn save(
+        &self,
+        program: &Program<CurrentNetwork>,
+        store: &FinalizeStore<CurrentNetwork, FinalizeMemory<CurrentNetwork>>,
+    ) -> Result<()> {
+        let path = self.file(program.id());
+        let mappings = program
+            .mappings()
+            .keys()
+            .map(|mapping| {
+                let entries = store.get_mapping_confirmed(*program.id(), *mapping)?;
+                Ok((
+                    mapping.to_string(),
+                    entries.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect(),
+                ))
+            })
+            .collect::<snarkvm::prelude::Result<Mappings>>()
+            .map_err(|error| CliError::failed_to_write_state(path.display(), error))?;
+        let contents = serde_json::to_string_pretty(&mappings)
+            .map_err(|error| CliError::failed_to_write_state(path.display(), error))?;
+        std::fs::create_dir_all(&self.path)
+            .and_then(|_| std::fs::write(&path, contents))
+            .map_err(|error| CliError::failed_to_write_state(path.display(), error))?;
+        Ok(())
+    }
+
+    /// Returns the path of the file in which the mappings of the given program are stored.
+    fn file(&self, program_id: &ProgramID<CurrentNetwork>) -> PathBuf {
+        self.path.join(format!("{program_id}.json"))
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_ast::{Ast, ProgramVisitor, Stub};
+use leo_compiler::merge_modules;
+use leo_errors::{emitter::Handler, Backtraced, CliError};
+use leo_package::source::SourceDirectory;
+use leo_passes::{Monomorphizer, Pass, SymbolTableCreator, TypeChecker, TypeTable};
+use leo_span::{
+    source_map::{FileName, SourceFile},
+    symbol::{with_session_globals, SessionGlobals, SESSION_GLOBALS},
+    Span,
+    Symbol,
+};
+
+use indexmap::IndexMap;
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use std::path::Path;
+
+/// The results of checking a Leo file, with their locations in the file.
+#[derive(Debug, Default)]
+pub struct Analysis {
+    /// The errors and warnings reported on the file.
+    pub diagnostics: Vec<Diagnostic>,
+    /// The types of the expressions and declared variables.
+    types: Vec<(Range, String)>,
+    /// The identifiers, with the locations of the definitions they refer to.
+    definitions: Vec<(Range, Range)>,
+}
+
+impl Analysis {
+    /// Parses and type checks the source of the Leo file at the given path.
+    /// The programs it imports are loaded from the `build/imports` directory of its package.
+    pub fn new(source: &str, path: &Path) -> Self {
+        // Each analysis uses its own session, so that the source map does not grow with every analysis.
+        SESSION_GLOBALS.set(&SessionGlobals::default(), || {
+            let (handler, buffer) = Handler::new_with_buf();
+            let source_file =
+                with_session_globals(|s| s.source_map.new_source(source, FileName::Real(path.to_path_buf())));
+            let references = check(&source_file, path, &handler);
+
+            // The modules imported by the file are in other files, so their locations are left out.
+            let in_file = |span: &Span| source_file.start_pos <= span.lo && span.hi <= source_file.end_pos;
+            let span_in_file = |span: Option<Span>| span.filter(in_file);
+
+            // Note that errors without a message only record an exit code, and are not reported.
+            let errors = buffer.extract_errs().into_inner().into_iter().filter_map(|error| {
+                let span = span_in_file(error.span());
+                Some(diagnostic(span, error.backtraced()?, error.error_code(), DiagnosticSeverity::ERROR))
+            });
+            let warnings = buffer.extract_warnings().into_inner().into_iter().map(|warning| {
+                let span = span_in_file(warning.span());
+                diagnostic(span, warning.backtraced(), warning.error_code(), DiagnosticSeverity::WARNING)
+            });
+
+            let (types, definitions) = references.unwrap_or_default();
+            Self {
+                diagnostics: errors.chain(warnings).collect(),
+                types: types
+                    .into_iter()
+                    .filter(|(span, _)| in_file(span))
+                    .map(|(span, type_)| (range(span), type_))
+                    .collect(),
+                definitions: definitions
+                    .into_iter()
+                    .filter(|(span, definition)| in_file(span) && in_file(definition))
+                    .map(|(span, definition)| (range(span), range(definition)))
+                    .collect(),
+            }
+        })
+    }
+
+    /// Returns the type of the innermost expression or declared variable at the given position.
+    pub fn type_at(&self, position: Position) -> Option<(Range, &str)> {
+        innermost(&self.types, position).map(|(range, type_)| (*range, type_.as_str()))
+    }
+
+    /// Returns the location of the definition of the identifier at the given position.
+    pub fn definition_at(&self, position: Position) -> Option<Range> {
+        innermost(&self.definitions, position).map(|(_, definition)| *definition)
+    }
+}
+
+/// The types of the expressions and declared variables, and the definitions of the identifiers, with their spans.
+type Spans = (Vec<(Span, String)>, Vec<(Span, Span)>);
+
+/// Runs the parser, the symbol table creator, and the type checker on the source, reporting errors to the handler.
+/// Returns the types and definitions found in the program, if it could be parsed.
+fn check(source_file: &SourceFile, path: &Path, handler: &Handler) -> Option<Spans> {
+    let node_builder = Default::default();
+
+    // Modules are only checked as part of the programs that import them, so only their syntax is checked on their own.
+    if SourceDirectory::is_module(path) {
+        let _ = handler.extend_if_error(leo_parser::parse_module(
+            handler,
+            &node_builder,
+            &source_file.src,
+            source_file.start_pos,
+        ));
+        return None;
+    }
+
+    let mut ast = handler
+        .extend_if_error(leo_parser::parse_ast(handler, &node_builder, &source_file.src, source_file.start_pos))
+        .ok()?;
+    let directory = path.parent().unwrap_or(path);
+    let _ = handler.extend_if_error(merge_modules(handler, &node_builder, &mut ast.ast, directory));
+
+    // Load the stubs of the imported programs, so that their functions and types can be checked.
+    let imports_directory = package_directory(path).map(|directory| directory.join("build").join("imports"));
+    let mut stubs = IndexMap::new();
+    let missing = ast
+        .ast
+        .imports
+        .iter()
+        .filter(|(name, _)| !matches!(&imports_directory, Some(directory) if load_stub(**name, directory, &mut stubs)))
+        .map(|(name, (_, span))| (*name, *span))
+        .collect::<Vec<_>>();
+    ast.ast.stubs = stubs;
+
+    // Note that the passes fail if any error has been reported, so the missing imports are reported afterwards.
+    // The generic functions are replaced by their instances, so that their bodies are checked for each of them.
+    let type_table = TypeTable::default();
+    let ast = match Monomorphizer::do_pass((ast.clone(), handler, &node_builder)) {
+        Ok(monomorphized) => {
+            if let Ok(symbol_table) = SymbolTableCreator::do_pass((&monomorphized, handler)) {
+                let _ = TypeChecker::do_pass((&monomorphized, handler, symbol_table, &type_table));
+            }
+            monomorphized
+        }
+        Err(_) => ast,
+    };
+    missing.into_iter().for_each(|(name, span)| handler.emit_err(CliError::import_not_built(name, span)));
+
+    Some(references(&ast, &type_table))
+}
+
+/// Collects the types and definitions found in the program.
+fn references(ast: &Ast, type_table: &TypeTable) -> Spans {
+    let mut references = References::new(type_table);
+    references.visit_program(ast.as_repr());
+    (references.types, references.definitions)
+}
+
+/// Loads the stub of the imported program with the given name, after the stubs of the programs it imports.
+/// Returns false if the program, or a program it imports, has not been built.
+fn load_stub(name: Symbol, imports_directory: &Path, stubs: &mut IndexMap<Symbol, Stub>) -> bool {
+    if stubs.contains_key(&name) {
+        return true;
+    }
+    let stub = match std::fs::read_to_string(imports_directory.join(format!("{name}.aleo"))) {
+        Ok(program) => match disassembler::disassemble_from_str(program) {
+            Ok(stub) => stub,
+            Err(_) => return false,
+        },
+        Err(_) => return false,
+    };
+    if !stub.imports.iter().all(|import| load_stub(import.name.name, imports_directory, stubs)) {
+        return false;
+    }
+    stubs.insert(name, stub);
+    true
+}
+
+/// Returns the directory of the package containing the given file, which is the closest with a `program.json`.
+fn package_directory(path: &Path) -> Option<&Path> {
+    path.ancestors().skip(1).find(|directory| directory.join("program.json").exists())
+}
+
+/// Converts a message reported by the compiler into a diagnostic.
+fn diagnostic(span: Option<Span>, message: &Backtraced, code: String, severity: DiagnosticSeverity) -> Diagnostic {
+    let message = match &message.help {
+        Some(help) => format!("{}\nhelp: {help}", message.message),
+        None => message.message.clone(),
+    };
+    Diagnostic {
+        range: span.map(range).unwrap_or_default(),
+        severity: Some(severity),
+        code: Some(NumberOrString::String(code)),
+        source: Some("leo".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+/// Converts a span into a range in the file it is in.
+/// Note that the positions in a range are zero-based, while those in a span location are one-based.
+fn range(span: Span) -> Range {
+    with_session_globals(|s| match s.source_map.span_to_location(span) {
+        Some(location) if !span.is_dummy() => Range::new(
+            Position::new(location.line_start.saturating_sub(1) as u32, location.col_start.saturating_sub(1) as u32),
+            Position::new(location.line_stop.saturating_sub(1) as u32, location.col_stop.saturating_sub(1) as u32),
+        ),
+        _ => Range::default(),
+    })
+}
+
+/// Returns the entry with the smallest range containing the given position.
+fn innermost<T>(entries: &[(Range, T)], position: Position) -> Option<&(Range, T)> {
+    entries.iter().filter(|(range, _)| range.start <= position && position <= range.end).min_by_key(|(range, _)| {
+        (range.end.line - range.start.line, range.end.character.abs_diff(range.start.character))
+    })
+}
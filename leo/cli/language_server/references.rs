@@ -0,0 +1,293 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use leo_ast::{
+    AccessExpression,
+    AssignStatement,
+    Block,
+    CallExpression,
+    ConstDeclaration,
+    DefinitionStatement,
+    ErrExpression,
+    Expression,
+    ExpressionVisitor,
+    Function,
+    Identifier,
+    IterationRange,
+    IterationStatement,
+    Node,
+    ProgramScope,
+    ProgramVisitor,
+    StatementVisitor,
+    StructExpression,
+    Type,
+};
+use leo_passes::TypeTable;
+use leo_span::{Span, Symbol};
+
+use indexmap::IndexMap;
+
+/// Collects the types of the expressions and declared variables of a program,
+/// and the definitions that the identifiers in it refer to.
+pub struct References<'a> {
+    /// The types of the nodes, as inferred by the type checker.
+    type_table: &'a TypeTable,
+    /// The definitions of the functions, structs, records, mappings, and constants of the program scope.
+    globals: IndexMap<Symbol, Span>,
+    /// The definitions of the members of each struct and record.
+    members: IndexMap<Symbol, IndexMap<Symbol, Span>>,
+    /// The definitions of the variables in each enclosing scope, with the innermost scope last.
+    scopes: Vec<IndexMap<Symbol, Span>>,
+    /// The types of the expressions and declared variables, with their spans.
+    pub types: Vec<(Span, String)>,
+    /// The spans of the identifiers, with the spans of the definitions they refer to.
+    pub definitions: Vec<(Span, Span)>,
+}
+
+impl<'a> References<'a> {
+    /// Initializes a new `References` collector.
+    pub fn new(type_table: &'a TypeTable) -> Self {
+        Self {
+            type_table,
+            globals: Default::default(),
+            members: Default::default(),
+            scopes: Default::default(),
+            types: Default::default(),
+            definitions: Default::default(),
+        }
+    }
+
+    /// Records the definition of a variable with the given type in the current scope.
+    fn define(&mut self, identifier: &Identifier, type_: &Type) {
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(identifier.name, identifier.span);
+        }
+        self.definitions.push((identifier.span, identifier.span));
+        self.types.push((identifier.span, format!("{}: {type_}", identifier.name)));
+        self.visit_type(type_);
+    }
+
+    /// Records the definition that the identifier refers to, searching the innermost scope first.
+    fn resolve(&mut self, identifier: &Identifier) {
+        let definition = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(&identifier.name))
+            .or_else(|| self.globals.get(&identifier.name));
+        if let Some(definition) = definition {
+            self.definitions.push((identifier.span, *definition));
+        }
+    }
+
+    /// Records the definitions of the structs and records named in the type.
+    fn visit_type(&mut self, type_: &Type) {
+        match type_ {
+            Type::Identifier(identifier) => self.resolve(identifier),
+            Type::Array(array) => self.visit_type(array.element_type()),
+            Type::Tuple(tuple) => tuple.elements().iter().for_each(|element| self.visit_type(element)),
+            _ => {}
+        }
+    }
+
+    /// Records the definition of a member of the struct or record with the given name.
+    fn resolve_member(&mut self, struct_: Symbol, member: &Identifier) {
+        if let Some(definition) = self.members.get(&struct_).and_then(|members| members.get(&member.name)) {
+            self.definitions.push((member.span, *definition));
+        }
+    }
+
+    /// Visits the block in a new scope, in which the given variables are defined.
+    fn visit_scope(&mut self, variables: &[(Identifier, Type)], block: &'a Block) {
+        self.scopes.push(IndexMap::new());
+        variables.iter().for_each(|(identifier, type_)| self.define(identifier, type_));
+        self.visit_block(block);
+        self.scopes.pop();
+    }
+}
+
+impl<'a> ExpressionVisitor<'a> for References<'a> {
+    type AdditionalInput = ();
+    type Output = ();
+
+    fn visit_expression(&mut self, input: &'a Expression, additional: &Self::AdditionalInput) -> Self::Output {
+        if let Some(type_) = self.type_table.get(&input.id()) {
+            self.types.push((input.span(), type_.to_string()));
+        }
+
+        match input {
+            Expression::Access(access) => self.visit_access(access, additional),
+            Expression::Array(array) => self.visit_array(array, additional),
+            Expression::Binary(binary) => self.visit_binary(binary, additional),
+            Expression::Call(call) => self.visit_call(call, additional),
+            Expression::Cast(cast) => self.visit_cast(cast, additional),
+            Expression::Struct(struct_) => self.visit_struct_init(struct_, additional),
+            Expression::Err(err) => self.visit_err(err, additional),
+            Expression::Identifier(identifier) => self.visit_identifier(identifier, additional),
+            Expression::Literal(literal) => self.visit_literal(literal, additional),
+            Expression::Ternary(ternary) => self.visit_ternary(ternary, additional),
+            Expression::Tuple(tuple) => self.visit_tuple(tuple, additional),
+            Expression::Unary(unary) => self.visit_unary(unary, additional),
+            Expression::Unit(unit) => self.visit_unit(unit, additional),
+        }
+    }
+
+    fn visit_access(&mut self, input: &'a AccessExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        match input {
+            AccessExpression::Array(array) => {
+                self.visit_expression(&array.array, additional);
+                self.visit_expression(&array.index, additional);
+            }
+            AccessExpression::AssociatedFunction(function) => {
+                function.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+            }
+            AccessExpression::Member(member) => {
+                self.visit_expression(&member.inner, additional);
+                if let Some(Type::Identifier(struct_)) = self.type_table.get(&member.inner.id()) {
+                    self.resolve_member(struct_.name, &member.name);
+                }
+            }
+            AccessExpression::Tuple(tuple) => self.visit_expression(&tuple.tuple, additional),
+            AccessExpression::AssociatedConstant(_) => {}
+        }
+    }
+
+    fn visit_call(&mut self, input: &'a CallExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        // Functions of external programs are not defined in this file.
+        if input.external.is_none() {
+            if let Expression::Identifier(function) = &*input.function {
+                self.resolve(function);
+            }
+        }
+        input.arguments.iter().for_each(|argument| self.visit_expression(argument, additional));
+    }
+
+    fn visit_struct_init(&mut self, input: &'a StructExpression, additional: &Self::AdditionalInput) -> Self::Output {
+        self.resolve(&input.name);
+        for member in input.members.iter() {
+            self.resolve_member(input.name.name, &member.identifier);
+            match &member.expression {
+                Some(expression) => self.visit_expression(expression, additional),
+                // The shorthand `Foo { bar }` also refers to the variable `bar`.
+                None => self.resolve(&member.identifier),
+            }
+        }
+    }
+
+    fn visit_err(&mut self, _input: &'a ErrExpression, _additional: &Self::AdditionalInput) -> Self::Output {}
+
+    fn visit_identifier(&mut self, input: &'a Identifier, _additional: &Self::AdditionalInput) -> Self::Output {
+        self.resolve(input)
+    }
+}
+
+impl<'a> StatementVisitor<'a> for References<'a> {
+    fn visit_assign(&mut self, input: &'a AssignStatement) {
+        self.visit_expression(&input.place, &());
+        self.visit_expression(&input.value, &());
+    }
+
+    fn visit_block(&mut self, input: &'a Block) {
+        self.scopes.push(IndexMap::new());
+        input.statements.iter().for_each(|statement| self.visit_statement(statement));
+        self.scopes.pop();
+    }
+
+    fn visit_const(&mut self, input: &'a ConstDeclaration) {
+        self.visit_expression(&input.value, &());
+        self.define(&input.place, &input.type_);
+    }
+
+    fn visit_definition(&mut self, input: &'a DefinitionStatement) {
+        self.visit_expression(&input.value, &());
+        match (&input.place, &input.type_) {
+            (Expression::Identifier(identifier), type_) => self.define(identifier, type_),
+            (Expression::Tuple(tuple), Type::Tuple(types)) => {
+                for (element, type_) in tuple.elements.iter().zip(types.elements().iter()) {
+                    if let Expression::Identifier(identifier) = element {
+                        self.define(identifier, type_);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_iteration(&mut self, input: &'a IterationStatement) {
+        match &input.range {
+            IterationRange::Range { start, stop, step, .. } => {
+                self.visit_expression(start, &());
+                self.visit_expression(stop, &());
+                if let Some(step) = step {
+                    self.visit_expression(step, &());
+                }
+            }
+            IterationRange::Array { array } => self.visit_expression(array, &()),
+        }
+        self.visit_scope(&[(input.variable, input.type_.clone())], &input.block);
+    }
+}
+
+impl<'a> ProgramVisitor<'a> for References<'a> {
+    fn visit_program_scope(&mut self, input: &'a ProgramScope) {
+        // The items of the program scope can be referred to before they are defined.
+        for (name, struct_) in input.structs.iter() {
+            self.globals.insert(*name, struct_.identifier.span);
+            self.members.insert(
+                *name,
+                struct_.members.iter().map(|member| (member.identifier.name, member.identifier.span)).collect(),
+            );
+        }
+        for (name, mapping) in input.mappings.iter() {
+            self.globals.insert(*name, mapping.identifier.span);
+        }
+        for (name, function) in input.functions.iter() {
+            self.globals.insert(*name, function.identifier.span);
+        }
+        for (name, const_) in input.consts.iter() {
+            self.globals.insert(*name, const_.place.span);
+        }
+
+        for (_, struct_) in input.structs.iter() {
+            self.definitions.push((struct_.identifier.span, struct_.identifier.span));
+            struct_.members.iter().for_each(|member| {
+                self.definitions.push((member.identifier.span, member.identifier.span));
+                self.types.push((member.identifier.span, format!("{}: {}", member.identifier.name, member.type_)));
+                self.visit_type(&member.type_);
+            });
+        }
+        for (_, mapping) in input.mappings.iter() {
+            self.definitions.push((mapping.identifier.span, mapping.identifier.span));
+            self.visit_type(&mapping.key_type);
+            self.visit_type(&mapping.value_type);
+        }
+        input.consts.iter().for_each(|(_, const_)| self.visit_const(const_));
+        input.functions.iter().for_each(|(_, function)| self.visit_function(function));
+    }
+
+    fn visit_function(&mut self, input: &'a Function) {
+        self.definitions.push((input.identifier.span, input.identifier.span));
+        input.output.iter().for_each(|output| self.visit_type(&output.type_()));
+
+        let inputs = input.input.iter().map(|input| (input.identifier(), input.type_())).collect::<Vec<_>>();
+        self.visit_scope(&inputs, &input.block);
+
+        if let Some(finalize) = &input.finalize {
+            let inputs = finalize.input.iter().map(|input| (input.identifier(), input.type_())).collect::<Vec<_>>();
+            self.visit_scope(&inputs, &finalize.block);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use leo_errors::{CliError, Result};
+
+use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    notification::{
+        DidCloseTextDocument,
+        DidOpenTextDocument,
+        DidSaveTextDocument,
+        Notification as _,
+        PublishDiagnostics,
+    },
+    request::{GotoDefinition, HoverRequest, Request as _},
+    DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams,
+    DidSaveTextDocumentParams,
+    GotoDefinitionParams,
+    GotoDefinitionResponse,
+    Hover,
+    HoverContents,
+    HoverParams,
+    HoverProviderCapability,
+    LanguageString,
+    Location,
+    MarkedString,
+    OneOf,
+    PublishDiagnosticsParams,
+    SaveOptions,
+    ServerCapabilities,
+    TextDocumentSyncCapability,
+    TextDocumentSyncKind,
+    TextDocumentSyncOptions,
+    TextDocumentSyncSaveOptions,
+    Url,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::collections::HashMap;
+
+/// A language server for Leo files, which communicates with the editor over stdin and stdout.
+/// Files are checked when they are opened and saved, and the diagnostics found are published to the editor.
+pub struct LanguageServer {
+    /// The connection to the editor.
+    connection: Connection,
+    /// The analyses of the open files, as of when they were last saved.
+    analyses: HashMap<Url, Analysis>,
+}
+
+impl LanguageServer {
+    /// Runs the language server until the editor shuts it down.
+    pub fn run() -> Result<()> {
+        let (connection, io_threads) = Connection::stdio();
+
+        let capabilities = ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Options(TextDocumentSyncOptions {
+                open_close: Some(true),
+                change: Some(TextDocumentSyncKind::NONE),
+                save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions { include_text: Some(true) })),
+                ..Default::default()
+            })),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            ..Default::default()
+        };
+        let capabilities = serde_json::to_value(capabilities).map_err(CliError::language_server_error)?;
+        connection.initialize(capabilities).map_err(CliError::language_server_error)?;
+
+        let mut server = Self { connection, analyses: HashMap::new() };
+        server.main_loop()?;
+        drop(server);
+
+        io_threads.join().map_err(CliError::language_server_error)?;
+        Ok(())
+    }
+
+    /// Handles the messages from the editor until it requests a shutdown.
+    fn main_loop(&mut self) -> Result<()> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request).map_err(CliError::language_server_error)? {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Responds to a hover or go-to-definition request.
+    fn handle_request(&self, request: Request) -> Result<()> {
+        match request.method.as_str() {
+            HoverRequest::METHOD => {
+                let params = extract::<HoverParams>(request.params)?;
+                let document = params.text_document_position_params;
+                let hover = self.analyses.get(&document.text_document.uri).and_then(|analysis| {
+                    let (range, type_) = analysis.type_at(document.position)?;
+                    Some(Hover {
+                        contents: HoverContents::Scalar(MarkedString::LanguageString(LanguageString {
+                            language: "leo".to_string(),
+                            value: type_.to_string(),
+                        })),
+                        range: Some(range),
+                    })
+                });
+                self.respond(request.id, hover)
+            }
+            GotoDefinition::METHOD => {
+                let params = extract::<GotoDefinitionParams>(request.params)?;
+                let document = params.text_document_position_params;
+                let uri = document.text_document.uri;
+                let definition = self.analyses.get(&uri).and_then(|analysis| {
+                    let range = analysis.definition_at(document.position)?;
+                    Some(GotoDefinitionResponse::Scalar(Location { uri: uri.clone(), range }))
+                });
+                self.respond(request.id, definition)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the files that are opened or saved, and forgets those that are closed.
+    /// Note that changes to a file are only checked once it is saved.
+    fn handle_notification(&mut self, notification: Notification) -> Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = extract::<DidOpenTextDocumentParams>(notification.params)?;
+                self.check(params.text_document.uri, &params.text_document.text)
+            }
+            DidSaveTextDocument::METHOD => match extract::<DidSaveTextDocumentParams>(notification.params)? {
+                DidSaveTextDocumentParams { text_document, text: Some(text) } => self.check(text_document.uri, &text),
+                _ => Ok(()),
+            },
+            DidCloseTextDocument::METHOD => {
+                let params = extract::<DidCloseTextDocumentParams>(notification.params)?;
+                self.analyses.remove(&params.text_document.uri);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the file with the given contents, and publishes the diagnostics found.
+    fn check(&mut self, uri: Url, text: &str) -> Result<()> {
+        let path = uri.to_file_path().unwrap_or_else(|_| uri.path().into());
+        let analysis = Analysis::new(text, &path);
+
+        let params = PublishDiagnosticsParams::new(uri.clone(), analysis.diagnostics.clone(), None);
+        self.send(Message::Notification(Notification::new(PublishDiagnostics::METHOD.to_string(), params)))?;
+
+        self.analyses.insert(uri, analysis);
+        Ok(())
+    }
+
+    /// Sends the result of a request to the editor.
+    fn respond(&self, id: RequestId, result: impl Serialize) -> Result<()> {
+        self.send(Message::Response(Response::new_ok(id, result)))
+    }
+
+    /// Sends a message to the editor.
+    fn send(&self, message: Message) -> Result<()> {
+        self.connection.sender.send(message).map_err(CliError::language_server_error)?;
+        Ok(())
+    }
+}
+
+/// Extracts the parameters of a request or notification.
+fn extract<P: DeserializeOwned>(params: serde_json::Value) -> Result<P> {
+    Ok(serde_json::from_value(params).map_err(CliError::language_server_error)?)
+}
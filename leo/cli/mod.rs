@@ -20,9 +20,14 @@ pub use cli::*;
 mod commands;
 pub use commands::*;
 
+mod formatter;
+
 mod helpers;
 pub use helpers::*;
 
+mod language_server;
+pub use language_server::*;
+
 pub(crate) type CurrentNetwork = snarkvm::prelude::Testnet3;
 pub(crate) const SNARKVM_COMMAND: &str = "snarkvm";
 
@@ -56,12 +56,21 @@ impl SourceDirectory {
         Ok(file_paths)
     }
 
-    /// Check that the files in the source directory are valid.
-    pub fn check_files(paths: &[PathBuf]) -> Result<()> {
-        match paths.len() {
-            0 => Err(PackageError::empty_source_directory().into()),
-            1 if paths[0].as_path().ends_with(MAIN_FILENAME) => Ok(()),
-            _ => Err(PackageError::source_directory_can_contain_only_one_file().into()),
+    /// Check that the files in the source directory are valid, and return the path to the main file.
+    /// Note that the other files are modules, which are compiled as part of the main file when it imports them.
+    pub fn check_files(paths: &[PathBuf]) -> Result<PathBuf> {
+        if paths.is_empty() {
+            return Err(PackageError::empty_source_directory().into());
         }
+        match paths.iter().find(|path| path.ends_with(format!("{SOURCE_DIRECTORY_NAME}{MAIN_FILENAME}"))) {
+            Some(path) => Ok(path.clone()),
+            None => Err(PackageError::missing_main_file().into()),
+        }
+    }
+
+    /// Returns whether the Leo file at the given path is a module, i.e. a file in the source directory other than the main file.
+    pub fn is_module(path: &Path) -> bool {
+        let source_directory = Path::new(SOURCE_DIRECTORY_NAME);
+        path.parent().and_then(Path::file_name) == source_directory.file_name() && !path.ends_with(MAIN_FILENAME)
     }
 }
@@ -89,7 +89,7 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
         PathBuf::from(String::new()),
         PathBuf::from(String::new()),
         Some(CompilerOptions {
-            build: BuildOptions { dce_enabled: true },
+            build: BuildOptions { dce_enabled: true, tests_enabled: false, check_privacy: false, lints: None },
             output: OutputOptions {
                 symbol_table_spans_enabled: false,
                 initial_symbol_table: false,
@@ -99,6 +99,7 @@ fn new_compiler(handler: &Handler) -> Compiler<'_> {
                 initial_ast: false,
                 unrolled_ast: false,
                 ssa_ast: false,
+                const_folded_ast: false,
                 flattened_ast: false,
                 destructured_ast: false,
                 inlined_ast: false,
@@ -156,6 +157,8 @@ impl Sample {
         self.bencher(c, mode, |mut compiler| {
             let (input, name) = self.data();
             compiler.parse_program_from_string(input, name).expect("Failed to parse program");
+            compiler.enum_lowering_pass().expect("failed to run enum lowering pass");
+            compiler.monomorphization_pass().expect("failed to run monomorphization pass");
             logic(compiler)
         });
     }
@@ -226,6 +229,7 @@ impl Sample {
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             let start = Instant::now();
             let out = compiler.flattening_pass(&symbol_table);
             let time = start.elapsed();
@@ -241,6 +245,7 @@ impl Sample {
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
             let start = Instant::now();
             let out = compiler.destructuring_pass();
@@ -257,6 +262,7 @@ impl Sample {
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             let start = Instant::now();
@@ -274,6 +280,7 @@ impl Sample {
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run inliner pass");
@@ -292,6 +299,7 @@ impl Sample {
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattener pass");
             compiler.destructuring_pass().expect("failed to run destructurer pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run inliner pass");
@@ -314,6 +322,7 @@ impl Sample {
                 compiler.type_checker_pass(symbol_table).expect("failed to run type check pass");
             let symbol_table = compiler.loop_unrolling_pass(symbol_table).expect("failed to run loop unrolling pass");
             compiler.static_single_assignment_pass(&symbol_table).expect("failed to run ssa pass");
+            compiler.constant_folding_pass().expect("failed to run constant folding pass");
             compiler.flattening_pass(&symbol_table).expect("failed to run flattening pass");
             compiler.destructuring_pass().expect("failed to run destructuring pass");
             compiler.function_inlining_pass(&call_graph).expect("failed to run function inlining pass");
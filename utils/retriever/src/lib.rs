@@ -20,13 +20,22 @@ pub use retriever::*;
 pub mod program_context;
 pub use program_context::*;
 
+pub mod registry;
+pub use registry::*;
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use aleo_std::aleo_dir;
     use leo_span::{symbol::create_session_if_not_set_then, Symbol};
     use serial_test::serial;
-    use std::path::PathBuf;
+    use std::path::{Path, PathBuf};
+
+    fn registry(home: &Path) -> Registry {
+        Registry::new(&home.join("registry"))
+            .with_backend(MirrorBackend::new(&home.join("registry")))
+            .with_backend(HttpBackend::default())
+    }
 
     #[test]
     #[ignore]
@@ -39,7 +48,8 @@ mod tests {
             let build_dir = PathBuf::from(BUILD_DIRECTORY);
             let home_dir = PathBuf::from(HOME_DIRECTORY);
             let mut retriever =
-                Retriever::new(Symbol::intern("nested"), &build_dir, &home_dir).expect("Failed to build retriever");
+                Retriever::new(Symbol::intern("nested"), &build_dir, registry(&home_dir), LockMode::default())
+                    .expect("Failed to build retriever");
             retriever.retrieve().expect("failed to retrieve");
             retriever.prepare_local(Symbol::intern("nested")).expect("failed to prepare local");
             retriever.process_local(Symbol::intern("nested")).expect("failed to process local");
@@ -57,7 +67,8 @@ mod tests {
 
             println!("aleo_dir: {:?}", aleo_dir());
             let mut retriever =
-                Retriever::new(Symbol::intern("nested"), &build_dir, &aleo_dir()).expect("Failed to build retriever");
+                Retriever::new(Symbol::intern("nested"), &build_dir, registry(&aleo_dir()), LockMode::default())
+                    .expect("Failed to build retriever");
             retriever.retrieve().expect("failed to retrieve");
             retriever.prepare_local(Symbol::intern("nested")).expect("failed to prepare local");
             retriever.process_local(Symbol::intern("nested")).expect("failed to process local");
@@ -75,7 +86,8 @@ mod tests {
             let build_dir = PathBuf::from(BUILD_DIRECTORY);
             let home_dir = PathBuf::from(HOME_DIRECTORY);
             let mut retriever =
-                Retriever::new(Symbol::intern("simple"), &build_dir, &home_dir).expect("Failed to build retriever");
+                Retriever::new(Symbol::intern("simple"), &build_dir, registry(&home_dir), LockMode::default())
+                    .expect("Failed to build retriever");
             retriever.retrieve().expect("failed to retrieve");
             retriever.prepare_local(Symbol::intern("simple")).expect("failed to prepare local");
             retriever.process_local(Symbol::intern("simple")).expect("failed to process local");
@@ -93,11 +105,62 @@ mod tests {
             let build_dir = PathBuf::from(BUILD_DIRECTORY);
             let home_dir = PathBuf::from(HOME_DIRECTORY);
             let mut retriever =
-                Retriever::new(Symbol::intern("local_test"), &build_dir, &home_dir).expect("Failed to build retriever");
+                Retriever::new(Symbol::intern("local_test"), &build_dir, registry(&home_dir), LockMode::default())
+                    .expect("Failed to build retriever");
             let _deps = retriever.retrieve().expect("failed to retrieve");
             retriever.prepare_local(Symbol::intern("nested")).expect("failed to prepare local");
             // retriever.process_local(Symbol::intern("nested")).expect("failed to process local");
             // retriever.prepare_local(Symbol::intern("local_dep_1")).expect("failed to prepare local");
         });
     }
+
+    #[test]
+    fn offline_registry_test() {
+        // Test that vendored programs take precedence over mirrors, and that missing programs are reported
+        let directory = tempfile::tempdir().expect("Failed to create temporary directory");
+        let project = directory.path().join("project");
+        let mirror = directory.path().join("mirror");
+        std::fs::create_dir_all(project.join("imports")).unwrap();
+        std::fs::create_dir_all(mirror.join("testnet3")).unwrap();
+        std::fs::write(project.join("imports").join("vendored.aleo"), "program vendored.aleo;").unwrap();
+        std::fs::write(mirror.join("testnet3").join("vendored.aleo"), "program mirrored.aleo;").unwrap();
+        std::fs::write(mirror.join("testnet3").join("mirrored.aleo"), "program mirrored.aleo;").unwrap();
+
+        let registry = Registry::new(&directory.path().join("cache"))
+            .with_backend(VendoredBackend::new(&project))
+            .with_backend(MirrorBackend::new(&mirror));
+
+        let path = registry.retrieve("vendored.aleo", &Network::Testnet3).expect("failed to retrieve");
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "program vendored.aleo;");
+        let path = registry.retrieve("mirrored.aleo", &Network::Testnet3).expect("failed to retrieve");
+        assert_eq!(path, directory.path().join("cache").join("testnet3").join("mirrored.aleo"));
+
+        create_session_if_not_set_then(|_| {
+            let err = registry.retrieve("missing.aleo", &Network::Testnet3).unwrap_err();
+            assert!(err.to_string().contains("Could not find `missing.aleo` on `testnet3`"));
+            assert!(err.to_string().contains(&mirror.display().to_string()));
+        });
+    }
+
+    #[test]
+    fn git_source_validation_test() {
+        // Test that Git sources which could be parsed as options or escape the cache are rejected
+        let source: GitSource = serde_json::from_str(r#"{ "url": "https://example.com/repo.git", "tag": "v1.0" }"#)
+            .expect("failed to parse git source");
+        assert_eq!(source.pinned_revision().map(String::as_str), Some("v1.0"));
+        assert!(serde_json::from_str::<GitSource>(r#"{ "url": "--upload-pack=touch pwned" }"#).is_err());
+        assert!(
+            serde_json::from_str::<GitSource>(r#"{ "url": "https://example.com/repo.git", "rev": "-p" }"#).is_err()
+        );
+
+        let directory = tempfile::tempdir().expect("Failed to create temporary directory");
+        let source = GitSource::new("https://example.com/repo.git".to_string(), Some("..".to_string()), None, None);
+        create_session_if_not_set_then(|_| {
+            let err = registry::git::checkout(directory.path(), &source).unwrap_err();
+            assert!(err.to_string().contains("is not a valid Git revision"));
+            let source = GitSource::new("https://example.com/repo.git".to_string(), Some("-p".to_string()), None, None);
+            let err = registry::git::checkout(directory.path(), &source).unwrap_err();
+            assert!(err.to_string().contains("is not a valid Git revision"));
+        });
+    }
 }
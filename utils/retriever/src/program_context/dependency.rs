@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Location, Network};
+use crate::{GitSource, Location, Network};
 use leo_span::Symbol;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -26,11 +26,18 @@ pub struct Dependency {
     location: Location,
     network: Option<Network>,
     path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    git: Option<GitSource>,
 }
 
 impl Dependency {
     pub fn new(name: String, location: Location, network: Option<Network>, path: Option<PathBuf>) -> Self {
-        Self { name, location, network, path }
+        Self { name, location, network, path, git: None }
+    }
+
+    // Create a dependency on a program in a Git repository, `path` being the package root within the repository
+    pub fn new_git(name: String, git: GitSource, path: Option<PathBuf>) -> Self {
+        Self { name, location: Location::Git, network: None, path, git: Some(git) }
     }
 
     pub fn name(&self) -> &String {
@@ -48,6 +55,10 @@ impl Dependency {
     pub fn path(&self) -> &Option<PathBuf> {
         &self.path
     }
+
+    pub fn git(&self) -> &Option<GitSource> {
+        &self.git
+    }
 }
 
 impl From<&Dependency> for Symbol {
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// The repository and revision of a Git dependency. At most one of `rev`, `tag` and `branch` is set;
+// if none is, the default branch of the repository is used.
+#[derive(Debug, Clone, std::cmp::Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "GitSourceFields")]
+pub struct GitSource {
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rev: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+}
+
+// The fields of a `GitSource` as written in `program.json` or `leo.lock`, before they are validated.
+#[derive(Deserialize)]
+struct GitSourceFields {
+    url: String,
+    #[serde(default)]
+    rev: Option<String>,
+    #[serde(default)]
+    tag: Option<String>,
+    #[serde(default)]
+    branch: Option<String>,
+}
+
+// Reject values that `git` would parse as options, since they come from the manifests of dependencies.
+impl TryFrom<GitSourceFields> for GitSource {
+    type Error = String;
+
+    fn try_from(fields: GitSourceFields) -> Result<Self, Self::Error> {
+        let GitSourceFields { url, rev, tag, branch } = fields;
+        if let Some(value) = std::iter::once(&url).chain(&rev).chain(&tag).chain(&branch).find(|v| v.starts_with('-')) {
+            return Err(format!("invalid git dependency `{value}`: values cannot start with `-`"));
+        }
+        Ok(Self { url, rev, tag, branch })
+    }
+}
+
+impl GitSource {
+    pub fn new(url: String, rev: Option<String>, tag: Option<String>, branch: Option<String>) -> Self {
+        Self { url, rev, tag, branch }
+    }
+
+    pub fn url(&self) -> &String {
+        &self.url
+    }
+
+    pub fn rev(&self) -> &Option<String> {
+        &self.rev
+    }
+
+    pub fn tag(&self) -> &Option<String> {
+        &self.tag
+    }
+
+    pub fn branch(&self) -> &Option<String> {
+        &self.branch
+    }
+
+    // The revision to check out, if it is pinned to a commit or a tag
+    pub fn pinned_revision(&self) -> Option<&String> {
+        self.rev.as_ref().or(self.tag.as_ref())
+    }
+}
+
+impl fmt::Display for GitSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.url)?;
+        match (&self.rev, &self.tag, &self.branch) {
+            (Some(rev), _, _) => write!(f, "#{rev}"),
+            (_, Some(tag), _) => write!(f, "#{tag}"),
+            (_, _, Some(branch)) => write!(f, "#{branch}"),
+            _ => Ok(()),
+        }
+    }
+}
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Location, Network, ProgramContext};
+use crate::{GitSource, Location, Network, ProgramContext};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -24,10 +24,26 @@ pub struct LockFileEntry {
     network: Option<Network>,
     location: Location,
     path: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    git: Option<GitSource>,
     checksum: String,
     dependencies: Vec<String>,
 }
 
+impl LockFileEntry {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn location(&self) -> &Location {
+        &self.location
+    }
+
+    pub fn checksum(&self) -> &String {
+        &self.checksum
+    }
+}
+
 impl From<&ProgramContext> for LockFileEntry {
     fn from(context: &ProgramContext) -> Self {
         LockFileEntry {
@@ -35,6 +51,7 @@ impl From<&ProgramContext> for LockFileEntry {
             network: context.network.clone(), // Direct access as per instruction
             location: context.location().clone(),
             path: context.path.clone(), // Direct access as per instruction
+            git: context.git.clone(),
             checksum: context.checksum().to_string(),
             dependencies: context.dependencies().iter().map(|dep| format!("{}.aleo", dep)).collect(),
         }
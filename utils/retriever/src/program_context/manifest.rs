@@ -15,6 +15,7 @@
 // along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::Dependency;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
 // Struct representation of program's `program.json` specification
@@ -25,6 +26,8 @@ pub struct Manifest {
     description: String,
     license: String,
     dependencies: Option<Vec<Dependency>>,
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    lints: IndexMap<String, bool>,
 }
 
 impl Manifest {
@@ -41,9 +44,15 @@ impl Manifest {
             description: description.to_owned(),
             license: license.to_owned(),
             dependencies,
+            lints: IndexMap::new(),
         }
     }
 
+    pub fn with_lints(mut self, lints: IndexMap<String, bool>) -> Self {
+        self.lints = lints;
+        self
+    }
+
     pub fn program(&self) -> &String {
         &self.program
     }
@@ -63,4 +72,8 @@ impl Manifest {
     pub fn dependencies(&self) -> &Option<Vec<Dependency>> {
         &self.dependencies
     }
+
+    pub fn lints(&self) -> &IndexMap<String, bool> {
+        &self.lints
+    }
 }
@@ -26,6 +26,8 @@ pub mod manifest;
 pub use manifest::*;
 pub mod dependency;
 pub use dependency::*;
+pub mod git_source;
+pub use git_source::*;
 use leo_ast::Stub;
 use leo_span::Symbol;
 
@@ -40,6 +42,7 @@ pub struct ProgramContext {
     location: Location,
     network: Option<Network>,
     path: Option<PathBuf>,
+    git: Option<GitSource>,
     full_path: Option<PathBuf>,
     compiled_file_path: Option<PathBuf>,
     dependencies: Option<Vec<Symbol>>,
@@ -56,6 +59,7 @@ impl ProgramContext {
             location: Location::Local,
             network: None,
             path: Some(path.clone()),
+            git: None,
             full_path: Some(path.clone()),
             compiled_file_path: Some(path.join("build/main.aleo")),
             dependencies: Some(dependencies.iter().map(Symbol::from).collect()),
@@ -90,6 +94,11 @@ impl ProgramContext {
         self.path.as_ref().expect("ProgramContext path is None")
     }
 
+    // Method to extract 'git', panics if `None`. Only safe to access if location is 'Git'.
+    pub fn git(&self) -> &GitSource {
+        self.git.as_ref().expect("ProgramContext git is None")
+    }
+
     // Method to extract 'full_path', panics if `None`. Only safe to access if location is 'Local'.
     pub fn full_path(&self) -> &PathBuf {
         self.full_path.as_ref().expect("ProgramContext full_path is None")
@@ -174,6 +183,7 @@ impl From<Dependency> for ProgramContext {
             location: dependency.location().clone(),
             network: dependency.network().clone(),
             path: dependency.path().clone(),
+            git: dependency.git().clone(),
             full_path: None,
             compiled_file_path: None,
             dependencies: None,
@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::GitSource;
+use leo_errors::UtilError;
+
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::Write,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+// Check out `source` in a directory of `cache_path`, and return the root of the checkout.
+// Checkouts of a commit or a tag are reused, while checkouts of a branch are updated every time.
+pub fn checkout(cache_path: &Path, source: &GitSource) -> Result<PathBuf, UtilError> {
+    let directory = cache_path.join(repository_directory(source.url())).join(revision_directory(source)?);
+
+    if directory.exists() {
+        if source.pinned_revision().is_none() {
+            git(&directory, &["pull", "--quiet", "--ff-only"])?;
+        }
+        return Ok(directory);
+    }
+
+    // Clone into a temporary directory first, so that a failed checkout is not reused
+    let partial = directory.with_extension("partial");
+    if partial.exists() {
+        std::fs::remove_dir_all(&partial).map_err(|err| {
+            UtilError::util_file_io_error(
+                format!("Could not remove {}", partial.to_str().unwrap()),
+                err,
+                Default::default(),
+            )
+        })?;
+    }
+    std::fs::create_dir_all(&partial).map_err(|err| {
+        UtilError::util_file_io_error(
+            format!("Could not create {}", partial.to_str().unwrap()),
+            err,
+            Default::default(),
+        )
+    })?;
+
+    println!("Retrieving {source}.");
+    let mut clone = vec!["clone", "--quiet"];
+    if let Some(branch) = source.branch() {
+        clone.extend(["--branch", branch]);
+    }
+    clone.extend(["--", source.url().as_str(), "."]);
+    git(&partial, &clone)?;
+    if let Some(revision) = source.pinned_revision() {
+        // The trailing `--` keeps the revision from being read as a path, and `revision_directory` rejects options.
+        git(&partial, &["checkout", "--quiet", revision, "--"])?;
+    }
+
+    std::fs::rename(&partial, &directory).map_err(|err| {
+        UtilError::util_file_io_error(
+            format!("Could not move {} to {}", partial.to_str().unwrap(), directory.to_str().unwrap()),
+            err,
+            Default::default(),
+        )
+    })?;
+
+    Ok(directory)
+}
+
+// Run `git` with `args` in `directory`.
+fn git(directory: &Path, args: &[&str]) -> Result<(), UtilError> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .map_err(|err| UtilError::failed_to_run_git(args.join(" "), err, Default::default()))?;
+    if !output.status.success() {
+        return Err(UtilError::failed_to_run_git(
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim(),
+            Default::default(),
+        ));
+    }
+    Ok(())
+}
+
+// The name of the directory for a repository, made of its name and a hash of its url.
+fn repository_directory(url: &str) -> String {
+    let name = url.trim_end_matches('/').rsplit('/').next().unwrap_or_default().trim_end_matches(".git");
+
+    let mut hash = String::new();
+    for byte in &Sha256::digest(url.as_bytes())[..8] {
+        write!(&mut hash, "{:02x}", byte).expect("Unable to write");
+    }
+
+    format!("{name}-{hash}")
+}
+
+// The name of the directory for the checked out revision.
+// Revisions that are `.` or `..` are rejected, as they would resolve to a directory outside of the repository's.
+// Revisions starting with `-` are rejected, as `git` would parse them as options.
+fn revision_directory(source: &GitSource) -> Result<String, UtilError> {
+    let revision = source.pinned_revision().or(source.branch().as_ref()).map_or("HEAD", String::as_str);
+    if revision.starts_with('-') || revision.split(['/', '\\']).any(|component| component == "." || component == "..") {
+        return Err(UtilError::invalid_git_revision(revision, Default::default()));
+    }
+    Ok(revision.replace(['/', '\\'], "-"))
+}
@@ -0,0 +1,78 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::RegistryBackend;
+use crate::Network;
+use indexmap::IndexMap;
+use leo_errors::UtilError;
+
+const ALEO_EXPLORER_URL: &str = "https://api.explorer.aleo.org/v1";
+
+// An HTTP endpoint serving programs at `{endpoint}/{network}/program/{program}`.
+pub struct HttpBackend {
+    endpoints: IndexMap<Network, String>,
+}
+
+impl Default for HttpBackend {
+    fn default() -> Self {
+        Self {
+            endpoints: IndexMap::from([
+                (Network::Testnet3, ALEO_EXPLORER_URL.to_string()),
+                (Network::Mainnet, ALEO_EXPLORER_URL.to_string()),
+            ]),
+        }
+    }
+}
+
+impl HttpBackend {
+    // Use `endpoint` instead of the default one for `network`.
+    pub fn with_endpoint(mut self, network: Network, endpoint: String) -> Self {
+        self.endpoints.insert(network, endpoint.trim_end_matches('/').to_string());
+        self
+    }
+
+    // The url of `program` on `network`, if an endpoint is configured for it.
+    pub fn url(&self, program: &str, network: &Network) -> Option<String> {
+        self.endpoints.get(network).map(|endpoint| format!("{endpoint}/{network}/program/{program}"))
+    }
+}
+
+impl RegistryBackend for HttpBackend {
+    fn describe(&self) -> String {
+        let endpoints = self.endpoints.iter().map(|(network, endpoint)| format!("`{endpoint}` ({network})"));
+        format!("the endpoints {}", endpoints.collect::<Vec<_>>().join(", "))
+    }
+
+    fn fetch(&self, program: &str, network: &Network) -> Result<Option<String>, UtilError> {
+        let Some(url) = self.url(program, network) else {
+            return Ok(None);
+        };
+
+        println!("Retrieving {} from {:?}.", program, network);
+        let response = ureq::get(&url)
+            .call()
+            .map_err(|err| UtilError::failed_to_retrieve_from_endpoint(url.clone(), err, Default::default()))?;
+        if response.status() != 200 {
+            return Err(UtilError::network_error(url, response.status(), Default::default()));
+        }
+        let file_str = response
+            .into_string()
+            .map_err(|err| UtilError::failed_to_retrieve_from_endpoint(url, err, Default::default()))?;
+        println!("Successfully retrieved {} from {:?}!", program, network);
+
+        Ok(Some(file_str.replace("\\n", "\n").replace('\"', "")))
+    }
+}
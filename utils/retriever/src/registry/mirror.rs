@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{read_if_exists, RegistryBackend};
+use crate::Network;
+use leo_errors::UtilError;
+
+use std::path::{Path, PathBuf};
+
+// A directory laid out like the `~/.aleo/registry` cache, containing `{network}/{program}` files.
+// Mirrors can be populated by copying the cache of a machine with network access.
+pub struct MirrorBackend {
+    path: PathBuf,
+}
+
+impl MirrorBackend {
+    pub fn new(path: &Path) -> Self {
+        Self { path: path.to_path_buf() }
+    }
+}
+
+impl RegistryBackend for MirrorBackend {
+    fn describe(&self) -> String {
+        format!("the mirror at `{}`", self.path.display())
+    }
+
+    fn fetch(&self, program: &str, network: &Network) -> Result<Option<String>, UtilError> {
+        read_if_exists(&self.path.join(format!("{network}")).join(program))
+    }
+}
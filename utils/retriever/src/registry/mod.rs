@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod git;
+
+pub mod http;
+pub use http::*;
+
+pub mod mirror;
+pub use mirror::*;
+
+pub mod vendored;
+pub use vendored::*;
+
+use crate::{GitSource, Network};
+use leo_errors::UtilError;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// A source of the bytecode of programs deployed to a network
+pub trait RegistryBackend {
+    // Describes where the backend looks for programs, used when a program cannot be found.
+    fn describe(&self) -> String;
+
+    // Returns the bytecode of `program` on `network`, or `None` if the backend does not have it.
+    fn fetch(&self, program: &str, network: &Network) -> Result<Option<String>, UtilError>;
+}
+
+// Registry is responsible for finding network dependencies.
+// Backends are queried in the order they were added, and the first one to have the program wins.
+pub struct Registry {
+    cache_path: PathBuf,
+    backends: Vec<Box<dyn RegistryBackend>>,
+}
+
+impl Registry {
+    // Initialize a new Registry with no backends, caching retrieved programs in `cache_path`.
+    pub fn new(cache_path: &Path) -> Self {
+        Self { cache_path: cache_path.to_path_buf(), backends: Vec::new() }
+    }
+
+    // Add a backend to be queried after the existing ones.
+    pub fn with_backend(mut self, backend: impl RegistryBackend + 'static) -> Self {
+        self.backends.push(Box::new(backend));
+        self
+    }
+
+    // The path to the cached bytecode of `program` on `network`.
+    pub fn cache_path(&self, program: &str, network: &Network) -> PathBuf {
+        self.cache_path.join(format!("{network}")).join(program)
+    }
+
+    // Check out a Git dependency in the `git` directory of the cache, and return the root of the checkout.
+    pub fn checkout(&self, source: &GitSource) -> Result<PathBuf, UtilError> {
+        git::checkout(&self.cache_path.join("git"), source)
+    }
+
+    // Retrieve `program` from the first backend that has it, and write it to the cache.
+    // Returns the path to the cached bytecode.
+    pub fn retrieve(&self, program: &str, network: &Network) -> Result<PathBuf, UtilError> {
+        let Some(file_str) = self.fetch(program, network)? else {
+            let searched = self.backends.iter().map(|backend| backend.describe()).collect::<Vec<_>>();
+            return Err(UtilError::program_not_found_in_registry(
+                program,
+                network,
+                match searched.is_empty() {
+                    true => "no registries are configured".to_string(),
+                    false => format!("searched {}", searched.join(", ")),
+                },
+                Default::default(),
+            ));
+        };
+
+        // Write file to cache, unless it was found there
+        let path = self.cache_path(program, network);
+        if fs::read_to_string(&path).ok().as_ref() != Some(&file_str) {
+            let directory = self.cache_path.join(format!("{network}"));
+            fs::create_dir_all(&directory).map_err(|err| {
+                UtilError::util_file_io_error(
+                    format!("Could not write path {}", directory.to_str().unwrap()),
+                    err,
+                    Default::default(),
+                )
+            })?;
+            fs::write(&path, file_str).map_err(|err| {
+                UtilError::util_file_io_error(
+                    format!("Could not write path {}", path.to_str().unwrap()),
+                    err,
+                    Default::default(),
+                )
+            })?;
+        }
+
+        Ok(path)
+    }
+
+    // Returns the bytecode of `program` from the first backend that has it.
+    pub fn fetch(&self, program: &str, network: &Network) -> Result<Option<String>, UtilError> {
+        for backend in &self.backends {
+            if let Some(file_str) = backend.fetch(program, network)? {
+                return Ok(Some(file_str));
+            }
+        }
+        Ok(None)
+    }
+}
+
+// Read a program from `path` if it exists.
+fn read_if_exists(path: &Path) -> Result<Option<String>, UtilError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    fs::read_to_string(path).map(Some).map_err(|err| {
+        UtilError::util_file_io_error(
+            format!("Could not read path {}", path.to_str().unwrap()),
+            err,
+            Default::default(),
+        )
+    })
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the Leo library.
+
+// The Leo library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The Leo library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the Leo library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::{read_if_exists, RegistryBackend};
+use crate::Network;
+use leo_errors::UtilError;
+
+use std::path::{Path, PathBuf};
+
+// The `imports/` directory of a package, containing `{program}` files checked in alongside the source.
+// Vendored programs take precedence over every other backend, so that builds are reproducible.
+pub struct VendoredBackend {
+    path: PathBuf,
+}
+
+impl VendoredBackend {
+    // Initialize a VendoredBackend for the package at `project_path`.
+    pub fn new(project_path: &Path) -> Self {
+        Self { path: project_path.join("imports") }
+    }
+}
+
+impl RegistryBackend for VendoredBackend {
+    fn describe(&self) -> String {
+        format!("the vendored programs in `{}`", self.path.display())
+    }
+
+    fn fetch(&self, program: &str, _network: &Network) -> Result<Option<String>, UtilError> {
+        read_if_exists(&self.path.join(program))
+    }
+}
@@ -21,7 +21,7 @@ use leo_errors::UtilError;
 use leo_passes::{common::DiGraph, DiGraphError};
 use leo_span::Symbol;
 
-use crate::{Dependency, Location, LockFileEntry, Manifest, Network, ProgramContext};
+use crate::{Dependency, Location, LockFileEntry, Manifest, Network, ProgramContext, Registry};
 use std::{
     fs,
     fs::File,
@@ -29,27 +29,40 @@ use std::{
     path::{Path, PathBuf},
 };
 
-const ALEO_EXPLORER_URL: &str = "https://api.explorer.aleo.org/v1";
+// How the retriever treats the existing `leo.lock` file
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LockMode {
+    // Verify the checksums of network dependencies, and add new dependencies to the lock file.
+    #[default]
+    Verify,
+    // Refuse to build if the lock file is out of date, and never write it.
+    Locked,
+    // Accept the current checksums of network dependencies, and rewrite the lock file.
+    Update,
+}
 
 // Retriever is responsible for retrieving external programs
 pub struct Retriever {
     name: Symbol,
     contexts: IndexMap<Symbol, ProgramContext>,
     project_path: PathBuf,
-    registry_path: PathBuf,
+    registry: Registry,
+    lock_file: IndexMap<String, LockFileEntry>,
+    lock_mode: LockMode,
 }
 
 impl Retriever {
-    // Initialize a new Retriever.
-    pub fn new(name: Symbol, path: &PathBuf, home: &Path) -> Result<Self, UtilError> {
+    // Initialize a new Retriever, which looks up network dependencies in `registry`.
+    pub fn new(name: Symbol, path: &PathBuf, registry: Registry, lock_mode: LockMode) -> Result<Self, UtilError> {
         // Starting point is all of the dependencies specified in the main `program.json` file
         let dependencies = retrieve_local(&format!("{name}.aleo"), path)?;
+        let lock_file = read_lock_file(&path.join("leo.lock"))?;
         let mut contexts = IndexMap::from([(name, ProgramContext::new_main(name, path.clone(), dependencies.clone()))]);
         for dep in dependencies {
             contexts.insert(Symbol::from(&dep), ProgramContext::from(dep));
         }
 
-        Ok(Self { name, contexts, project_path: path.clone(), registry_path: home.join("registry") })
+        Ok(Self { name, contexts, project_path: path.clone(), registry, lock_file, lock_mode })
     }
 
     pub fn get_context(&self, name: &Symbol) -> &ProgramContext {
@@ -78,12 +91,13 @@ impl Retriever {
                 // Split into cases based on network dependency or local dependency
                 let nested_dependencies = match cur_context.location() {
                     Location::Network => {
-                        let (stub, nested_dependencies) = retrieve_from_network(
+                        let (stub, nested_dependencies, path) = retrieve_from_network(
                             &self.project_path,
-                            &self.registry_path,
+                            &self.registry,
                             cur_context.full_name(),
                             cur_context.network(),
                         )?;
+                        cur_context.add_compiled_file_path(&path);
 
                         // Cache the stubs
                         if cur_context.add_stub(stub.clone()) {
@@ -94,15 +108,15 @@ impl Retriever {
                         }
 
                         cur_context.add_checksum();
+                        self.verify_checksum(cur_context)?;
 
                         nested_dependencies
                     }
-                    Location::Local => {
+                    Location::Local | Location::Git => {
                         // Programs add an entry for their dependencies in the self.contexts mapping, so we can use that information to learn the current path
                         let cur_full_path = cur_context.full_path();
                         retrieve_local(cur_context.full_name(), cur_full_path)?
                     }
-                    Location::Git => panic!("Location::Git is not supported yet"),
                 };
 
                 // Mark as visited
@@ -126,13 +140,23 @@ impl Retriever {
                                     .add_compiled_file_path(&dep_context.full_path().join("build").join("main.aleo"));
                             }
                             Location::Network => {
-                                dep_context.add_compiled_file_path(&self.registry_path.join(format!(
-                                    "{}/{}",
-                                    dep_context.network(),
-                                    dep_context.full_name()
-                                )));
+                                dep_context.add_compiled_file_path(
+                                    &self.registry.cache_path(dep_context.full_name(), dep_context.network()),
+                                );
+                            }
+                            Location::Git => {
+                                // Git dependencies are checked out in the registry, and then compiled like local dependencies
+                                let Some(source) = dep.git() else {
+                                    return Err(UtilError::missing_git_error(dep.name(), Default::default()));
+                                };
+                                let checkout = self.registry.checkout(source)?;
+                                let full_path = match dep.path() {
+                                    Some(path) => checkout.join(path),
+                                    None => checkout,
+                                };
+                                dep_context.add_full_path(&full_path);
+                                dep_context.add_compiled_file_path(&full_path.join("build").join("main.aleo"));
                             }
-                            _ => panic!("Location::Git is not supported yet"),
                         }
 
                         // Don't add a new dependency to check if it has already been processed, or will be processed in the future
@@ -170,6 +194,11 @@ impl Retriever {
                 // Remove the main program
                 order.remove(&self.name);
 
+                // Under `--locked`, the lock file must list exactly the current dependencies
+                if self.lock_mode == LockMode::Locked {
+                    self.verify_dependencies(&order)?;
+                }
+
                 // Cache order
                 contexts
                     .get_mut(&self.name)
@@ -177,8 +206,11 @@ impl Retriever {
                     .add_post_order(order.clone());
 
                 // Filter out all network dependencies
-                let local_order: Vec<Symbol> =
-                    order.iter().cloned().filter(|p| contexts.get(p).unwrap().location() == &Location::Local).collect();
+                let local_order: Vec<Symbol> = order
+                    .iter()
+                    .cloned()
+                    .filter(|p| contexts.get(p).unwrap().location() != &Location::Network)
+                    .collect();
 
                 // Save the local contexts
                 self.contexts = contexts;
@@ -309,8 +341,47 @@ impl Retriever {
         Ok(())
     }
 
+    // Check that the checksum of a network dependency matches its entry in the lock file, if it has one
+    fn verify_checksum(&self, context: &ProgramContext) -> Result<(), UtilError> {
+        if self.lock_mode == LockMode::Update {
+            return Ok(());
+        }
+        match self.lock_file.get(&context.name().to_string()) {
+            Some(entry) if entry.checksum() != context.checksum() => Err(UtilError::checksum_mismatch(
+                context.full_name(),
+                entry.checksum(),
+                context.checksum(),
+                Default::default(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    // Check that the lock file has an entry for each dependency, and none for programs that are no longer dependencies
+    fn verify_dependencies(&self, dependencies: &IndexSet<Symbol>) -> Result<(), UtilError> {
+        let names: IndexSet<String> = dependencies.iter().map(|dependency| dependency.to_string()).collect();
+        if let Some(name) = names.iter().find(|name| !self.lock_file.contains_key(*name)) {
+            return Err(UtilError::lock_file_out_of_date(
+                format!("`{name}.aleo` is missing from the lock file"),
+                Default::default(),
+            ));
+        }
+        if let Some(name) = self.lock_file.keys().find(|name| !names.contains(*name)) {
+            return Err(UtilError::lock_file_out_of_date(
+                format!("`{name}.aleo` is no longer a dependency"),
+                Default::default(),
+            ));
+        }
+        Ok(())
+    }
+
     // Write lock file
     fn write_lock_file(&self, name: &Symbol) -> Result<(), UtilError> {
+        // The lock file is left untouched under `--locked`
+        if self.lock_mode == LockMode::Locked {
+            return Ok(());
+        }
+
         // Add entry for all dependencies
         let mut lock_file: IndexMap<String, Vec<LockFileEntry>> = IndexMap::new();
         let packages: Vec<LockFileEntry> = self
@@ -340,6 +411,19 @@ impl Retriever {
     }
 }
 
+// Read the entries of an existing lock file. Lock files are created empty, so a missing or empty file has no entries.
+fn read_lock_file(path: &Path) -> Result<IndexMap<String, LockFileEntry>, UtilError> {
+    let content = match path.exists() {
+        true => fs::read_to_string(path).map_err(|err| {
+            UtilError::util_file_io_error(format!("Could not read {}", path.to_str().unwrap()), err, Default::default())
+        })?,
+        false => String::new(),
+    };
+    let mut lock_file: IndexMap<String, Vec<LockFileEntry>> = toml::from_str(&content)
+        .map_err(|err| UtilError::failed_to_deserialize_file(format!("{}: {err}", path.to_str().unwrap())))?;
+    Ok(lock_file.remove("package").unwrap_or_default().into_iter().map(|entry| (entry.name().clone(), entry)).collect())
+}
+
 // Retrieve local
 fn retrieve_local(name: &String, path: &PathBuf) -> Result<Vec<Dependency>, UtilError> {
     // Create the lock file if it doesn't exist
@@ -405,48 +489,19 @@ fn retrieve_local(name: &String, path: &PathBuf) -> Result<Vec<Dependency>, Util
 // Retrieve from network
 fn retrieve_from_network(
     project_path: &Path,
-    home_path: &Path,
-    name: &String,
+    registry: &Registry,
+    name: &str,
     network: &Network,
-) -> Result<(Stub, Vec<Dependency>), UtilError> {
-    // Check if the file is already cached in `~/.aleo/registry/{network}/{program}`
-    let move_to_path = home_path.join(format!("{network}"));
-    let path = move_to_path.join(name.clone());
-    let mut file_str: String;
-    if !path.exists() {
-        // Create directories along the way if they don't exist
-        std::fs::create_dir_all(&move_to_path).map_err(|err| {
-            UtilError::util_file_io_error(
-                format!("Could not write path {}", move_to_path.to_str().unwrap()),
-                err,
-                Default::default(),
-            )
-        })?;
-
-        // Fetch from network
-        println!("Retrieving {} from {:?}.", name, network.clone());
-        file_str = fetch_from_network(name, network.clone())?;
-        file_str = file_str.replace("\\n", "\n").replace('\"', "");
-        println!("Successfully retrieved {} from {:?}!", name, network);
-
-        // Write file to cache
-        std::fs::write(path.clone(), file_str.clone().replace("\\n", "\n")).map_err(|err| {
-            UtilError::util_file_io_error(
-                format!("Could not open path {}", path.to_str().unwrap()),
-                err,
-                Default::default(),
-            )
-        })?;
-    } else {
-        // Read file from cache
-        file_str = fs::read_to_string(path.clone()).map_err(|err| {
-            UtilError::util_file_io_error(
-                format!("Could not read path {}", path.clone().to_str().unwrap()),
-                err,
-                Default::default(),
-            )
-        })?;
-    }
+) -> Result<(Stub, Vec<Dependency>, PathBuf), UtilError> {
+    // Find the program in the registry, which caches it in `~/.aleo/registry/{network}/{program}`
+    let path = registry.retrieve(name, network)?;
+    let file_str = fs::read_to_string(path.clone()).map_err(|err| {
+        UtilError::util_file_io_error(
+            format!("Could not read path {}", path.clone().to_str().unwrap()),
+            err,
+            Default::default(),
+        )
+    })?;
 
     // Copy the file into build directory. We can assume build directory exists because of its initialization in `leo/cli/commands/build.rs`.
     let import_dir = project_path.join("build").join("imports");
@@ -458,7 +513,7 @@ fn retrieve_from_network(
             Default::default(),
         )
     })?;
-    let build_location = PathBuf::from(import_dir_path).join(name.clone());
+    let build_location = PathBuf::from(import_dir_path).join(name);
     std::fs::write(build_location.clone(), file_str.clone()).map_err(|err| {
         UtilError::util_file_io_error(
             format!("Could not write to path {}", build_location.to_str().unwrap()),
@@ -485,17 +540,6 @@ fn retrieve_from_network(
                 )
             })
             .collect(),
+        path,
     ))
 }
-
-fn fetch_from_network(program: &String, network: Network) -> Result<String, UtilError> {
-    let url = format!("{}/{}/program/{}", ALEO_EXPLORER_URL, network.clone(), program);
-    let response = ureq::get(&url.clone())
-        .call()
-        .map_err(|err| UtilError::failed_to_retrieve_from_endpoint(url.clone(), err, Default::default()))?;
-    if response.status() == 200 {
-        Ok(response.into_string().unwrap())
-    } else {
-        Err(UtilError::network_error(url, response.status(), Default::default()))
-    }
-}